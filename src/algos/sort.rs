@@ -82,8 +82,8 @@ mod tests {
     #[test]
     fn test_argsort_empty() {
         let (indexer, sorted) = Sorter::argsort(&Vec::<i64>::new());
-        assert_eq!(indexer, vec![]);
-        assert_eq!(sorted, vec![]);
+        assert!(indexer.is_empty());
+        assert!(sorted.is_empty());
     }
 
     #[test]
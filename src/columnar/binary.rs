@@ -0,0 +1,613 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::{Append, Slicer, Stringify};
+
+/// Encoding to stringify binary values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal, such as `"deadbeef"`
+    Hex,
+    /// Standard base64 with padding, such as `"3q2+7w=="`
+    Base64,
+}
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_hex(value: &[u8]) -> String {
+    let mut result = String::with_capacity(value.len() * 2);
+    for &b in value {
+        result.push(HEX_CHARS[(b >> 4) as usize] as char);
+        result.push(HEX_CHARS[(b & 0x0f) as usize] as char);
+    }
+    result
+}
+
+fn encode_base64(value: &[u8]) -> String {
+    let mut result = String::with_capacity(value.len().div_ceil(3) * 4);
+    for chunk in value.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).cloned().unwrap_or(0);
+        let b2 = chunk.get(2).cloned().unwrap_or(0);
+        result.push(BASE64_CHARS[(b0 >> 2) as usize] as char);
+        result.push(BASE64_CHARS[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        if chunk.len() > 1 {
+            result.push(BASE64_CHARS[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char);
+        } else {
+            result.push('=');
+        }
+        if chunk.len() > 2 {
+            result.push(BASE64_CHARS[(b2 & 0x3f) as usize] as char);
+        } else {
+            result.push('=');
+        }
+    }
+    result
+}
+
+/// Nullable binary column which stores all values in a single byte buffer.
+///
+/// The i-th value is `values[offsets[i]..offsets[i + 1]]`, the same layout as
+/// `Utf8Column` without UTF-8 validation.
+#[derive(Clone, Debug)]
+pub struct BinaryColumn {
+    values: Vec<u8>,
+    // always has len + 1 elements, starting with 0
+    offsets: Vec<u64>,
+    mask: Option<Vec<bool>>,
+}
+
+impl BinaryColumn {
+    /// Create new `BinaryColumn` from `Vec<Vec<u8>>`.
+    pub fn new(values: Vec<Vec<u8>>) -> Self {
+        BinaryColumn::with_mask(values, None)
+    }
+
+    /// Create new `BinaryColumn` from `Vec<Vec<u8>>` and mask.
+    ///
+    /// If mask is `true`, corresponding element is regarded as `Null`.
+    pub fn with_mask(values: Vec<Vec<u8>>, mask: Option<Vec<bool>>) -> Self {
+        if let Some(ref m) = mask {
+            assert!(
+                m.len() == values.len(),
+                "mask must be the same length as values"
+            );
+        }
+        let mut builder = BinaryColumnBuilder::with_capacity(values.len());
+        match mask {
+            Some(ref m) => {
+                for (v, &is_null) in values.iter().zip(m.iter()) {
+                    if is_null {
+                        builder.push_null();
+                    } else {
+                        builder.push(v);
+                    }
+                }
+            }
+            None => {
+                for v in values.iter() {
+                    builder.push(v);
+                }
+            }
+        }
+        builder.finish()
+    }
+
+    /// Create new `BinaryColumn` from raw buffers.
+    ///
+    /// # Panics
+    ///
+    /// - if `offsets` doesn't start with 0, isn't monotonically increasing or
+    ///   exceeds the length of `values`
+    /// - if `mask` has different length
+    pub fn from_buffers(values: Vec<u8>, offsets: Vec<u64>, mask: Option<Vec<bool>>) -> Self {
+        assert!(
+            !offsets.is_empty() && offsets[0] == 0,
+            "offsets must start with 0"
+        );
+        assert!(
+            offsets.windows(2).all(|w| w[0] <= w[1]),
+            "offsets must be monotonically increasing"
+        );
+        assert!(
+            *offsets.last().unwrap() as usize <= values.len(),
+            "offsets must not exceed values"
+        );
+        if let Some(ref m) = mask {
+            assert!(
+                m.len() == offsets.len() - 1,
+                "mask must be the same length as values"
+            );
+        }
+        BinaryColumn {
+            values,
+            offsets,
+            mask: mask.filter(|m| m.iter().any(|&x| x)),
+        }
+    }
+
+    /// Returns the byte buffer which stores all values.
+    pub fn values_buffer(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// Returns offsets of each value in the byte buffer.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+
+    /// Returns mask whether the corresponding value is `Null`.
+    pub fn mask(&self) -> Option<&[bool]> {
+        self.mask.as_ref().map(|m| &m[..])
+    }
+
+    /// Returns whether the column contains `Null`.
+    pub fn has_null(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Returns `Vec<bool>` whether the collesponding value is `Null`.
+    pub fn is_null(&self) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => mask.clone(),
+            None => vec![false; self.len()],
+        }
+    }
+
+    /// Returns the value specified with the location, `None` if the value is `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if specified location outs of bounds
+    pub fn value(&self, location: usize) -> Option<&[u8]> {
+        assert!(location < self.len(), "Index out of bounds");
+        unsafe { self.value_unchecked(location) }
+    }
+
+    unsafe fn value_unchecked(&self, location: usize) -> Option<&[u8]> {
+        if let Some(ref mask) = self.mask {
+            if *mask.get_unchecked(location) {
+                return None;
+            }
+        }
+        let start = *self.offsets.get_unchecked(location) as usize;
+        let end = *self.offsets.get_unchecked(location + 1) as usize;
+        Some(self.values.get_unchecked(start..end))
+    }
+
+    /// Returns the number of bytes of each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let c = BinaryColumn::new(vec![vec![1, 2], vec![]]);
+    /// assert_eq!(c.lengths(), NullVec::new(vec![2, 0]));
+    /// ```
+    pub fn lengths(&self) -> NullVec<usize> {
+        let lengths: Vec<usize> = self
+            .offsets
+            .windows(2)
+            .map(|w| (w[1] - w[0]) as usize)
+            .collect();
+        NullVec::with_mask(lengths, self.mask.clone())
+    }
+
+    /// Returns hash of each value, which can be used to group equal values.
+    ///
+    /// All `Null` have the same hash.
+    pub fn hash_values(&self) -> Vec<u64> {
+        self.iter()
+            .map(|v| {
+                let mut hasher = DefaultHasher::new();
+                v.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    /// Returns values encoded to strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let c = BinaryColumn::new(vec![vec![0xde, 0xad, 0xbe, 0xef]]);
+    /// assert_eq!(c.encode(BinaryEncoding::Hex), NullVec::new(vec!["deadbeef".to_string()]));
+    /// assert_eq!(c.encode(BinaryEncoding::Base64), NullVec::new(vec!["3q2+7w==".to_string()]));
+    /// ```
+    pub fn encode(&self, encoding: BinaryEncoding) -> NullVec<String> {
+        let encode = match encoding {
+            BinaryEncoding::Hex => encode_hex,
+            BinaryEncoding::Base64 => encode_base64,
+        };
+        self.iter()
+            .map(|v| match v {
+                Some(v) => Nullable::Value(encode(v)),
+                None => Nullable::Null,
+            })
+            .collect()
+    }
+
+    /// Convert to `NullVec<Vec<u8>>`.
+    pub fn to_nullvec(&self) -> NullVec<Vec<u8>> {
+        self.iter()
+            .map(|v| match v {
+                Some(v) => Nullable::Value(v.to_vec()),
+                None => Nullable::Null,
+            })
+            .collect()
+    }
+
+    /// Returns Iterator which iterates `Option<&[u8]>`, `None` means `Null`.
+    pub fn iter(&self) -> BinaryColumnIter<'_> {
+        BinaryColumnIter {
+            data: self,
+            current: 0,
+        }
+    }
+}
+
+/// Build `BinaryColumn` by appending values
+struct BinaryColumnBuilder {
+    values: Vec<u8>,
+    offsets: Vec<u64>,
+    mask: Vec<bool>,
+}
+
+impl BinaryColumnBuilder {
+    fn with_capacity(capacity: usize) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        BinaryColumnBuilder {
+            values: vec![],
+            offsets,
+            mask: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, value: &[u8]) {
+        self.values.extend_from_slice(value);
+        self.offsets.push(self.values.len() as u64);
+        self.mask.push(false);
+    }
+
+    fn push_null(&mut self) {
+        self.offsets.push(self.values.len() as u64);
+        self.mask.push(true);
+    }
+
+    fn push_option(&mut self, value: Option<&[u8]>) {
+        match value {
+            Some(v) => self.push(v),
+            None => self.push_null(),
+        }
+    }
+
+    fn finish(self) -> BinaryColumn {
+        BinaryColumn::from_buffers(self.values, self.offsets, Some(self.mask))
+    }
+}
+
+/// Iterator returns `Option<&[u8]>`
+#[derive(Clone, Debug)]
+pub struct BinaryColumnIter<'a> {
+    data: &'a BinaryColumn,
+    current: usize,
+}
+
+impl<'a> Iterator for BinaryColumnIter<'a> {
+    type Item = Option<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current < self.data.len() {
+            let result = unsafe { self.data.value_unchecked(self.current) };
+            self.current += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.data.len() - self.current;
+        (hint, Some(hint))
+    }
+}
+
+// compare values rather than buffers, which may contain bytes under the mask
+impl PartialEq for BinaryColumn {
+    fn eq(&self, other: &BinaryColumn) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for BinaryColumn {}
+
+impl Hash for BinaryColumn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for v in self.iter() {
+            v.hash(state);
+        }
+    }
+}
+
+impl Slicer for BinaryColumn {
+    type Scalar = Nullable<Vec<u8>>;
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        assert!(*location < self.len(), "Index out of bounds");
+        unsafe { self.iloc_unchecked(location) }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        match self.value_unchecked(*location) {
+            Some(v) => Nullable::Value(v.to_vec()),
+            None => Nullable::Null,
+        }
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        unsafe { self.ilocs_unchecked(locations) }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        let mut builder = BinaryColumnBuilder::with_capacity(locations.len());
+        for &loc in locations.iter() {
+            builder.push_option(self.value_unchecked(loc));
+        }
+        builder.finish()
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        let mut builder = BinaryColumnBuilder::with_capacity(locations.len());
+        for &loc in locations.iter() {
+            if loc < len {
+                builder.push_option(unsafe { self.value_unchecked(loc) });
+            } else {
+                builder.push_null();
+            }
+        }
+        builder.finish()
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len() == flags.len(),
+            "flags must be the same length as values"
+        );
+        let mut builder = BinaryColumnBuilder::with_capacity(self.len());
+        for (v, &f) in self.iter().zip(flags.iter()) {
+            if f {
+                builder.push_option(v);
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl Append for BinaryColumn {
+    fn append(&self, other: &BinaryColumn) -> Self {
+        let mut values = Vec::with_capacity(self.values.len() + other.values.len());
+        values.extend_from_slice(&self.values);
+        values.extend_from_slice(&other.values);
+
+        let base = self.values.len() as u64;
+        let mut offsets = Vec::with_capacity(self.offsets.len() + other.len());
+        offsets.extend_from_slice(&self.offsets);
+        offsets.extend(other.offsets[1..].iter().map(|o| o + base));
+
+        let mask = match (&self.mask, &other.mask) {
+            (&None, &None) => None,
+            _ => {
+                let mut mask = self.is_null();
+                mask.extend(other.is_null());
+                Some(mask)
+            }
+        };
+        BinaryColumn {
+            values,
+            offsets,
+            mask,
+        }
+    }
+}
+
+/// Values are stringified as hex, use `BinaryColumn::encode` for base64
+impl Stringify for BinaryColumn {
+    fn into_string_vec(&self) -> Vec<String> {
+        self.iter()
+            .map(|v| match v {
+                Some(v) => encode_hex(v),
+                None => "Null".to_string(),
+            })
+            .collect()
+    }
+}
+
+// Conversion
+
+impl From<Vec<Vec<u8>>> for BinaryColumn {
+    fn from(values: Vec<Vec<u8>>) -> Self {
+        BinaryColumn::new(values)
+    }
+}
+
+impl<'a> From<Vec<&'a [u8]>> for BinaryColumn {
+    fn from(values: Vec<&'a [u8]>) -> Self {
+        let mut builder = BinaryColumnBuilder::with_capacity(values.len());
+        for v in values {
+            builder.push(v);
+        }
+        builder.finish()
+    }
+}
+
+impl From<NullVec<Vec<u8>>> for BinaryColumn {
+    fn from(values: NullVec<Vec<u8>>) -> Self {
+        let mut builder = BinaryColumnBuilder::with_capacity(values.len());
+        for (m, v) in values.iter_raw() {
+            if m {
+                builder.push_null();
+            } else {
+                builder.push(v);
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl From<BinaryColumn> for NullVec<Vec<u8>> {
+    fn from(values: BinaryColumn) -> Self {
+        values.to_nullvec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::{encode_base64, BinaryColumn, BinaryEncoding};
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{Append, Slicer, Stringify};
+
+    fn hash_of(c: &BinaryColumn) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        c.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_creation() {
+        let c = BinaryColumn::new(vec![vec![1], vec![], vec![2, 3]]);
+        assert_eq!(c.len(), 3);
+        assert_eq!(c.values_buffer(), &[1, 2, 3]);
+        assert_eq!(c.offsets(), &[0, 1, 1, 3]);
+        assert!(!c.has_null());
+
+        let c = BinaryColumn::with_mask(vec![vec![1], vec![2]], Some(vec![true, false]));
+        assert_eq!(c.values_buffer(), &[2]);
+        assert_eq!(c.is_null(), vec![true, false]);
+        assert_eq!(c.value(0), None);
+        assert_eq!(c.value(1), Some(&[2u8][..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_buffers_invalid_offsets() {
+        BinaryColumn::from_buffers(vec![1, 2], vec![0, 2, 1], None);
+    }
+
+    #[test]
+    fn test_eq_hash() {
+        // bytes under the mask are ignored
+        let c1 = BinaryColumn::from_buffers(
+            vec![1, 9, 2],
+            vec![0, 1, 2, 3],
+            Some(vec![false, true, false]),
+        );
+        let c2 = BinaryColumn::with_mask(
+            vec![vec![1], vec![], vec![2]],
+            Some(vec![false, true, false]),
+        );
+        assert_eq!(c1, c2);
+        assert_eq!(hash_of(&c1), hash_of(&c2));
+
+        let c3 = BinaryColumn::new(vec![vec![1], vec![], vec![2]]);
+        assert!(c1 != c3);
+
+        let c = BinaryColumn::with_mask(
+            vec![vec![1, 2], vec![3], vec![1, 2], vec![]],
+            Some(vec![false, false, false, true]),
+        );
+        let hashes = c.hash_values();
+        assert_eq!(hashes[0], hashes[2]);
+        assert!(hashes[0] != hashes[1]);
+    }
+
+    #[test]
+    fn test_lengths() {
+        let c = BinaryColumn::with_mask(vec![vec![1, 2], vec![3]], Some(vec![false, true]));
+        let res = c.lengths();
+        assert_eq!(res.is_null(), vec![false, true]);
+        assert_eq!(res.not_null_values(), vec![2]);
+    }
+
+    #[test]
+    fn test_slicer() {
+        let c = BinaryColumn::with_mask(
+            vec![vec![1], vec![2, 2], vec![3, 3, 3]],
+            Some(vec![false, true, false]),
+        );
+        assert_eq!(c.iloc(&0), Nullable::Value(vec![1]));
+        assert_eq!(c.iloc(&1), Nullable::Null);
+
+        let res = c.ilocs(&[2, 0]);
+        assert_eq!(res, BinaryColumn::new(vec![vec![3, 3, 3], vec![1]]));
+        assert_eq!(res.offsets(), &[0, 3, 4]);
+
+        let res = c.ilocs_forced(&[1, 5]);
+        assert_eq!(res.is_null(), vec![true, true]);
+
+        let res = c.blocs(&[false, true, true]);
+        assert_eq!(
+            res.iter().collect::<Vec<_>>(),
+            vec![None, Some(&[3u8, 3, 3][..])]
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let c1 = BinaryColumn::new(vec![vec![1], vec![2, 2]]);
+        let c2 = BinaryColumn::with_mask(vec![vec![3], vec![4]], Some(vec![true, false]));
+        let res = c1.append(&c2);
+        assert_eq!(res.values_buffer(), &[1, 2, 2, 4]);
+        assert_eq!(res.offsets(), &[0, 1, 3, 3, 4]);
+        assert_eq!(res.is_null(), vec![false, false, true, false]);
+    }
+
+    #[test]
+    fn test_stringify() {
+        let c = BinaryColumn::with_mask(
+            vec![vec![0x00, 0xff, 0x10], vec![], vec![1]],
+            Some(vec![false, false, true]),
+        );
+        assert_eq!(
+            c.into_string_vec(),
+            vec!["00ff10".to_string(), "".to_string(), "Null".to_string()]
+        );
+        assert_eq!(
+            c.encode(BinaryEncoding::Base64).into_string_vec(),
+            vec!["AP8Q".to_string(), "".to_string(), "Null".to_string()]
+        );
+
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_nullvec_conversion() {
+        let nv = NullVec::with_mask(vec![vec![1u8], vec![2]], Some(vec![true, false]));
+        let c: BinaryColumn = nv.clone().into();
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![None, Some(&[2u8][..])]);
+        let res: NullVec<Vec<u8>> = c.into();
+        assert_eq!(res.is_null(), nv.is_null());
+        assert_eq!(res.not_null_values(), vec![vec![2]]);
+    }
+}
@@ -0,0 +1,552 @@
+use std::collections::HashMap;
+
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::{Append, Slicer, Stringify};
+
+/// Nullable `String` column encoded as integer codes which refer to categories.
+///
+/// If `ordered` is `true`, the order of categories defines the order of values
+/// and ordering comparisons are allowed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Categorical {
+    codes: NullVec<u32>,
+    categories: Vec<String>,
+    ordered: bool,
+}
+
+impl Categorical {
+    /// Create new `Categorical` whose categories are sorted unique non-null values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let values = NullVec::new(vec!["b".to_string(), "a".to_string(), "b".to_string()]);
+    /// let c = Categorical::new(&values, false);
+    /// assert_eq!(c.categories(), &["a".to_string(), "b".to_string()]);
+    /// assert_eq!(c.codes(), &NullVec::new(vec![1, 0, 1]));
+    /// ```
+    pub fn new(values: &NullVec<String>, ordered: bool) -> Self {
+        let mut categories = values.not_null_values();
+        categories.sort();
+        categories.dedup();
+        Categorical::with_categories(values, categories, ordered)
+    }
+
+    /// Create new `Categorical` with specified categories.
+    ///
+    /// Values which are not included in `categories` are regarded as `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if `categories` contains duplicates
+    pub fn with_categories(
+        values: &NullVec<String>,
+        categories: Vec<String>,
+        ordered: bool,
+    ) -> Self {
+        let lookup = category_lookup(&categories);
+        let codes: NullVec<u32> = values
+            .iter_raw()
+            .map(|(m, v)| match lookup.get(v.as_str()) {
+                Some(&code) if !m => Nullable::Value(code),
+                _ => Nullable::Null,
+            })
+            .collect();
+        Categorical {
+            codes,
+            categories,
+            ordered,
+        }
+    }
+
+    /// Create new `Categorical` from codes and categories.
+    ///
+    /// # Panics
+    ///
+    /// - if `categories` contains duplicates
+    /// - if non-null code is out of bounds of `categories`
+    pub fn from_codes(codes: NullVec<u32>, categories: Vec<String>, ordered: bool) -> Self {
+        category_lookup(&categories);
+        for (m, &c) in codes.iter_raw() {
+            assert!(m || (c as usize) < categories.len(), "code out of bounds");
+        }
+        Categorical {
+            codes,
+            categories,
+            ordered,
+        }
+    }
+
+    /// Returns codes which refer to `categories`.
+    pub fn codes(&self) -> &NullVec<u32> {
+        &self.codes
+    }
+
+    /// Returns categories.
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Returns whether categories are ordered.
+    pub fn is_ordered(&self) -> bool {
+        self.ordered
+    }
+
+    /// Returns `Vec<bool>` whether the collesponding value is `Null`.
+    pub fn is_null(&self) -> Vec<bool> {
+        self.codes.is_null()
+    }
+
+    /// Convert to `NullVec<String>`.
+    pub fn to_nullvec(&self) -> NullVec<String> {
+        self.codes
+            .iter_raw()
+            .map(|(m, &c)| {
+                if m {
+                    Nullable::Null
+                } else {
+                    Nullable::Value(self.categories[c as usize].clone())
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the code of `value`, `None` if it is not a category.
+    fn code_of(&self, value: &str) -> Option<u32> {
+        self.categories
+            .iter()
+            .position(|c| c == value)
+            .map(|i| i as u32)
+    }
+
+    /// Returns categories and the number of non-null occurrences, sorted by
+    /// count in descending order. Categories with the same count keep
+    /// the order of categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let c = Categorical::new(&NullVec::from(vec!["a", "b", "b"]), false);
+    /// assert_eq!(c.value_counts(), (vec!["b".to_string(), "a".to_string()], vec![2, 1]));
+    /// ```
+    pub fn value_counts(&self) -> (Vec<String>, Vec<usize>) {
+        let mut counts = vec![0usize; self.categories.len()];
+        for (m, &c) in self.codes.iter_raw() {
+            if !m {
+                counts[c as usize] += 1;
+            }
+        }
+        let mut indexer: Vec<usize> = (0..counts.len()).collect();
+        indexer.sort_by(|&l, &r| counts[r].cmp(&counts[l]));
+        let categories = indexer
+            .iter()
+            .map(|&i| self.categories[i].clone())
+            .collect();
+        let counts = indexer.iter().map(|&i| counts[i]).collect();
+        (categories, counts)
+    }
+
+    /// Returns `Categorical` shifted by `periods`, filling vacated locations with
+    /// `fill_value`. See `NullVec::shift`.
+    ///
+    /// # Panics
+    ///
+    /// - if `fill_value` is not `Null` and not a category
+    pub fn shift(&self, periods: isize, fill_value: Nullable<String>) -> Self {
+        let fill_code = match fill_value {
+            Nullable::Value(ref v) => {
+                Nullable::Value(self.code_of(v).expect("fill_value must be a category"))
+            }
+            Nullable::Null => Nullable::Null,
+        };
+        Categorical {
+            codes: self.codes.shift(periods, fill_code),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    /// Compare each code with the code of `value`.
+    fn compare<F>(&self, value: &str, ordering: bool, func: F) -> NullVec<bool>
+    where
+        F: Fn(u32, u32) -> bool,
+    {
+        if ordering {
+            assert!(self.ordered, "categories must be ordered");
+        }
+        let code = match self.code_of(value) {
+            Some(code) => code,
+            None => {
+                assert!(!ordering, "value must be a category");
+                // never equal to any value
+                self.categories.len() as u32
+            }
+        };
+        self.codes
+            .iter_raw()
+            .map(|(m, &c)| {
+                if m {
+                    Nullable::Null
+                } else {
+                    Nullable::Value(func(c, code))
+                }
+            })
+            .collect()
+    }
+
+    /// Returns whether each value is equal to `value`. `Null` is kept as `Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let c = Categorical::new(&NullVec::from(vec!["a", "b"]), false);
+    /// assert_eq!(c.equal("a"), NullVec::new(vec![true, false]));
+    /// ```
+    pub fn equal(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, false, |c, v| c == v)
+    }
+
+    /// Returns whether each value is not equal to `value`. `Null` is kept as `Null`.
+    pub fn not_equal(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, false, |c, v| c != v)
+    }
+
+    /// Returns whether each value is less than `value` in the order of categories.
+    ///
+    /// # Panics
+    ///
+    /// - if categories are not ordered
+    /// - if `value` is not a category
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let values = NullVec::from(vec!["low", "high", "mid"]);
+    /// let categories = vec!["low".to_string(), "mid".to_string(), "high".to_string()];
+    /// let c = Categorical::with_categories(&values, categories, true);
+    /// assert_eq!(c.less("mid"), NullVec::new(vec![true, false, false]));
+    /// ```
+    pub fn less(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, true, |c, v| c < v)
+    }
+
+    /// Returns whether each value is less than or equal to `value` in the order of
+    /// categories. See `Categorical::less`.
+    pub fn less_equal(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, true, |c, v| c <= v)
+    }
+
+    /// Returns whether each value is greater than `value` in the order of categories.
+    /// See `Categorical::less`.
+    pub fn greater(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, true, |c, v| c > v)
+    }
+
+    /// Returns whether each value is greater than or equal to `value` in the order of
+    /// categories. See `Categorical::less`.
+    pub fn greater_equal(&self, value: &str) -> NullVec<bool> {
+        self.compare(value, true, |c, v| c >= v)
+    }
+}
+
+/// Map each category to its code
+///
+/// # Panics
+///
+/// - if `categories` contains duplicates
+fn category_lookup(categories: &[String]) -> HashMap<&str, u32> {
+    let mut lookup = HashMap::with_capacity(categories.len());
+    for (i, c) in categories.iter().enumerate() {
+        assert!(
+            lookup.insert(c.as_str(), i as u32).is_none(),
+            "categories must be unique"
+        );
+    }
+    lookup
+}
+
+impl Slicer for Categorical {
+    type Scalar = Nullable<String>;
+
+    fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        match self.codes.iloc(location) {
+            Nullable::Value(c) => Nullable::Value(self.categories[c as usize].clone()),
+            Nullable::Null => Nullable::Null,
+        }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        match self.codes.iloc_unchecked(location) {
+            Nullable::Value(c) => Nullable::Value(self.categories[c as usize].clone()),
+            Nullable::Null => Nullable::Null,
+        }
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        Categorical {
+            codes: self.codes.ilocs(locations),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        Categorical {
+            codes: self.codes.ilocs_unchecked(locations),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        Categorical {
+            codes: self.codes.ilocs_forced(locations),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        Categorical {
+            codes: self.codes.blocs(flags),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+}
+
+impl Append for Categorical {
+    /// Append `other`, unifying categories.
+    ///
+    /// Categories of `other` which are not in `self` are added after the categories
+    /// of `self`.
+    ///
+    /// # Panics
+    ///
+    /// - if orderedness differs
+    /// - if ordered and categories differ
+    fn append(&self, other: &Categorical) -> Self {
+        assert!(
+            self.ordered == other.ordered,
+            "orderedness must be the same"
+        );
+        if self.categories == other.categories {
+            return Categorical {
+                codes: self.codes.append(&other.codes),
+                categories: self.categories.clone(),
+                ordered: self.ordered,
+            };
+        }
+        assert!(
+            !self.ordered,
+            "ordered categories must be the same to be appended"
+        );
+
+        let mut categories = self.categories.clone();
+        let mut remap: Vec<u32> = Vec::with_capacity(other.categories.len());
+        for c in other.categories.iter() {
+            match self.code_of(c) {
+                Some(code) => remap.push(code),
+                None => {
+                    remap.push(categories.len() as u32);
+                    categories.push(c.clone());
+                }
+            }
+        }
+        let other_codes: NullVec<u32> = other
+            .codes
+            .iter_raw()
+            .map(|(m, &c)| {
+                if m {
+                    Nullable::Null
+                } else {
+                    Nullable::Value(remap[c as usize])
+                }
+            })
+            .collect();
+        Categorical {
+            codes: self.codes.append(&other_codes),
+            categories,
+            ordered: self.ordered,
+        }
+    }
+}
+
+impl Stringify for Categorical {
+    fn into_string_vec(&self) -> Vec<String> {
+        self.to_nullvec().into_string_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Categorical;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{Append, Slicer, Stringify};
+
+    fn strings(values: Vec<&str>) -> Vec<String> {
+        values.into_iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_creation() {
+        let values = NullVec::with_mask(
+            strings(vec!["b", "a", "x", "b"]),
+            Some(vec![false, false, true, false]),
+        );
+        let c = Categorical::new(&values, false);
+        assert_eq!(c.categories(), &strings(vec!["a", "b"])[..]);
+        assert_eq!(c.codes().is_null(), vec![false, false, true, false]);
+        assert_eq!(c.codes().not_null_values(), vec![1, 0, 1]);
+        assert!(!c.is_ordered());
+        assert_eq!(c.to_nullvec().into_string_vec(), values.into_string_vec());
+
+        // values not in categories are Null
+        let c = Categorical::with_categories(&values, strings(vec!["b"]), true);
+        assert_eq!(c.is_null(), vec![false, true, true, false]);
+        assert!(c.is_ordered());
+    }
+
+    #[test]
+    fn test_from_codes() {
+        let codes = NullVec::with_mask(vec![1, 0, 0], Some(vec![false, false, true]));
+        let c = Categorical::from_codes(codes, strings(vec!["a", "b"]), false);
+        assert_eq!(c.into_string_vec(), strings(vec!["b", "a", "Null"]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_codes_out_of_bounds() {
+        Categorical::from_codes(NullVec::new(vec![2]), strings(vec!["a", "b"]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicated_categories() {
+        Categorical::from_codes(NullVec::new(vec![0]), strings(vec!["a", "a"]), false);
+    }
+
+    #[test]
+    fn test_slicer() {
+        let values = NullVec::with_mask(
+            strings(vec!["b", "a", "x", "c"]),
+            Some(vec![false, false, true, false]),
+        );
+        let c = Categorical::new(&values, false);
+        assert_eq!(c.len(), 4);
+        assert_eq!(c.iloc(&0), Nullable::Value("b".to_string()));
+        assert_eq!(c.iloc(&2), Nullable::Null);
+
+        let res = c.ilocs(&[3, 2]);
+        assert_eq!(res.categories(), c.categories());
+        assert_eq!(res.into_string_vec(), strings(vec!["c", "Null"]));
+
+        let res = c.blocs(&[true, false, false, true]);
+        assert_eq!(res.into_string_vec(), strings(vec!["b", "c"]));
+    }
+
+    #[test]
+    fn test_append() {
+        let c1 = Categorical::new(&NullVec::from(vec!["b", "a"]), false);
+        let c2 = Categorical::new(
+            &NullVec::with_mask(strings(vec!["c", "b", "a"]), Some(vec![false, false, true])),
+            false,
+        );
+        let res = c1.append(&c2);
+        assert_eq!(res.categories(), &strings(vec!["a", "b", "c"])[..]);
+        assert_eq!(res.codes().not_null_values(), vec![1, 0, 2, 1]);
+        assert_eq!(
+            res.into_string_vec(),
+            strings(vec!["b", "a", "c", "b", "Null"])
+        );
+
+        let res = c1.append(&c1);
+        assert_eq!(res.categories(), c1.categories());
+        assert_eq!(res.codes(), &NullVec::new(vec![1, 0, 1, 0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_append_ordered_mismatch() {
+        let c1 = Categorical::new(&NullVec::from(vec!["a"]), true);
+        let c2 = Categorical::new(&NullVec::from(vec!["b"]), true);
+        c1.append(&c2);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let values = NullVec::with_mask(
+            strings(vec!["low", "high", "mid", "mid"]),
+            Some(vec![false, false, false, true]),
+        );
+        let c = Categorical::with_categories(&values, strings(vec!["low", "mid", "high"]), true);
+        let mask = Some(vec![false, false, false, true]);
+        assert_eq!(
+            c.equal("mid"),
+            NullVec::with_mask(vec![false, false, true, false], mask.clone())
+        );
+        assert_eq!(
+            c.not_equal("mid"),
+            NullVec::with_mask(vec![true, true, false, false], mask.clone())
+        );
+        assert_eq!(
+            c.less("mid"),
+            NullVec::with_mask(vec![true, false, false, false], mask.clone())
+        );
+        assert_eq!(
+            c.less_equal("mid"),
+            NullVec::with_mask(vec![true, false, true, false], mask.clone())
+        );
+        assert_eq!(
+            c.greater("mid"),
+            NullVec::with_mask(vec![false, true, false, false], mask.clone())
+        );
+        assert_eq!(
+            c.greater_equal("mid"),
+            NullVec::with_mask(vec![false, true, true, false], mask.clone())
+        );
+        assert_eq!(
+            c.equal("x"),
+            NullVec::with_mask(vec![false, false, false, false], mask)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_comparison_unordered() {
+        let c = Categorical::new(&NullVec::from(vec!["a", "b"]), false);
+        c.less("b");
+    }
+
+    #[test]
+    fn test_value_counts() {
+        let values = NullVec::with_mask(
+            strings(vec!["a", "b", "c", "c", "b", "c"]),
+            Some(vec![false, false, false, false, false, true]),
+        );
+        let c = Categorical::new(&values, false);
+        let (categories, counts) = c.value_counts();
+        assert_eq!(categories, strings(vec!["b", "c", "a"]));
+        assert_eq!(counts, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_shift() {
+        let c = Categorical::new(&NullVec::from(vec!["a", "b", "c"]), false);
+        let res = c.shift(1, Nullable::Value("c".to_string()));
+        assert_eq!(res.into_string_vec(), strings(vec!["c", "a", "b"]));
+        let res = c.shift(-1, Nullable::Null);
+        assert_eq!(res.into_string_vec(), strings(vec!["b", "c", "Null"]));
+    }
+}
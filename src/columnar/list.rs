@@ -0,0 +1,544 @@
+use std::ops::Range;
+
+use super::{is_masked, shift_locations};
+use generic::{Array, DtypeError, Scalar};
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::{Append, BasicAggregation, NumericAggregation, Slicer, Stringify};
+
+/// Nullable list column which stores all elements in a single child `Array`.
+///
+/// The i-th row is `values[offsets[i]..offsets[i + 1]]`. A `Null` row and an empty
+/// row both span no elements, and are distinguished by the mask.
+#[derive(Clone, Debug)]
+pub struct ListColumn {
+    values: Box<Array>,
+    // always has len + 1 elements, starting with 0
+    offsets: Vec<u64>,
+    mask: Option<Vec<bool>>,
+}
+
+impl ListColumn {
+    /// Create new `ListColumn` from child `Array`, offsets and mask.
+    ///
+    /// If mask is `true`, corresponding row is regarded as `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if `offsets` doesn't start with 0, isn't monotonically increasing or
+    ///   exceeds the length of `values`
+    /// - if `mask` has different length
+    pub fn new(values: Array, offsets: Vec<u64>, mask: Option<Vec<bool>>) -> Self {
+        assert!(
+            !offsets.is_empty() && offsets[0] == 0,
+            "offsets must start with 0"
+        );
+        assert!(
+            offsets.windows(2).all(|w| w[0] <= w[1]),
+            "offsets must be monotonically increasing"
+        );
+        assert!(
+            *offsets.last().unwrap() as usize <= values.len(),
+            "offsets must not exceed values"
+        );
+        if let Some(ref m) = mask {
+            assert!(
+                m.len() == offsets.len() - 1,
+                "mask must be the same length as values"
+            );
+        }
+        ListColumn {
+            values: Box::new(values),
+            offsets,
+            mask: mask.filter(|m| m.iter().any(|&x| x)),
+        }
+    }
+
+    /// Create new `ListColumn` from rows, `None` is regarded as `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if all rows are `None`, as the child dtype cannot be inferred
+    /// - if rows have different dtypes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// use nullvec::prelude::dev::Stringify;
+    ///
+    /// let c = ListColumn::from_rows(vec![
+    ///     Some(Array::new(vec![1i64, 2])),
+    ///     None,
+    ///     Some(Array::new(vec![3i64])),
+    /// ]);
+    /// assert_eq!(c.len(), 3);
+    /// assert_eq!(c.into_string_vec(), vec!["[1, 2]", "Null", "[3]"]);
+    /// ```
+    pub fn from_rows(rows: Vec<Option<Array>>) -> Self {
+        let mut values: Option<Array> = None;
+        let mut offsets: Vec<u64> = Vec::with_capacity(rows.len() + 1);
+        let mut mask: Vec<bool> = Vec::with_capacity(rows.len());
+        offsets.push(0);
+        for row in rows.iter() {
+            if let Some(ref row) = *row {
+                values = Some(match values {
+                    Some(v) => v.append(row),
+                    None => row.clone(),
+                });
+            }
+            offsets.push(values.as_ref().map_or(0, |v| v.len()) as u64);
+            mask.push(row.is_none());
+        }
+        let values = values.expect("unable to infer dtype");
+        ListColumn::new(values, offsets, Some(mask))
+    }
+
+    /// Returns the child `Array` which stores all elements.
+    pub fn values(&self) -> &Array {
+        &self.values
+    }
+
+    /// Returns offsets of each row in the child `Array`.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+
+    /// Returns mask whether the corresponding row is `Null`.
+    pub fn mask(&self) -> Option<&[bool]> {
+        self.mask.as_ref().map(|m| &m[..])
+    }
+
+    /// Returns whether the column contains `Null` row.
+    pub fn has_null(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Returns `Vec<bool>` whether the collesponding row is `Null`.
+    pub fn is_null(&self) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => mask.clone(),
+            None => vec![false; self.len()],
+        }
+    }
+
+    /// Returns the row specified with the location, `None` if the row is `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if specified location outs of bounds
+    pub fn value(&self, location: usize) -> Option<Array> {
+        assert!(location < self.len(), "Index out of bounds");
+        if self.row_is_null(location) {
+            None
+        } else {
+            let indexer: Vec<usize> = self.range(location).collect();
+            // ilocs_forced drops the mask if the row has no Null element
+            Some(self.values.ilocs_forced(&indexer))
+        }
+    }
+
+    /// Returns the number of elements in each row.
+    pub fn lengths(&self) -> NullVec<usize> {
+        let lengths: Vec<usize> = (0..self.len()).map(|i| self.range(i).len()).collect();
+        NullVec::with_mask(lengths, self.mask.clone())
+    }
+
+    /// Flatten rows into a single child `Array`.
+    ///
+    /// Returns the flattened `Array` and the parent row location of each element.
+    /// `Null` and empty rows don't emit any element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    ///
+    /// let c = ListColumn::from_rows(vec![
+    ///     Some(Array::new(vec![1i64, 2])),
+    ///     None,
+    ///     Some(Array::new(vec![3i64])),
+    /// ]);
+    /// let (values, parents) = c.explode();
+    /// assert_eq!(values, Array::new(vec![1i64, 2, 3]));
+    /// assert_eq!(parents, vec![0, 0, 2]);
+    /// ```
+    pub fn explode(&self) -> (Array, Vec<usize>) {
+        let mut indexer: Vec<usize> = vec![];
+        let mut parents: Vec<usize> = vec![];
+        for i in 0..self.len() {
+            if self.row_is_null(i) {
+                continue;
+            }
+            for j in self.range(i) {
+                indexer.push(j);
+                parents.push(i);
+            }
+        }
+        (unsafe { self.values.ilocs_unchecked(&indexer) }, parents)
+    }
+
+    /// Returns the sum of each row, `Null` if the row is `Null`.
+    ///
+    /// The result has the same dtype as the child `Array`. Returns an error if the
+    /// child dtype is neither numeric nor duration.
+    pub fn list_sum(&self) -> Result<Array, DtypeError> {
+        match *self.values {
+            Array::DurationArray(_) => {}
+            ref values if values.is_numeric() => {}
+            ref values => return Err(DtypeError::new("list_sum", &values.dtype())),
+        }
+        let sums = (0..self.len())
+            .map(|i| match self.value(i) {
+                Some(row) => row.sum(),
+                None => Ok(Scalar::Null),
+            })
+            .collect::<Result<Vec<Scalar>, DtypeError>>()?;
+        // leading Null rows can't infer dtype, fill them from the child dtype
+        let first = sums
+            .iter()
+            .position(|s| *s != Scalar::Null)
+            .unwrap_or(sums.len());
+        let nulls = self.values.ilocs_forced(&vec![self.values.len(); first]);
+        if first == sums.len() {
+            Ok(nulls)
+        } else {
+            Ok(nulls.append(&sums[first..].to_vec().into()))
+        }
+    }
+
+    /// Returns the mean of each row, `Null` if the row is `Null`.
+    ///
+    /// Returns an error if the child dtype is not numeric.
+    pub fn list_mean(&self) -> Result<NullVec<f64>, DtypeError> {
+        if !self.values.is_numeric() {
+            return Err(DtypeError::new("list_mean", &self.values.dtype()));
+        }
+        (0..self.len())
+            .map(|i| match self.value(i) {
+                Some(row) => row.mean(),
+                None => Ok(Nullable::Null),
+            })
+            .collect()
+    }
+
+    /// Returns whether each row contains the specified value, `Null` if the row
+    /// is `Null`.
+    ///
+    /// Passing `Scalar::Null` checks whether each row contains `Null` element.
+    pub fn list_contains(&self, value: &Scalar) -> NullVec<bool> {
+        (0..self.len())
+            .map(|i| {
+                if self.row_is_null(i) {
+                    Nullable::Null
+                } else {
+                    let found = self
+                        .range(i)
+                        .any(|j| unsafe { self.values.iloc_unchecked(&j) } == *value);
+                    Nullable::Value(found)
+                }
+            })
+            .collect()
+    }
+
+    /// Shift rows by specified periods, filling with `Null` rows.
+    pub fn shift(&self, periods: isize) -> Self {
+        self.ilocs_forced(&shift_locations(self.len(), periods))
+    }
+
+    fn row_is_null(&self, location: usize) -> bool {
+        is_masked(&self.mask, location)
+    }
+
+    fn range(&self, location: usize) -> Range<usize> {
+        self.offsets[location] as usize..self.offsets[location + 1] as usize
+    }
+
+    /// Gather rows specified with locations, out of bounds location results in
+    /// `Null` row.
+    fn gather(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        let mut indexer: Vec<usize> = vec![];
+        let mut offsets: Vec<u64> = Vec::with_capacity(locations.len() + 1);
+        let mut mask: Vec<bool> = Vec::with_capacity(locations.len());
+        offsets.push(0);
+        for &loc in locations.iter() {
+            let is_null = loc >= len || self.row_is_null(loc);
+            if !is_null {
+                indexer.extend(self.range(loc));
+            }
+            offsets.push(indexer.len() as u64);
+            mask.push(is_null);
+        }
+        let values = unsafe { self.values.ilocs_unchecked(&indexer) };
+        ListColumn::new(values, offsets, Some(mask))
+    }
+}
+
+impl PartialEq for ListColumn {
+    fn eq(&self, other: &ListColumn) -> bool {
+        if self.len() != other.len() || self.values.dtype() != other.values.dtype() {
+            return false;
+        }
+        (0..self.len()).all(|i| {
+            if self.row_is_null(i) || other.row_is_null(i) {
+                return self.row_is_null(i) == other.row_is_null(i);
+            }
+            let (left, right) = (self.range(i), other.range(i));
+            left.len() == right.len()
+                && left
+                    .zip(right)
+                    .all(|(j, k)| self.values.iloc(&j) == other.values.iloc(&k))
+        })
+    }
+}
+
+impl Slicer for ListColumn {
+    type Scalar = Scalar;
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        match self.value(*location) {
+            Some(row) => Scalar::List(row),
+            None => Scalar::Null,
+        }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        self.iloc(location)
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        self.gather(locations)
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        self.gather(locations)
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        self.gather(locations)
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len() == flags.len(),
+            "flags must be the same length as values"
+        );
+        let locations: Vec<usize> = flags
+            .iter()
+            .enumerate()
+            .filter(|&(_, &f)| f)
+            .map(|(i, _)| i)
+            .collect();
+        self.gather(&locations)
+    }
+}
+
+impl Append for ListColumn {
+    fn append(&self, other: &ListColumn) -> Self {
+        let values = self.values.append(&other.values);
+
+        let base = self.values.len() as u64;
+        let mut offsets = Vec::with_capacity(self.offsets.len() + other.len());
+        offsets.extend_from_slice(&self.offsets);
+        offsets.extend(other.offsets[1..].iter().map(|o| o + base));
+
+        let mask = match (&self.mask, &other.mask) {
+            (&None, &None) => None,
+            _ => {
+                let mut mask = self.is_null();
+                mask.extend(other.is_null());
+                Some(mask)
+            }
+        };
+        ListColumn {
+            values: Box::new(values),
+            offsets,
+            mask,
+        }
+    }
+}
+
+impl Stringify for ListColumn {
+    fn into_string_vec(&self) -> Vec<String> {
+        (0..self.len())
+            .map(|i| match self.value(i) {
+                Some(row) => format!("[{}]", row.into_string_vec().join(", ")),
+                None => "Null".to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::ListColumn;
+    use generic::{Array, Scalar};
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{Append, Slicer, Stringify};
+
+    fn sample() -> ListColumn {
+        ListColumn::from_rows(vec![
+            Some(Array::new(vec![1i64, 2])),
+            None,
+            Some(Array::Int64Array(NullVec::new(vec![]))),
+            Some(Array::Int64Array(
+                NullVec::new(vec![3, 4]).ilocs_forced(&[0, 2]),
+            )),
+        ])
+    }
+
+    #[test]
+    fn test_creation() {
+        let c = sample();
+        assert_eq!(c.len(), 4);
+        assert_eq!(c.offsets(), &[0, 2, 2, 2, 4]);
+        assert_eq!(c.is_null(), vec![false, true, false, false]);
+        assert_eq!(c.value(0), Some(Array::new(vec![1i64, 2])));
+        assert_eq!(c.value(1), None);
+        assert_eq!(c.value(2), Some(Array::Int64Array(NullVec::new(vec![]))));
+
+        let c = ListColumn::new(
+            Array::new(vec![1, 2, 3]),
+            vec![0, 1, 3],
+            Some(vec![false; 2]),
+        );
+        assert!(!c.has_null());
+        assert_eq!(c.value(1), Some(Array::new(vec![2, 3])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_offsets() {
+        ListColumn::new(Array::new(vec![1, 2, 3]), vec![0, 2, 4], None);
+    }
+
+    #[test]
+    fn test_lengths() {
+        let c = sample();
+        let exp = NullVec::with_mask(vec![2, 0, 0, 2], Some(vec![false, true, false, false]));
+        assert_eq!(c.lengths(), exp);
+    }
+
+    #[test]
+    fn test_explode() {
+        let (values, parents) = sample().explode();
+        assert_eq!(values.into_string_vec(), vec!["1", "2", "3", "Null"]);
+        assert_eq!(parents, vec![0, 0, 3, 3]);
+    }
+
+    #[test]
+    fn test_list_aggregation() {
+        let c = sample();
+        let sum = c.list_sum().unwrap();
+        assert_eq!(sum.into_string_vec(), vec!["3", "Null", "0", "3"]);
+        assert_eq!(sum.dtype(), "i64");
+
+        let mean = c.list_mean().unwrap();
+        assert_eq!(mean.iloc(&0), Nullable::new(1.5));
+        assert_eq!(mean.iloc(&1), Nullable::Null);
+        assert_eq!(mean.iloc(&3), Nullable::new(3.0));
+
+        let exp = NullVec::with_mask(
+            vec![true, false, false, false],
+            Some(vec![false, true, false, false]),
+        );
+        assert_eq!(c.list_contains(&Scalar::i64(2)), exp);
+        let exp = NullVec::with_mask(
+            vec![false, false, false, true],
+            Some(vec![false, true, false, false]),
+        );
+        assert_eq!(c.list_contains(&Scalar::Null), exp);
+
+        // dtype is kept even if leading or all rows are Null
+        let res = c.ilocs(&[1, 0]).list_sum().unwrap();
+        assert_eq!(res.dtype(), "i64");
+        assert_eq!(res.into_string_vec(), vec!["Null", "3"]);
+        let res = c.ilocs(&[1, 1]).list_sum().unwrap();
+        assert_eq!(res.dtype(), "i64");
+        assert_eq!(res.len(), 2);
+    }
+
+    #[test]
+    fn test_list_aggregation_unsupported_dtype() {
+        let c = ListColumn::from_rows(vec![Some(Array::new(vec!["a", "b"])), None]);
+        let err = c.list_sum().unwrap_err();
+        assert_eq!(err.operation(), "list_sum");
+        assert_eq!(err.dtype(), "str");
+        assert_eq!(c.list_mean().unwrap_err().operation(), "list_mean");
+    }
+
+    #[test]
+    fn test_eq() {
+        let c = sample();
+        // same rows with different buffers, the Null row spans an element
+        let other = ListColumn::new(
+            Array::Int64Array(NullVec::with_mask(
+                vec![1, 2, 9, 3, 5],
+                Some(vec![false, false, false, false, true]),
+            )),
+            vec![0, 2, 3, 3, 5],
+            Some(vec![false, true, false, false]),
+        );
+        assert_eq!(c, other);
+        assert_eq!(c, c.ilocs(&[0, 1, 2, 3]));
+        assert_ne!(c, c.ilocs(&[0, 1, 2]));
+        assert_ne!(c, c.ilocs(&[0, 2, 1, 3]));
+
+        let other = ListColumn::new(Array::new(vec![1i64, 2]), vec![0, 2], None);
+        assert_ne!(
+            other,
+            ListColumn::new(Array::new(vec![1i64, 3]), vec![0, 2], None)
+        );
+        assert_ne!(
+            other,
+            ListColumn::new(Array::new(vec![1i32, 2]), vec![0, 2], None)
+        );
+    }
+
+    #[test]
+    fn test_slicer() {
+        let c = sample();
+        assert_eq!(c.iloc(&0), Scalar::List(Array::new(vec![1i64, 2])));
+        assert_eq!(c.iloc(&1), Scalar::Null);
+
+        let res = c.ilocs(&[3, 1, 0]);
+        assert_eq!(res.into_string_vec(), vec!["[3, Null]", "Null", "[1, 2]"]);
+        assert_eq!(res.values().len(), 4);
+
+        let res = c.ilocs_forced(&[0, 10]);
+        assert_eq!(res.into_string_vec(), vec!["[1, 2]", "Null"]);
+
+        let res = c.blocs(&[false, true, true, false]);
+        assert_eq!(res.into_string_vec(), vec!["Null", "[]"]);
+
+        let res = c.shift(1);
+        assert_eq!(res.into_string_vec(), vec!["Null", "[1, 2]", "Null", "[]"]);
+
+        let res = c.shift(isize::MIN);
+        assert_eq!(res.into_string_vec(), vec!["Null", "Null", "Null", "Null"]);
+        let res = c.shift(isize::MAX);
+        assert_eq!(res.into_string_vec(), vec!["Null", "Null", "Null", "Null"]);
+    }
+
+    #[test]
+    fn test_append() {
+        let c = sample();
+        let other = ListColumn::new(Array::new(vec![5i64, 6]), vec![0, 2], None);
+        let res = c.append(&other);
+        assert_eq!(res.len(), 5);
+        assert_eq!(res.offsets(), &[0, 2, 2, 2, 4, 6]);
+        assert_eq!(
+            res.into_string_vec(),
+            vec!["[1, 2]", "Null", "[]", "[3, Null]", "[5, 6]"]
+        );
+    }
+}
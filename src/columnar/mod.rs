@@ -0,0 +1,39 @@
+//! Columnar storages which keep all values in a single contiguous buffer.
+//!
+//! # Examples
+//!
+//! ```
+//! use nullvec::prelude::*;
+//! let c = Utf8Column::from(vec!["a", "bb", "ccc"]);
+//! assert_eq!(c.value(1), Some("bb"));
+//! assert_eq!(c.values_buffer(), b"abbccc");
+//! ```
+
+mod binary;
+mod categorical;
+mod list;
+mod structure;
+mod utf8;
+
+pub use self::binary::{BinaryColumn, BinaryColumnIter, BinaryEncoding};
+pub use self::categorical::Categorical;
+pub use self::list::ListColumn;
+pub use self::structure::StructColumn;
+pub use self::utf8::{Utf8Column, Utf8ColumnIter};
+
+/// Returns whether the row at `location` is `Null` in `mask`.
+fn is_masked(mask: &Option<Vec<bool>>, location: usize) -> bool {
+    mask.as_ref().is_some_and(|m| m[location])
+}
+
+/// Returns locations to shift `len` rows by `periods`. Rows shifted in from
+/// outside are indicated by `len`, which results in `Null` with `ilocs_forced`.
+fn shift_locations(len: usize, periods: isize) -> Vec<usize> {
+    let ilen = len as isize;
+    (0..ilen)
+        .map(|i| match i.checked_sub(periods) {
+            Some(loc) if (0..ilen).contains(&loc) => loc as usize,
+            _ => len,
+        })
+        .collect()
+}
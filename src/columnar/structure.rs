@@ -0,0 +1,343 @@
+use super::{is_masked, shift_locations};
+use generic::{Array, Scalar};
+use traits::{Append, Slicer, Stringify};
+
+/// Nullable struct column which stores each field as a named child `Array`.
+///
+/// All children have the same length as the column. The mask represents whether the
+/// whole row is `Null`, children may also contain `Null` individually.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructColumn {
+    names: Vec<String>,
+    fields: Vec<Array>,
+    mask: Option<Vec<bool>>,
+}
+
+impl StructColumn {
+    /// Create new `StructColumn` from named child `Array`s.
+    ///
+    /// # Panics
+    ///
+    /// - if no field is passed
+    /// - if names are duplicated
+    /// - if children have different lengths
+    pub fn new(fields: Vec<(String, Array)>) -> Self {
+        StructColumn::with_mask(fields, None)
+    }
+
+    /// Create new `StructColumn` from named child `Array`s and mask.
+    ///
+    /// If mask is `true`, corresponding row is regarded as `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if no field is passed
+    /// - if names are duplicated
+    /// - if children or `mask` have different lengths
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// use nullvec::prelude::dev::Stringify;
+    ///
+    /// let c = StructColumn::with_mask(
+    ///     vec![
+    ///         ("a".to_string(), Array::new(vec![1i64, 2])),
+    ///         ("b".to_string(), Array::new(vec!["x", "y"])),
+    ///     ],
+    ///     Some(vec![false, true]),
+    /// );
+    /// assert_eq!(c.field("a"), Some(&Array::new(vec![1i64, 2])));
+    /// assert_eq!(c.into_string_vec(), vec!["{a: 1, b: x}", "Null"]);
+    /// ```
+    pub fn with_mask(fields: Vec<(String, Array)>, mask: Option<Vec<bool>>) -> Self {
+        assert!(!fields.is_empty(), "struct must have at least one field");
+        let len = fields[0].1.len();
+        let mut names: Vec<String> = Vec::with_capacity(fields.len());
+        let mut children: Vec<Array> = Vec::with_capacity(fields.len());
+        for (name, field) in fields.into_iter() {
+            assert!(!names.contains(&name), "field names must be unique");
+            assert!(field.len() == len, "fields must have the same length");
+            names.push(name);
+            children.push(field);
+        }
+        if let Some(ref m) = mask {
+            assert!(m.len() == len, "mask must be the same length as fields");
+        }
+        StructColumn {
+            names,
+            fields: children,
+            mask: mask.filter(|m| m.iter().any(|&x| x)),
+        }
+    }
+
+    /// Returns field names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns child `Array`s in the same order as names.
+    pub fn fields(&self) -> &[Array] {
+        &self.fields
+    }
+
+    /// Returns the child `Array` specified with the name, `None` if it doesn't exist.
+    pub fn field(&self, name: &str) -> Option<&Array> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| &self.fields[i])
+    }
+
+    /// Returns mask whether the corresponding row is `Null`.
+    pub fn mask(&self) -> Option<&[bool]> {
+        self.mask.as_ref().map(|m| &m[..])
+    }
+
+    /// Returns whether the column contains `Null` row.
+    pub fn has_null(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Returns `Vec<bool>` whether the collesponding row is `Null`.
+    pub fn is_null(&self) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => mask.clone(),
+            None => vec![false; self.len()],
+        }
+    }
+
+    /// Shift rows by specified periods, filling with `Null` rows.
+    pub fn shift(&self, periods: isize) -> Self {
+        self.ilocs_forced(&shift_locations(self.len(), periods))
+    }
+
+    fn row_is_null(&self, location: usize) -> bool {
+        is_masked(&self.mask, location)
+    }
+
+    fn with_fields(&self, fields: Vec<Array>, mask: Option<Vec<bool>>) -> Self {
+        StructColumn {
+            names: self.names.clone(),
+            fields,
+            mask: mask.filter(|m| m.iter().any(|&x| x)),
+        }
+    }
+}
+
+impl Slicer for StructColumn {
+    type Scalar = Scalar;
+
+    fn len(&self) -> usize {
+        self.fields[0].len()
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        assert!(*location < self.len(), "Index out of bounds");
+        unsafe { self.iloc_unchecked(location) }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        if self.row_is_null(*location) {
+            return Scalar::Null;
+        }
+        let values = self
+            .names
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(n, f)| (n.clone(), f.iloc_unchecked(location)))
+            .collect();
+        Scalar::Struct(values)
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        unsafe { self.ilocs_unchecked(locations) }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.ilocs_unchecked(locations))
+            .collect();
+        let mask = self
+            .mask
+            .as_ref()
+            .map(|m| locations.iter().map(|&i| m[i]).collect());
+        self.with_fields(fields, mask)
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.ilocs_forced(locations))
+            .collect();
+        let mask = locations
+            .iter()
+            .map(|&i| i >= len || self.row_is_null(i))
+            .collect();
+        self.with_fields(fields, Some(mask))
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len() == flags.len(),
+            "flags must be the same length as values"
+        );
+        let fields = self.fields.iter().map(|f| f.blocs(flags)).collect();
+        let mask = self.mask.as_ref().map(|m| {
+            m.iter()
+                .zip(flags.iter())
+                .filter(|&(_, &f)| f)
+                .map(|(&m, _)| m)
+                .collect()
+        });
+        self.with_fields(fields, mask)
+    }
+}
+
+impl Append for StructColumn {
+    fn append(&self, other: &StructColumn) -> Self {
+        assert!(
+            self.names == other.names,
+            "struct must have the same field names"
+        );
+        let fields = self
+            .fields
+            .iter()
+            .zip(other.fields.iter())
+            .map(|(l, r)| l.append(r))
+            .collect();
+        let mask = match (&self.mask, &other.mask) {
+            (&None, &None) => None,
+            _ => {
+                let mut mask = self.is_null();
+                mask.extend(other.is_null());
+                Some(mask)
+            }
+        };
+        self.with_fields(fields, mask)
+    }
+}
+
+impl Stringify for StructColumn {
+    fn into_string_vec(&self) -> Vec<String> {
+        (0..self.len())
+            .map(|i| unsafe { self.iloc_unchecked(&i) }.to_string())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::StructColumn;
+    use generic::{Array, Scalar};
+    use nullvec::NullVec;
+    use traits::{Append, Slicer, Stringify};
+
+    fn sample() -> StructColumn {
+        StructColumn::with_mask(
+            vec![
+                ("a".to_string(), Array::new(vec![1i64, 2, 3])),
+                (
+                    "b".to_string(),
+                    Array::StringArray(NullVec::with_mask(
+                        vec!["x".to_string(), "y".to_string(), "z".to_string()],
+                        Some(vec![true, false, false]),
+                    )),
+                ),
+            ],
+            Some(vec![false, true, false]),
+        )
+    }
+
+    #[test]
+    fn test_creation() {
+        let c = sample();
+        assert_eq!(c.len(), 3);
+        assert_eq!(c.names(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(c.fields().len(), 2);
+        assert_eq!(c.field("a"), Some(&Array::new(vec![1i64, 2, 3])));
+        assert_eq!(c.field("c"), None);
+        assert_eq!(c.is_null(), vec![false, true, false]);
+
+        let c = StructColumn::with_mask(
+            vec![("a".to_string(), Array::new(vec![1i64]))],
+            Some(vec![false]),
+        );
+        assert!(!c.has_null());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_different_length() {
+        StructColumn::new(vec![
+            ("a".to_string(), Array::new(vec![1i64, 2])),
+            ("b".to_string(), Array::new(vec![1i64])),
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicated_names() {
+        StructColumn::new(vec![
+            ("a".to_string(), Array::new(vec![1i64])),
+            ("a".to_string(), Array::new(vec![1i64])),
+        ]);
+    }
+
+    #[test]
+    fn test_slicer() {
+        let c = sample();
+        let exp = Scalar::Struct(vec![
+            ("a".to_string(), Scalar::i64(1)),
+            ("b".to_string(), Scalar::Null),
+        ]);
+        assert_eq!(c.iloc(&0), exp);
+        assert_eq!(c.iloc(&1), Scalar::Null);
+
+        let res = c.ilocs(&[2, 1]);
+        assert_eq!(res.into_string_vec(), vec!["{a: 3, b: z}", "Null"]);
+        assert_eq!(res.field("a"), Some(&Array::new(vec![3i64, 2])));
+
+        let res = c.ilocs_forced(&[2, 5]);
+        assert_eq!(res.into_string_vec(), vec!["{a: 3, b: z}", "Null"]);
+        assert_eq!(res.field("a").unwrap().len(), 2);
+
+        let res = c.blocs(&[true, false, true]);
+        assert_eq!(
+            res.into_string_vec(),
+            vec!["{a: 1, b: Null}", "{a: 3, b: z}"]
+        );
+        assert!(!res.has_null());
+
+        let res = c.shift(-1);
+        assert_eq!(res.into_string_vec(), vec!["Null", "{a: 3, b: z}", "Null"]);
+    }
+
+    #[test]
+    fn test_append() {
+        let c = sample();
+        let other = StructColumn::new(vec![
+            ("a".to_string(), Array::new(vec![4i64])),
+            ("b".to_string(), Array::new(vec!["w".to_string()])),
+        ]);
+        let res = c.append(&other);
+        assert_eq!(res.len(), 4);
+        assert_eq!(res.is_null(), vec![false, true, false, false]);
+        assert_eq!(res.field("a"), Some(&Array::new(vec![1i64, 2, 3, 4])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_append_different_names() {
+        let other = StructColumn::new(vec![("a".to_string(), Array::new(vec![4i64]))]);
+        sample().append(&other);
+    }
+}
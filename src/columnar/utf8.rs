@@ -0,0 +1,567 @@
+use std::hash::{Hash, Hasher};
+use std::str;
+
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::{Append, Slicer, Stringify};
+
+/// Nullable `String` column which stores all values in a single byte buffer.
+///
+/// The i-th value is `values[offsets[i]..offsets[i + 1]]`. Compared to
+/// `NullVec<String>`, which allocates each element separately, indexing and appending
+/// only copy bytes.
+///
+/// Equality and hash are based on values, bytes under the mask and unused bytes
+/// in the buffer are ignored.
+#[derive(Clone, Debug)]
+pub struct Utf8Column {
+    values: Vec<u8>,
+    // always has len + 1 elements, starting with 0
+    offsets: Vec<u64>,
+    mask: Option<Vec<bool>>,
+}
+
+impl Utf8Column {
+    /// Create new `Utf8Column` from `Vec<String>`.
+    pub fn new(values: Vec<String>) -> Self {
+        Utf8Column::with_mask(values, None)
+    }
+
+    /// Create new `Utf8Column` from `Vec<String>` and mask.
+    ///
+    /// If mask is `true`, corresponding element is regarded as `Null`.
+    pub fn with_mask(values: Vec<String>, mask: Option<Vec<bool>>) -> Self {
+        if let Some(ref m) = mask {
+            assert!(
+                m.len() == values.len(),
+                "mask must be the same length as values"
+            );
+        }
+        let mut builder = Utf8ColumnBuilder::with_capacity(values.len());
+        match mask {
+            Some(ref m) => {
+                for (v, &is_null) in values.iter().zip(m.iter()) {
+                    if is_null {
+                        builder.push_null();
+                    } else {
+                        builder.push(v);
+                    }
+                }
+            }
+            None => {
+                for v in values.iter() {
+                    builder.push(v);
+                }
+            }
+        }
+        builder.finish()
+    }
+
+    /// Create new `Utf8Column` from raw buffers.
+    ///
+    /// # Panics
+    ///
+    /// - if `offsets` doesn't start with 0, isn't monotonically increasing or
+    ///   exceeds the length of `values`
+    /// - if `values` between offsets is not valid UTF-8
+    /// - if `mask` has different length
+    pub fn from_buffers(values: Vec<u8>, offsets: Vec<u64>, mask: Option<Vec<bool>>) -> Self {
+        assert!(
+            !offsets.is_empty() && offsets[0] == 0,
+            "offsets must start with 0"
+        );
+        assert!(
+            offsets.windows(2).all(|w| w[0] <= w[1]),
+            "offsets must be monotonically increasing"
+        );
+        assert!(
+            *offsets.last().unwrap() as usize <= values.len(),
+            "offsets must not exceed values"
+        );
+        for w in offsets.windows(2) {
+            assert!(
+                str::from_utf8(&values[w[0] as usize..w[1] as usize]).is_ok(),
+                "values must be valid UTF-8"
+            );
+        }
+        if let Some(ref m) = mask {
+            assert!(
+                m.len() == offsets.len() - 1,
+                "mask must be the same length as values"
+            );
+        }
+        Utf8Column {
+            values,
+            offsets,
+            mask: normalize_mask(mask),
+        }
+    }
+
+    /// Returns the byte buffer which stores all values.
+    pub fn values_buffer(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// Returns offsets of each value in the byte buffer.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+
+    /// Returns mask whether the corresponding value is `Null`.
+    pub fn mask(&self) -> Option<&[bool]> {
+        self.mask.as_ref().map(|m| &m[..])
+    }
+
+    /// Returns whether the column contains `Null`.
+    pub fn has_null(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Returns `Vec<bool>` whether the collesponding value is `Null`.
+    pub fn is_null(&self) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => mask.clone(),
+            None => vec![false; self.len()],
+        }
+    }
+
+    /// Returns the value specified with the location, `None` if the value is `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if specified location outs of bounds
+    pub fn value(&self, location: usize) -> Option<&str> {
+        assert!(location < self.len(), "Index out of bounds");
+        unsafe { self.value_unchecked(location) }
+    }
+
+    unsafe fn value_unchecked(&self, location: usize) -> Option<&str> {
+        if let Some(ref mask) = self.mask {
+            if *mask.get_unchecked(location) {
+                return None;
+            }
+        }
+        Some(self.raw_value_unchecked(location))
+    }
+
+    /// Returns the value ignoring mask
+    unsafe fn raw_value_unchecked(&self, location: usize) -> &str {
+        let start = *self.offsets.get_unchecked(location) as usize;
+        let end = *self.offsets.get_unchecked(location + 1) as usize;
+        // values are validated when created
+        str::from_utf8_unchecked(self.values.get_unchecked(start..end))
+    }
+
+    /// Convert to `NullVec<String>`.
+    pub fn to_nullvec(&self) -> NullVec<String> {
+        self.iter()
+            .map(|v| match v {
+                Some(v) => Nullable::Value(v.to_string()),
+                None => Nullable::Null,
+            })
+            .collect()
+    }
+
+    /// Returns Iterator which iterates `Option<&str>`, `None` means `Null`.
+    pub fn iter(&self) -> Utf8ColumnIter<'_> {
+        Utf8ColumnIter {
+            data: self,
+            current: 0,
+        }
+    }
+}
+
+/// Return `None` if mask doesn't contain `true`
+fn normalize_mask(mask: Option<Vec<bool>>) -> Option<Vec<bool>> {
+    mask.filter(|m| m.iter().any(|&x| x))
+}
+
+/// Build `Utf8Column` by appending values
+struct Utf8ColumnBuilder {
+    values: Vec<u8>,
+    offsets: Vec<u64>,
+    mask: Vec<bool>,
+}
+
+impl Utf8ColumnBuilder {
+    fn with_capacity(capacity: usize) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Utf8ColumnBuilder {
+            values: vec![],
+            offsets,
+            mask: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, value: &str) {
+        self.values.extend_from_slice(value.as_bytes());
+        self.offsets.push(self.values.len() as u64);
+        self.mask.push(false);
+    }
+
+    fn push_null(&mut self) {
+        self.offsets.push(self.values.len() as u64);
+        self.mask.push(true);
+    }
+
+    fn push_option(&mut self, value: Option<&str>) {
+        match value {
+            Some(v) => self.push(v),
+            None => self.push_null(),
+        }
+    }
+
+    fn finish(self) -> Utf8Column {
+        Utf8Column {
+            values: self.values,
+            offsets: self.offsets,
+            mask: normalize_mask(Some(self.mask)),
+        }
+    }
+}
+
+/// Iterator returns `Option<&str>`
+#[derive(Clone, Debug)]
+pub struct Utf8ColumnIter<'a> {
+    data: &'a Utf8Column,
+    current: usize,
+}
+
+impl<'a> Iterator for Utf8ColumnIter<'a> {
+    type Item = Option<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current < self.data.len() {
+            let result = unsafe { self.data.value_unchecked(self.current) };
+            self.current += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.data.len() - self.current;
+        (hint, Some(hint))
+    }
+}
+
+impl PartialEq for Utf8Column {
+    fn eq(&self, other: &Utf8Column) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for Utf8Column {}
+
+impl Hash for Utf8Column {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for v in self.iter() {
+            v.hash(state);
+        }
+    }
+}
+
+impl Slicer for Utf8Column {
+    type Scalar = Nullable<String>;
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        assert!(*location < self.len(), "Index out of bounds");
+        unsafe { self.iloc_unchecked(location) }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        match self.value_unchecked(*location) {
+            Some(v) => Nullable::Value(v.to_string()),
+            None => Nullable::Null,
+        }
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        unsafe { self.ilocs_unchecked(locations) }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        let mut builder = Utf8ColumnBuilder::with_capacity(locations.len());
+        for &loc in locations.iter() {
+            builder.push_option(self.value_unchecked(loc));
+        }
+        builder.finish()
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        let mut builder = Utf8ColumnBuilder::with_capacity(locations.len());
+        for &loc in locations.iter() {
+            if loc < len {
+                builder.push_option(unsafe { self.value_unchecked(loc) });
+            } else {
+                builder.push_null();
+            }
+        }
+        builder.finish()
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len() == flags.len(),
+            "flags must be the same length as values"
+        );
+        let mut builder = Utf8ColumnBuilder::with_capacity(self.len());
+        for (v, &f) in self.iter().zip(flags.iter()) {
+            if f {
+                builder.push_option(v);
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl Append for Utf8Column {
+    fn append(&self, other: &Utf8Column) -> Self {
+        let mut values = Vec::with_capacity(self.values.len() + other.values.len());
+        values.extend_from_slice(&self.values);
+        values.extend_from_slice(&other.values);
+
+        let base = self.values.len() as u64;
+        let mut offsets = Vec::with_capacity(self.offsets.len() + other.len());
+        offsets.extend_from_slice(&self.offsets);
+        offsets.extend(other.offsets[1..].iter().map(|o| o + base));
+
+        let mask = match (&self.mask, &other.mask) {
+            (&None, &None) => None,
+            _ => {
+                let mut mask = self.is_null();
+                mask.extend(other.is_null());
+                Some(mask)
+            }
+        };
+        Utf8Column {
+            values,
+            offsets,
+            mask,
+        }
+    }
+}
+
+impl Stringify for Utf8Column {
+    fn into_string_vec(&self) -> Vec<String> {
+        self.iter()
+            .map(|v| match v {
+                Some(v) => v.to_string(),
+                None => "Null".to_string(),
+            })
+            .collect()
+    }
+}
+
+// Conversion
+
+impl<'a> From<Vec<&'a str>> for Utf8Column {
+    fn from(values: Vec<&'a str>) -> Self {
+        let mut builder = Utf8ColumnBuilder::with_capacity(values.len());
+        for v in values {
+            builder.push(v);
+        }
+        builder.finish()
+    }
+}
+
+impl From<Vec<String>> for Utf8Column {
+    fn from(values: Vec<String>) -> Self {
+        Utf8Column::new(values)
+    }
+}
+
+impl From<NullVec<String>> for Utf8Column {
+    fn from(values: NullVec<String>) -> Self {
+        let mut builder = Utf8ColumnBuilder::with_capacity(values.len());
+        for (m, v) in values.iter_raw() {
+            if m {
+                builder.push_null();
+            } else {
+                builder.push(v);
+            }
+        }
+        builder.finish()
+    }
+}
+
+impl From<Utf8Column> for NullVec<String> {
+    fn from(values: Utf8Column) -> Self {
+        values.to_nullvec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use super::Utf8Column;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{Append, Slicer, Stringify};
+
+    #[test]
+    fn test_creation() {
+        let c = Utf8Column::from(vec!["a", "", "ccc"]);
+        assert_eq!(c.len(), 3);
+        assert_eq!(c.values_buffer(), b"accc");
+        assert_eq!(c.offsets(), &[0, 1, 1, 4]);
+        assert_eq!(c.mask(), None);
+        assert!(!c.has_null());
+
+        let c = Utf8Column::with_mask(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            Some(vec![false, true, false]),
+        );
+        assert_eq!(c.values_buffer(), b"ac");
+        assert_eq!(c.offsets(), &[0, 1, 1, 2]);
+        assert_eq!(c.is_null(), vec![false, true, false]);
+
+        // mask without null is removed
+        let c = Utf8Column::with_mask(vec!["a".to_string()], Some(vec![false]));
+        assert_eq!(c.mask(), None);
+    }
+
+    #[test]
+    fn test_from_buffers() {
+        let c = Utf8Column::from_buffers(b"abcd".to_vec(), vec![0, 2, 4], None);
+        assert_eq!(c, Utf8Column::from(vec!["ab", "cd"]));
+    }
+
+    #[test]
+    fn test_eq_hash() {
+        fn hash_of(c: &Utf8Column) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            c.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // bytes under the mask and after the last offset are ignored
+        let c1 = Utf8Column::from_buffers(
+            b"axbz".to_vec(),
+            vec![0, 1, 2, 3],
+            Some(vec![false, true, false]),
+        );
+        let c2 = Utf8Column::with_mask(
+            vec!["a".to_string(), "".to_string(), "b".to_string()],
+            Some(vec![false, true, false]),
+        );
+        assert_eq!(c1, c2);
+        assert_eq!(hash_of(&c1), hash_of(&c2));
+
+        assert!(c1 != Utf8Column::from(vec!["a", "", "b"]));
+        assert!(c1 != Utf8Column::from(vec!["a"]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_buffers_invalid_utf8() {
+        Utf8Column::from_buffers(vec![0xe3, 0x81, 0x82], vec![0, 1, 3], None);
+    }
+
+    #[test]
+    fn test_value_iter() {
+        let c = Utf8Column::with_mask(
+            vec!["あ".to_string(), "b".to_string(), "c".to_string()],
+            Some(vec![false, true, false]),
+        );
+        assert_eq!(c.value(0), Some("あ"));
+        assert_eq!(c.value(1), None);
+        let res: Vec<Option<&str>> = c.iter().collect();
+        assert_eq!(res, vec![Some("あ"), None, Some("c")]);
+    }
+
+    #[test]
+    fn test_iloc() {
+        let c = Utf8Column::with_mask(
+            vec!["a".to_string(), "b".to_string()],
+            Some(vec![false, true]),
+        );
+        assert_eq!(c.iloc(&0), Nullable::Value("a".to_string()));
+        assert_eq!(c.iloc(&1), Nullable::Null);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iloc_out_of_bounds() {
+        let c = Utf8Column::from(vec!["a", "b"]);
+        c.iloc(&2);
+    }
+
+    #[test]
+    fn test_ilocs() {
+        let c = Utf8Column::with_mask(
+            vec!["a".to_string(), "bb".to_string(), "ccc".to_string()],
+            Some(vec![false, true, false]),
+        );
+        let res = c.ilocs(&[2, 0]);
+        assert_eq!(res, Utf8Column::from(vec!["ccc", "a"]));
+        assert_eq!(res.offsets(), &[0, 3, 4]);
+
+        let res = c.ilocs(&[1, 2]);
+        assert_eq!(res.is_null(), vec![true, false]);
+
+        let res = c.ilocs_forced(&[0, 5]);
+        assert_eq!(res.iter().collect::<Vec<_>>(), vec![Some("a"), None]);
+    }
+
+    #[test]
+    fn test_blocs() {
+        let c = Utf8Column::from(vec!["a", "bb", "ccc"]);
+        let res = c.blocs(&[true, false, true]);
+        assert_eq!(res, Utf8Column::from(vec!["a", "ccc"]));
+    }
+
+    #[test]
+    fn test_append() {
+        let c1 = Utf8Column::from(vec!["a", "bb"]);
+        let c2 = Utf8Column::with_mask(
+            vec!["c".to_string(), "d".to_string()],
+            Some(vec![true, false]),
+        );
+        let res = c1.append(&c2);
+        assert_eq!(res.values_buffer(), b"abbd");
+        assert_eq!(res.offsets(), &[0, 1, 3, 3, 4]);
+        assert_eq!(res.is_null(), vec![false, false, true, false]);
+
+        let res = c1.append(&c1);
+        assert_eq!(res, Utf8Column::from(vec!["a", "bb", "a", "bb"]));
+    }
+
+    #[test]
+    fn test_into_string_vec() {
+        let c = Utf8Column::with_mask(
+            vec!["a".to_string(), "b".to_string()],
+            Some(vec![false, true]),
+        );
+        assert_eq!(
+            c.into_string_vec(),
+            vec!["a".to_string(), "Null".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_nullvec_conversion() {
+        let nv = NullVec::with_mask(
+            vec!["a".to_string(), "b".to_string()],
+            Some(vec![true, false]),
+        );
+        let c: Utf8Column = nv.clone().into();
+        assert_eq!(c.iter().collect::<Vec<_>>(), vec![None, Some("b")]);
+        let res: NullVec<String> = c.into();
+        assert_eq!(res.is_null(), nv.is_null());
+        assert_eq!(res.not_null_values(), vec!["b".to_string()]);
+    }
+}
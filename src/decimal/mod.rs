@@ -0,0 +1,558 @@
+//! Fixed-point decimal type which can be stored to `NullVec`.
+//!
+//! `Decimal` keeps an `i128` mantissa with precision (the number of significant
+//! digits) and scale (the number of digits after the decimal point), so that
+//! addition, subtraction, multiplication and summation are exact.
+//!
+//! # Examples
+//!
+//! ```
+//! use nullvec::prelude::*;
+//! let x = Decimal::parse("0.1").unwrap();
+//! let y = Decimal::parse("0.2").unwrap();
+//! assert_eq!((x + y).to_string(), "0.3");
+//! assert_eq!((x * y).to_string(), "0.02");
+//! assert_eq!((x / Decimal::parse("3").unwrap()).to_string(), "0.0");
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, ToPrimitive, Zero};
+
+use traits::NullStorable;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+/// Maximum precision which `i128` mantissa can hold.
+pub const MAX_PRECISION: u8 = 38;
+
+/// Returns `10^exp`.
+///
+/// # Panics
+///
+/// - if `exp` is larger than `MAX_PRECISION`
+fn pow10(exp: u8) -> i128 {
+    assert!(exp <= MAX_PRECISION, "decimal scale overflow");
+    10i128.pow(u32::from(exp))
+}
+
+/// Returns the number of decimal digits of `value`, 0 has 1 digit.
+fn digits(value: i128) -> u8 {
+    let mut value = value.unsigned_abs();
+    let mut n = 1;
+    while value >= 10 {
+        value /= 10;
+        n += 1;
+    }
+    n
+}
+
+/// Divide `num` by `den`, rounding half away from zero.
+fn div_round(num: i128, den: i128) -> i128 {
+    assert!(den != 0, "attempt to divide by zero");
+    let quot = num / den;
+    let rem = num % den;
+    if rem.unsigned_abs() >= den.unsigned_abs() - rem.unsigned_abs() {
+        if (num < 0) == (den < 0) {
+            quot + 1
+        } else {
+            quot - 1
+        }
+    } else {
+        quot
+    }
+}
+
+/// Fixed-point decimal number, `mantissa * 10^-scale`.
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal {
+    mantissa: i128,
+    precision: u8,
+    scale: u8,
+}
+
+impl Decimal {
+    /// Create new `Decimal` which represents `mantissa * 10^-scale`.
+    ///
+    /// # Panics
+    ///
+    /// - if `precision` is not between 1 and `MAX_PRECISION`
+    /// - if `scale` is larger than `precision`
+    /// - if `mantissa` has more digits than `precision`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let d = Decimal::new(-12345, 5, 2);
+    /// assert_eq!(d.to_string(), "-123.45");
+    /// ```
+    pub fn new(mantissa: i128, precision: u8, scale: u8) -> Self {
+        assert!(
+            (1..=MAX_PRECISION).contains(&precision),
+            "precision must be between 1 and 38"
+        );
+        assert!(
+            scale <= precision,
+            "scale must not be larger than precision"
+        );
+        assert!(
+            digits(mantissa) <= precision,
+            "mantissa must not have more digits than precision"
+        );
+        Decimal {
+            mantissa,
+            precision,
+            scale,
+        }
+    }
+
+    /// Create the result of an operation, widening precision to hold the scale
+    fn from_result(mantissa: i128, precision: u8, scale: u8) -> Self {
+        Decimal::checked_from_result(mantissa, precision, scale).expect("decimal overflow")
+    }
+
+    /// Same as `from_result`, but returns `None` if the mantissa has too many digits
+    fn checked_from_result(mantissa: i128, precision: u8, scale: u8) -> Option<Self> {
+        let precision = precision.clamp(scale.max(1), MAX_PRECISION);
+        if digits(mantissa) > precision {
+            return None;
+        }
+        Some(Decimal {
+            mantissa,
+            precision,
+            scale,
+        })
+    }
+
+    /// Parse `Decimal` from a string such as `"-123.45"`.
+    ///
+    /// Precision is the number of significant digits and scale is the number of
+    /// digits after the decimal point. Returns `None` if `value` can't be parsed
+    /// or has more than 38 digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let d = Decimal::parse("-0.050").unwrap();
+    /// assert_eq!((d.mantissa(), d.precision(), d.scale()), (-50, 3, 3));
+    /// assert_eq!(Decimal::parse("1e3"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Decimal> {
+        let (negative, body) = match value.as_bytes().first() {
+            Some(&b'-') => (true, &value[1..]),
+            Some(&b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+        let (int_part, frac_part) = match body.find('.') {
+            Some(pos) => (&body[..pos], &body[pos + 1..]),
+            None => (body, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        let int_digits = int_part.trim_start_matches('0').len();
+        if int_digits + frac_part.len() > MAX_PRECISION as usize {
+            return None;
+        }
+        let mut mantissa = 0i128;
+        for b in int_part.bytes().chain(frac_part.bytes()) {
+            if !b.is_ascii_digit() {
+                return None;
+            }
+            mantissa = mantissa * 10 + i128::from(b - b'0');
+        }
+        let scale = frac_part.len() as u8;
+        let precision = (int_digits as u8 + scale).max(1);
+        let mantissa = if negative { -mantissa } else { mantissa };
+        Some(Decimal::new(mantissa, precision, scale))
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Returns `Decimal` which has the specified scale.
+    ///
+    /// Decreasing scale rounds half away from zero.
+    ///
+    /// # Panics
+    ///
+    /// - if the result can't be represented in 38 digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let d = Decimal::parse("-2.345").unwrap();
+    /// assert_eq!(d.rescale(2).to_string(), "-2.35");
+    /// assert_eq!(d.rescale(5).to_string(), "-2.34500");
+    /// ```
+    pub fn rescale(&self, scale: u8) -> Self {
+        let int_digits = self.precision - self.scale;
+        if scale >= self.scale {
+            let mantissa = self
+                .mantissa
+                .checked_mul(pow10(scale - self.scale))
+                .expect("decimal overflow");
+            Decimal::from_result(mantissa, int_digits + scale, scale)
+        } else {
+            let mantissa = div_round(self.mantissa, pow10(self.scale - scale));
+            // rounding may carry to a new integer digit
+            Decimal::from_result(mantissa, int_digits + scale + 1, scale)
+        }
+    }
+
+    /// Returns `self / other` rounded half away from zero to `scale`.
+    ///
+    /// # Panics
+    ///
+    /// - if `other` is zero
+    /// - if the result can't be represented in 38 digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let x = Decimal::parse("2").unwrap();
+    /// let y = Decimal::parse("3").unwrap();
+    /// assert_eq!(x.div_with_scale(&y, 4).to_string(), "0.6667");
+    /// ```
+    pub fn div_with_scale(&self, other: &Decimal, scale: u8) -> Self {
+        assert!(scale <= MAX_PRECISION, "decimal scale overflow");
+        // (m1 / 10^s1) / (m2 / 10^s2) = m1 * 10^(s + s2 - s1) / m2 / 10^s
+        let exp = i32::from(scale) + i32::from(other.scale) - i32::from(self.scale);
+        let mantissa = if exp >= 0 {
+            let num = self
+                .mantissa
+                .checked_mul(pow10(exp as u8))
+                .expect("decimal overflow");
+            div_round(num, other.mantissa)
+        } else {
+            let den = other
+                .mantissa
+                .checked_mul(pow10((-exp) as u8))
+                .expect("decimal overflow");
+            div_round(self.mantissa, den)
+        };
+        let int_digits = (self.precision - self.scale) + other.scale;
+        Decimal::from_result(mantissa, int_digits.saturating_add(scale), scale)
+    }
+
+    /// Returns mantissas of `self` and `other` aligned to the larger scale,
+    /// `None` if either overflows
+    fn align(&self, other: &Decimal) -> Option<(i128, i128, u8)> {
+        let scale = self.scale.max(other.scale);
+        let lhs = self.mantissa.checked_mul(pow10(scale - self.scale))?;
+        let rhs = other.mantissa.checked_mul(pow10(scale - other.scale))?;
+        Some((lhs, rhs, scale))
+    }
+
+    /// Returns the precision which can hold the sum of `self` and `other`
+    fn sum_precision(&self, other: &Decimal, scale: u8) -> u8 {
+        let int_digits = (self.precision - self.scale).max(other.precision - other.scale);
+        int_digits + scale + 1
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Decimal::zero()
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        // compare integer parts first not to overflow by aligning scales
+        let lunit = pow10(self.scale);
+        let runit = pow10(other.scale);
+        let lint = self.mantissa.div_euclid(lunit);
+        let rint = other.mantissa.div_euclid(runit);
+        lint.cmp(&rint).then_with(|| {
+            let scale = self.scale.max(other.scale);
+            let lfrac = self.mantissa.rem_euclid(lunit) * pow10(scale - self.scale);
+            let rfrac = other.mantissa.rem_euclid(runit) * pow10(scale - other.scale);
+            lfrac.cmp(&rfrac)
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.mantissa < 0 {
+            write!(f, "-")?;
+        }
+        let abs = self.mantissa.unsigned_abs();
+        if self.scale == 0 {
+            write!(f, "{}", abs)
+        } else {
+            let unit = pow10(self.scale) as u128;
+            write!(
+                f,
+                "{}.{:0width$}",
+                abs / unit,
+                abs % unit,
+                width = self.scale as usize
+            )
+        }
+    }
+}
+
+impl NullStorable for Decimal {}
+
+impl ToPrimitive for Decimal {
+    fn to_i64(&self) -> Option<i64> {
+        (self.mantissa / pow10(self.scale)).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self.mantissa / pow10(self.scale)).to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.mantissa as f64 / pow10(self.scale) as f64)
+    }
+}
+
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Decimal) -> Decimal {
+        CheckedAdd::checked_add(&self, &other).expect("decimal overflow")
+    }
+}
+
+impl CheckedAdd for Decimal {
+    fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let (lhs, rhs, scale) = self.align(other)?;
+        let mantissa = lhs.checked_add(rhs)?;
+        Decimal::checked_from_result(mantissa, self.sum_precision(other, scale), scale)
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: Decimal) -> Decimal {
+        CheckedSub::checked_sub(&self, &other).expect("decimal overflow")
+    }
+}
+
+impl CheckedSub for Decimal {
+    fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        let (lhs, rhs, scale) = self.align(other)?;
+        let mantissa = lhs.checked_sub(rhs)?;
+        Decimal::checked_from_result(mantissa, self.sum_precision(other, scale), scale)
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: Decimal) -> Decimal {
+        assert!(
+            self.scale + other.scale <= MAX_PRECISION,
+            "decimal scale overflow"
+        );
+        CheckedMul::checked_mul(&self, &other).expect("decimal overflow")
+    }
+}
+
+impl CheckedMul for Decimal {
+    /// Returns `None` also if the scale of the result exceeds 38.
+    fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        let scale = self.scale + other.scale;
+        if scale > MAX_PRECISION {
+            return None;
+        }
+        let mantissa = self.mantissa.checked_mul(other.mantissa)?;
+        Decimal::checked_from_result(mantissa, self.precision + other.precision, scale)
+    }
+}
+
+impl Div for Decimal {
+    type Output = Decimal;
+
+    /// Divide keeping the larger scale of both operands. Use `div_with_scale`
+    /// to specify the scale of the result.
+    fn div(self, other: Decimal) -> Decimal {
+        self.div_with_scale(&other, self.scale.max(other.scale))
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal {
+            mantissa: -self.mantissa,
+            precision: self.precision,
+            scale: self.scale,
+        }
+    }
+}
+
+impl Zero for Decimal {
+    fn zero() -> Self {
+        Decimal {
+            mantissa: 0,
+            precision: 1,
+            scale: 0,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Decimal;
+    use num_traits::ToPrimitive;
+
+    fn d(value: &str) -> Decimal {
+        Decimal::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let x = Decimal::new(12345, 5, 2);
+        assert_eq!(x.mantissa(), 12345);
+        assert_eq!(x.precision(), 5);
+        assert_eq!(x.scale(), 2);
+        assert_eq!(x.to_string(), "123.45");
+        assert_eq!(Decimal::new(-5, 3, 3).to_string(), "-0.005");
+        assert_eq!(Decimal::new(0, 1, 0).to_string(), "0");
+        assert_eq!(Decimal::default(), d("0.00"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_too_many_digits() {
+        Decimal::new(12345, 4, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_invalid_scale() {
+        Decimal::new(1, 2, 3);
+    }
+
+    #[test]
+    fn test_parse() {
+        let x = d("0012.340");
+        assert_eq!((x.mantissa(), x.precision(), x.scale()), (12340, 5, 3));
+        let x = d("+.5");
+        assert_eq!((x.mantissa(), x.precision(), x.scale()), (5, 1, 1));
+        let x = d("-7.");
+        assert_eq!((x.mantissa(), x.precision(), x.scale()), (-7, 1, 0));
+        let x = d("99999999999999999999999999999999999999");
+        assert_eq!(x.precision(), 38);
+
+        assert_eq!(Decimal::parse(""), None);
+        assert_eq!(Decimal::parse("-"), None);
+        assert_eq!(Decimal::parse("."), None);
+        assert_eq!(Decimal::parse("1.2.3"), None);
+        assert_eq!(Decimal::parse("1,000"), None);
+        assert_eq!(Decimal::parse("--1"), None);
+        assert_eq!(
+            Decimal::parse("999999999999999999999999999999999999999"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert_eq!(d("1.0"), d("1"));
+        assert_eq!(d("-0.00"), d("0"));
+        assert!(d("1.05") > d("1.049"));
+        assert!(d("-1.05") < d("-1.049"));
+        assert!(d("-0.5") < d("0.1"));
+        assert!(
+            d("99999999999999999999999999999999999999")
+                > d("0.9999999999999999999999999999999999999")
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = d("1.25");
+        let y = d("-0.3");
+
+        let res = x + y;
+        assert_eq!(res.to_string(), "0.95");
+        assert_eq!((res.precision(), res.scale()), (4, 2));
+
+        assert_eq!((x - y).to_string(), "1.55");
+        assert_eq!((x * y).to_string(), "-0.375");
+        assert_eq!((x / y).to_string(), "-4.17");
+        assert_eq!((-x).to_string(), "-1.25");
+        assert_eq!(x.div_with_scale(&y, 0).to_string(), "-4");
+        assert_eq!(d("2.5").div_with_scale(&d("1"), 0).to_string(), "3");
+        assert_eq!(d("-2.5").div_with_scale(&d("1"), 0).to_string(), "-3");
+        assert_eq!(d("1").div_with_scale(&d("0.001"), 0).to_string(), "1000");
+
+        // exact where float is not
+        let mut sum = d("0");
+        for _ in 0..10 {
+            sum = sum + d("0.1");
+        }
+        assert_eq!(sum, d("1"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_zero() {
+        let _ = d("1") / d("0.0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflow() {
+        let x = d("99999999999999999999999999999999999999");
+        let _ = x + x;
+    }
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(d("9.995").rescale(2).to_string(), "10.00");
+        assert_eq!(d("1.5").rescale(3).to_string(), "1.500");
+        assert_eq!(d("-0.5").rescale(0).to_string(), "-1");
+        assert_eq!(d("0.4").rescale(0).to_string(), "0");
+    }
+
+    #[test]
+    fn test_to_primitive() {
+        assert_eq!(d("-1.75").to_f64(), Some(-1.75));
+        assert_eq!(d("-1.75").to_i64(), Some(-1));
+        assert_eq!(d("-1.75").to_u64(), None);
+    }
+}
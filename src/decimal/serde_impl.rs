@@ -0,0 +1,52 @@
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Decimal;
+
+/// Serialized as a string such as `"-123.45"` not to lose digits. Precision is
+/// restored as the number of significant digits, the same as `Decimal::parse`.
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("decimal string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Decimal, E> {
+        Decimal::parse(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DecimalVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use decimal::Decimal;
+    use serde_json;
+
+    #[test]
+    fn test_decimal_roundtrip() {
+        let d = Decimal::new(-12345, 5, 2);
+        let s = serde_json::to_string(&d).unwrap();
+        assert_eq!(s, "\"-123.45\"");
+        let res: Decimal = serde_json::from_str(&s).unwrap();
+        assert_eq!(res, d);
+        assert_eq!((res.precision(), res.scale()), (5, 2));
+        assert!(serde_json::from_str::<Decimal>("\"1e3\"").is_err());
+    }
+}
@@ -0,0 +1,535 @@
+//! Two-dimensional table which consists of named `Array` columns.
+//!
+//! # Examples
+//!
+//! ```
+//! use nullvec::prelude::*;
+//!
+//! let df = DataFrame::new(vec![
+//!     ("a".to_string(), Array::new(vec![3i64, 1, 2])),
+//!     ("b".to_string(), Array::new(vec!["x", "y", "z"])),
+//! ]);
+//! let sorted = df.sort_by(&["a"]).unwrap();
+//! assert_eq!(sorted.column("b"), Some(&Array::new(vec!["y", "z", "x"])));
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use generic::{Array, DtypeError, Scalar};
+use nullvec::NullVec;
+use traits::{Append, Slicer, Stringify};
+
+/// Table which stores ordered named `Array` columns of the same length.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataFrame {
+    names: Vec<String>,
+    columns: Vec<Array>,
+    len: usize,
+}
+
+impl DataFrame {
+    /// Create new `DataFrame` from named columns.
+    ///
+    /// # Panics
+    ///
+    /// - if names are duplicated
+    /// - if columns have different lengths
+    pub fn new(columns: Vec<(String, Array)>) -> Self {
+        let mut df = DataFrame {
+            names: vec![],
+            columns: vec![],
+            len: columns.first().map_or(0, |c| c.1.len()),
+        };
+        for (name, values) in columns.into_iter() {
+            let loc = df.names.len();
+            df.insert(loc, &name, values);
+        }
+        df
+    }
+
+    /// Returns column names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns columns in the same order as names.
+    pub fn columns(&self) -> &[Array] {
+        &self.columns
+    }
+
+    /// Returns dtype of each column.
+    pub fn dtypes(&self) -> Vec<String> {
+        self.columns.iter().map(|c| c.dtype()).collect()
+    }
+
+    /// Returns the column specified with the name, `None` if it doesn't exist.
+    pub fn column(&self, name: &str) -> Option<&Array> {
+        self.position(name).map(|i| &self.columns[i])
+    }
+
+    /// Returns a new `DataFrame` which consists of specified columns.
+    ///
+    /// # Panics
+    ///
+    /// - if any of names doesn't exist
+    pub fn select(&self, names: &[&str]) -> Self {
+        let columns = names
+            .iter()
+            .map(|&name| (name.to_string(), self.get_column(name).clone()))
+            .collect();
+        DataFrame::new(columns)
+    }
+
+    /// Insert a column at the specified location.
+    ///
+    /// # Panics
+    ///
+    /// - if the name already exists
+    /// - if the column has different length
+    /// - if the location is larger than the number of columns
+    pub fn insert(&mut self, location: usize, name: &str, values: Array) {
+        assert!(self.position(name).is_none(), "column names must be unique");
+        if self.columns.is_empty() {
+            self.len = values.len();
+        }
+        assert!(
+            values.len() == self.len,
+            "columns must have the same length"
+        );
+        self.names.insert(location, name.to_string());
+        self.columns.insert(location, values);
+    }
+
+    /// Remove the column specified with the name and return it.
+    ///
+    /// # Panics
+    ///
+    /// - if the name doesn't exist
+    pub fn remove(&mut self, name: &str) -> Array {
+        let loc = self
+            .position(name)
+            .unwrap_or_else(|| panic!("column {} doesn't exist", name));
+        self.names.remove(loc);
+        self.columns.remove(loc)
+    }
+
+    /// Concatenate columns of `other` to the right.
+    ///
+    /// # Panics
+    ///
+    /// - if names are duplicated
+    /// - if `other` has different length
+    pub fn concat_columns(&self, other: &DataFrame) -> Self {
+        let mut df = self.clone();
+        for (name, values) in other.names.iter().zip(other.columns.iter()) {
+            let loc = df.names.len();
+            df.insert(loc, name, values.clone());
+        }
+        df
+    }
+
+    /// Returns the first `n` rows.
+    pub fn head(&self, n: usize) -> Self {
+        let indexer: Vec<usize> = (0..n.min(self.len)).collect();
+        unsafe { self.ilocs_unchecked(&indexer) }
+    }
+
+    /// Returns the last `n` rows.
+    pub fn tail(&self, n: usize) -> Self {
+        let indexer: Vec<usize> = (self.len - n.min(self.len)..self.len).collect();
+        unsafe { self.ilocs_unchecked(&indexer) }
+    }
+
+    /// Sort rows by the specified columns in ascending order.
+    ///
+    /// Sort is stable, and `Null` is placed last. Categorical is sorted in the order
+    /// of its categories. Returns an error if any of the columns is list or struct,
+    /// whose values cannot be compared.
+    ///
+    /// # Panics
+    ///
+    /// - if any of names doesn't exist
+    pub fn sort_by(&self, names: &[&str]) -> Result<Self, DtypeError> {
+        let keys: Vec<&Array> = names.iter().map(|&name| self.get_column(name)).collect();
+        for key in keys.iter() {
+            if let Array::ListArray(_) | Array::StructArray(_) = **key {
+                return Err(DtypeError::new("sort", &key.dtype()));
+            }
+        }
+        // categoricals are compared by codes rather than values
+        let codes: Vec<Option<Array>> = keys
+            .iter()
+            .map(|key| match **key {
+                Array::CategoricalArray(ref c) => Some(Array::UInt32Array(c.codes().clone())),
+                _ => None,
+            })
+            .collect();
+        let keys: Vec<&Array> = keys
+            .iter()
+            .zip(codes.iter())
+            .map(|(key, codes)| codes.as_ref().unwrap_or(key))
+            .collect();
+        let mut indexer: Vec<usize> = (0..self.len).collect();
+        indexer.sort_by(|&l, &r| {
+            for key in keys.iter() {
+                let (lv, rv) = unsafe { (key.iloc_unchecked(&l), key.iloc_unchecked(&r)) };
+                match compare_scalar(&lv, &rv) {
+                    Ordering::Equal => continue,
+                    ord => return ord,
+                }
+            }
+            Ordering::Equal
+        });
+        Ok(unsafe { self.ilocs_unchecked(&indexer) })
+    }
+
+    /// Returns rows whose condition is `true`. `Null` is regarded as `false`.
+    ///
+    /// # Panics
+    ///
+    /// - if `condition` has different length
+    pub fn filter(&self, condition: &NullVec<bool>) -> Self {
+        assert!(
+            condition.len() == self.len,
+            "condition must be the same length as rows"
+        );
+        let flags: Vec<bool> = condition
+            .iter_raw()
+            .map(|(is_null, &v)| !is_null && v)
+            .collect();
+        self.blocs(&flags)
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn get_column(&self, name: &str) -> &Array {
+        self.column(name)
+            .unwrap_or_else(|| panic!("column {} doesn't exist", name))
+    }
+
+    fn with_columns(&self, columns: Vec<Array>, len: usize) -> Self {
+        DataFrame {
+            names: self.names.clone(),
+            columns,
+            len,
+        }
+    }
+}
+
+/// Compare scalars of the same dtype, `Null` is regarded as the largest.
+///
+/// List and struct scalars must be rejected beforehand.
+fn compare_scalar(left: &Scalar, right: &Scalar) -> Ordering {
+    match (left, right) {
+        (Scalar::Null, Scalar::Null) => Ordering::Equal,
+        (Scalar::Null, _) => Ordering::Greater,
+        (_, Scalar::Null) => Ordering::Less,
+        (Scalar::i64(l), Scalar::i64(r)) => l.cmp(r),
+        (Scalar::i32(l), Scalar::i32(r)) => l.cmp(r),
+        (Scalar::i16(l), Scalar::i16(r)) => l.cmp(r),
+        (Scalar::i8(l), Scalar::i8(r)) => l.cmp(r),
+        (Scalar::isize(l), Scalar::isize(r)) => l.cmp(r),
+        (Scalar::u64(l), Scalar::u64(r)) => l.cmp(r),
+        (Scalar::u32(l), Scalar::u32(r)) => l.cmp(r),
+        (Scalar::u16(l), Scalar::u16(r)) => l.cmp(r),
+        (Scalar::u8(l), Scalar::u8(r)) => l.cmp(r),
+        (Scalar::usize(l), Scalar::usize(r)) => l.cmp(r),
+        (Scalar::i128(l), Scalar::i128(r)) => l.cmp(r),
+        (Scalar::u128(l), Scalar::u128(r)) => l.cmp(r),
+        // NaN is stored as Null, so floats are always comparable
+        (Scalar::f64(l), Scalar::f64(r)) => l.partial_cmp(r).unwrap(),
+        (Scalar::f32(l), Scalar::f32(r)) => l.partial_cmp(r).unwrap(),
+        (Scalar::bool(l), Scalar::bool(r)) => l.cmp(r),
+        (Scalar::char(l), Scalar::char(r)) => l.cmp(r),
+        (Scalar::String(l), Scalar::String(r)) => l.cmp(r),
+        (Scalar::Binary(l), Scalar::Binary(r)) => l.cmp(r),
+        (Scalar::Date32(l), Scalar::Date32(r)) => l.cmp(r),
+        (Scalar::Timestamp(l), Scalar::Timestamp(r)) => l.cmp(r),
+        (Scalar::Duration(l), Scalar::Duration(r)) => l.cmp(r),
+        (Scalar::Decimal(l), Scalar::Decimal(r)) => l.cmp(r),
+        _ => panic!("unable to compare {} and {}", left.dtype(), right.dtype()),
+    }
+}
+
+impl Slicer for DataFrame {
+    type Scalar = Scalar;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the row as `Scalar::Struct`.
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        assert!(*location < self.len, "Index out of bounds");
+        unsafe { self.iloc_unchecked(location) }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        let values = self
+            .names
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(n, c)| (n.clone(), c.iloc_unchecked(location)))
+            .collect();
+        Scalar::Struct(values)
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len;
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        unsafe { self.ilocs_unchecked(locations) }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.ilocs_unchecked(locations))
+            .collect();
+        self.with_columns(columns, locations.len())
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.ilocs_forced(locations))
+            .collect();
+        self.with_columns(columns, locations.len())
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len == flags.len(),
+            "flags must be the same length as rows"
+        );
+        let columns = self.columns.iter().map(|c| c.blocs(flags)).collect();
+        self.with_columns(columns, flags.iter().filter(|&&f| f).count())
+    }
+}
+
+impl Append for DataFrame {
+    fn append(&self, other: &DataFrame) -> Self {
+        assert!(
+            self.names == other.names,
+            "DataFrame must have the same column names"
+        );
+        assert!(
+            self.dtypes() == other.dtypes(),
+            "DataFrame must have the same dtypes"
+        );
+        let columns = self
+            .columns
+            .iter()
+            .zip(other.columns.iter())
+            .map(|(l, r)| l.append(r))
+            .collect();
+        self.with_columns(columns, self.len + other.len)
+    }
+}
+
+impl fmt::Display for DataFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index: Vec<String> = (0..self.len).map(|i| i.to_string()).collect();
+        let mut cells: Vec<(String, Vec<String>)> = vec![(String::new(), index)];
+        for (name, values) in self.names.iter().zip(self.columns.iter()) {
+            cells.push((name.clone(), values.into_string_vec()));
+        }
+        let widths: Vec<usize> = cells
+            .iter()
+            .map(|(name, values)| {
+                values
+                    .iter()
+                    .map(|v| v.chars().count())
+                    .fold(name.chars().count(), usize::max)
+            })
+            .collect();
+
+        let header: Vec<String> = cells
+            .iter()
+            .zip(widths.iter())
+            .map(|((name, _), &w)| format!("{:>w$}", name, w = w))
+            .collect();
+        write!(f, "{}", header.join("  ").trim_end())?;
+        for i in 0..self.len {
+            let row: Vec<String> = cells
+                .iter()
+                .zip(widths.iter())
+                .map(|((_, values), &w)| format!("{:>w$}", values[i], w = w))
+                .collect();
+            write!(f, "\n{}", row.join("  "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::DataFrame;
+    use columnar::ListColumn;
+    use generic::{Array, Scalar};
+    use nullvec::NullVec;
+    use traits::{Append, Slicer};
+
+    fn sample() -> DataFrame {
+        DataFrame::new(vec![
+            (
+                "a".to_string(),
+                Array::Int64Array(NullVec::with_mask(
+                    vec![2, 1, 0, 1],
+                    Some(vec![false, false, true, false]),
+                )),
+            ),
+            ("b".to_string(), Array::new(vec!["w", "x", "y", "z"])),
+            ("c".to_string(), Array::new(vec![1.5, 2.5, 3.5, 0.5])),
+        ])
+    }
+
+    #[test]
+    fn test_creation() {
+        let df = sample();
+        assert_eq!(df.len(), 4);
+        assert_eq!(
+            df.names(),
+            &["a".to_string(), "b".to_string(), "c".to_string()]
+        );
+        assert_eq!(df.dtypes(), vec!["i64", "str", "f64"]);
+        assert_eq!(df.column("b"), Some(&Array::new(vec!["w", "x", "y", "z"])));
+        assert_eq!(df.column("d"), None);
+
+        let df = DataFrame::new(vec![]);
+        assert_eq!(df.len(), 0);
+        assert_eq!(df.columns().len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_different_length() {
+        DataFrame::new(vec![
+            ("a".to_string(), Array::new(vec![1i64, 2])),
+            ("b".to_string(), Array::new(vec![1i64])),
+        ]);
+    }
+
+    #[test]
+    fn test_columns() {
+        let mut df = sample().select(&["c", "a"]);
+        assert_eq!(df.names(), &["c".to_string(), "a".to_string()]);
+
+        df.insert(1, "d", Array::new(vec![true, false, true, false]));
+        assert_eq!(
+            df.names(),
+            &["c".to_string(), "d".to_string(), "a".to_string()]
+        );
+
+        let removed = df.remove("c");
+        assert_eq!(removed, Array::new(vec![1.5, 2.5, 3.5, 0.5]));
+        assert_eq!(df.names(), &["d".to_string(), "a".to_string()]);
+
+        let res = df.concat_columns(&sample().select(&["b"]));
+        assert_eq!(
+            res.names(),
+            &["d".to_string(), "a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_duplicated() {
+        let mut df = sample();
+        df.insert(0, "a", Array::new(vec![1i64, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_slicer() {
+        let df = sample();
+        let exp = Scalar::Struct(vec![
+            ("a".to_string(), Scalar::i64(1)),
+            ("b".to_string(), Scalar::String("x".to_string())),
+            ("c".to_string(), Scalar::f64(2.5)),
+        ]);
+        assert_eq!(df.iloc(&1), exp);
+
+        let res = df.ilocs(&[3, 0]);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res.column("b"), Some(&Array::new(vec!["z", "w"])));
+
+        let res = df.blocs(&[true, false, false, true]);
+        assert_eq!(res.column("c"), Some(&Array::new(vec![1.5, 0.5])));
+
+        assert_eq!(df.head(2), df.ilocs(&[0, 1]));
+        assert_eq!(df.tail(3), df.ilocs(&[1, 2, 3]));
+        assert_eq!(df.head(10), df);
+        assert_eq!(df.tail(0).len(), 0);
+    }
+
+    #[test]
+    fn test_append() {
+        let df = sample();
+        let res = df.append(&df.head(1));
+        assert_eq!(res.len(), 5);
+        assert_eq!(
+            res.column("b"),
+            Some(&Array::new(vec!["w", "x", "y", "z", "w"]))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_append_different_dtypes() {
+        let other = DataFrame::new(vec![
+            ("a".to_string(), Array::new(vec![1.0])),
+            ("b".to_string(), Array::new(vec!["w"])),
+            ("c".to_string(), Array::new(vec![1.0])),
+        ]);
+        sample().append(&other);
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let df = sample();
+        let res = df.sort_by(&["a"]).unwrap();
+        assert_eq!(res.column("b"), Some(&Array::new(vec!["x", "z", "w", "y"])));
+
+        let res = df.sort_by(&["a", "c"]).unwrap();
+        assert_eq!(res.column("b"), Some(&Array::new(vec!["z", "x", "w", "y"])));
+    }
+
+    #[test]
+    fn test_sort_by_unsupported_dtype() {
+        let mut df = sample();
+        let rows = (0..df.len())
+            .map(|i| Some(Array::new(vec![i as i64])))
+            .collect();
+        df.insert(0, "l", ListColumn::from_rows(rows).into());
+        let err = df.sort_by(&["a", "l"]).unwrap_err();
+        assert_eq!(err.operation(), "sort");
+        assert_eq!(err.dtype(), "list");
+    }
+
+    #[test]
+    fn test_filter() {
+        let df = sample();
+        let cond = NullVec::with_mask(
+            vec![true, true, false, true],
+            Some(vec![false, true, false, false]),
+        );
+        let res = df.filter(&cond);
+        assert_eq!(res.column("b"), Some(&Array::new(vec!["w", "z"])));
+    }
+
+    #[test]
+    fn test_display() {
+        let df = sample();
+        let exp = "      a  b    c
+0     2  w  1.5
+1     1  x  2.5
+2  Null  y  3.5
+3     1  z  0.5";
+        assert_eq!(format!("{}", df), exp);
+    }
+}
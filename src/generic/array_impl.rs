@@ -1,4 +1,6 @@
-use super::Array;
+use super::{Array, DtypeError, Scalar};
+use columnar::Categorical;
+use nullvec::NullVec;
 use traits::{Stringify, Append};
 
 impl Array {
@@ -21,10 +23,22 @@ impl Array {
             &Array::UInt16Array(_) => "u16".to_string(),
             &Array::UInt8Array(_) => "u8".to_string(),
             &Array::UsizeArray(_) => "usize".to_string(),
+            &Array::Int128Array(_) => "i128".to_string(),
+            &Array::UInt128Array(_) => "u128".to_string(),
             &Array::Float64Array(_) => "f64".to_string(),
             &Array::Float32Array(_) => "f32".to_string(),
             &Array::BoolArray(_) => "bool".to_string(),
+            &Array::CharArray(_) => "char".to_string(),
             &Array::StringArray(_) => "str".to_string(),
+            &Array::Utf8Array(_) => "utf8".to_string(),
+            &Array::BinaryArray(_) => "binary".to_string(),
+            &Array::ListArray(_) => "list".to_string(),
+            &Array::StructArray(_) => "struct".to_string(),
+            &Array::CategoricalArray(_) => "category".to_string(),
+            &Array::Date32Array(_) => "date32".to_string(),
+            &Array::TimestampArray(_) => "timestamp".to_string(),
+            &Array::DurationArray(_) => "duration".to_string(),
+            &Array::DecimalArray(_) => "decimal".to_string(),
         }
     }
 
@@ -40,10 +54,146 @@ impl Array {
             &Array::UInt16Array(_) => true,
             &Array::UInt8Array(_) => true,
             &Array::UsizeArray(_) => true,
+            &Array::Int128Array(_) => true,
+            &Array::UInt128Array(_) => true,
             &Array::Float64Array(_) => true,
             &Array::Float32Array(_) => true,
             &Array::BoolArray(_) => false,
+            &Array::CharArray(_) => false,
             &Array::StringArray(_) => false,
+            &Array::Utf8Array(_) => false,
+            &Array::BinaryArray(_) => false,
+            &Array::ListArray(_) => false,
+            &Array::StructArray(_) => false,
+            &Array::CategoricalArray(_) => false,
+            &Array::Date32Array(_) => false,
+            &Array::TimestampArray(_) => false,
+            &Array::DurationArray(_) => false,
+            &Array::DecimalArray(_) => true,
+        }
+    }
+
+    /// Returns `Array` shifted by `periods`, filling vacated locations with `fill_value`.
+    /// See `NullVec::shift`.
+    ///
+    /// Returns `DtypeError` if `fill_value` is not `Scalar::Null` and its dtype differs
+    /// from the array. List and struct arrays can only be filled with `Scalar::Null`,
+    /// and categorical arrays only with one of the categories.
+    pub fn shift(&self, periods: isize, fill_value: Scalar) -> Result<Array, DtypeError> {
+        let supported = match (self, &fill_value) {
+            (_, Scalar::Null) => true,
+            (Array::ListArray(_), _) | (Array::StructArray(_), _) => false,
+            (Array::Utf8Array(_), Scalar::String(_)) => true,
+            (Array::CategoricalArray(vals), Scalar::String(v)) => vals.categories().contains(v),
+            _ => fill_value.dtype() == self.dtype(),
+        };
+        if !supported {
+            let operation = format!("shift with {} fill value", fill_value.dtype());
+            return Err(DtypeError::new(&operation, &self.dtype()));
+        }
+        Ok(match *self {
+            Array::Int64Array(ref vals) => {
+                Array::Int64Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::Int32Array(ref vals) => {
+                Array::Int32Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::Int16Array(ref vals) => {
+                Array::Int16Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.shift(periods, fill_value.into())),
+            Array::IsizeArray(ref vals) => {
+                Array::IsizeArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::UInt64Array(ref vals) => {
+                Array::UInt64Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::UInt32Array(ref vals) => {
+                Array::UInt32Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::UInt16Array(ref vals) => {
+                Array::UInt16Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::UInt8Array(ref vals) => {
+                Array::UInt8Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::UsizeArray(ref vals) => {
+                Array::UsizeArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::Int128Array(ref vals) => {
+                Array::Int128Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::UInt128Array(ref vals) => {
+                Array::UInt128Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::Float64Array(ref vals) => {
+                Array::Float64Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::Float32Array(ref vals) => {
+                Array::Float32Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::BoolArray(ref vals) => Array::BoolArray(vals.shift(periods, fill_value.into())),
+            Array::CharArray(ref vals) => Array::CharArray(vals.shift(periods, fill_value.into())),
+            Array::StringArray(ref vals) => {
+                Array::StringArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::Utf8Array(ref vals) => {
+                Array::Utf8Array(vals.to_nullvec().shift(periods, fill_value.into()).into())
+            }
+            Array::BinaryArray(ref vals) => {
+                Array::BinaryArray(vals.to_nullvec().shift(periods, fill_value.into()).into())
+            }
+            Array::ListArray(ref vals) => Array::ListArray(vals.shift(periods)),
+            Array::StructArray(ref vals) => Array::StructArray(vals.shift(periods)),
+            Array::CategoricalArray(ref vals) => {
+                Array::CategoricalArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::Date32Array(ref vals) => {
+                Array::Date32Array(vals.shift(periods, fill_value.into()))
+            }
+            Array::TimestampArray(ref vals) => {
+                Array::TimestampArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::DurationArray(ref vals) => {
+                Array::DurationArray(vals.shift(periods, fill_value.into()))
+            }
+            Array::DecimalArray(ref vals) => {
+                Array::DecimalArray(vals.shift(periods, fill_value.into()))
+            }
+        })
+    }
+
+    /// Cast string-like `Array` to the specified dtype.
+    ///
+    /// Supported dtypes are `"str"`, `"utf8"` and `"category"`. Casting to
+    /// `"category"` creates unordered categories from sorted unique values.
+    /// Returns an error if the `Array` is not string-like or `dtype` is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let arr = Array::new(vec!["b", "a", "b"]);
+    /// let cat = arr.cast("category").unwrap();
+    /// assert_eq!(cat.dtype(), "category");
+    /// assert_eq!(cat.cast("str"), Ok(arr));
+    /// ```
+    pub fn cast(&self, dtype: &str) -> Result<Array, DtypeError> {
+        if self.dtype() == dtype {
+            return Ok(self.clone());
+        }
+        let err = || DtypeError::new(&format!("cast to {}", dtype), &self.dtype());
+        let values: NullVec<String> = match *self {
+            Array::StringArray(ref vals) => vals.clone(),
+            Array::Utf8Array(ref vals) => vals.to_nullvec(),
+            Array::CategoricalArray(ref vals) => vals.to_nullvec(),
+            _ => return Err(err()),
+        };
+        match dtype {
+            "str" => Ok(Array::StringArray(values)),
+            "utf8" => Ok(Array::Utf8Array(values.into())),
+            "category" => Ok(Array::CategoricalArray(Categorical::new(&values, false))),
+            _ => Err(err()),
         }
     }
 }
@@ -61,10 +211,22 @@ impl Stringify for Array {
             &Array::UInt16Array(ref vals) => vals.into_string_vec(),
             &Array::UInt8Array(ref vals) => vals.into_string_vec(),
             &Array::UsizeArray(ref vals) => vals.into_string_vec(),
+            &Array::Int128Array(ref vals) => vals.into_string_vec(),
+            &Array::UInt128Array(ref vals) => vals.into_string_vec(),
             &Array::Float64Array(ref vals) => vals.into_string_vec(),
             &Array::Float32Array(ref vals) => vals.into_string_vec(),
             &Array::BoolArray(ref vals) => vals.into_string_vec(),
+            &Array::CharArray(ref vals) => vals.into_string_vec(),
             &Array::StringArray(ref vals) => vals.into_string_vec(),
+            &Array::Utf8Array(ref vals) => vals.into_string_vec(),
+            &Array::BinaryArray(ref vals) => vals.into_string_vec(),
+            &Array::ListArray(ref vals) => vals.into_string_vec(),
+            &Array::StructArray(ref vals) => vals.into_string_vec(),
+            &Array::CategoricalArray(ref vals) => vals.into_string_vec(),
+            &Array::Date32Array(ref vals) => vals.into_string_vec(),
+            &Array::TimestampArray(ref vals) => vals.into_string_vec(),
+            &Array::DurationArray(ref vals) => vals.into_string_vec(),
+            &Array::DecimalArray(ref vals) => vals.into_string_vec(),
         }
     }
 }
@@ -101,6 +263,12 @@ impl Append for Array {
             (&Array::UsizeArray(ref l), &Array::UsizeArray(ref r)) => {
                 Array::UsizeArray(l.append(r))
             }
+            (&Array::Int128Array(ref l), &Array::Int128Array(ref r)) => {
+                Array::Int128Array(l.append(r))
+            }
+            (&Array::UInt128Array(ref l), &Array::UInt128Array(ref r)) => {
+                Array::UInt128Array(l.append(r))
+            }
             (&Array::Float64Array(ref l), &Array::Float64Array(ref r)) => {
                 Array::Float64Array(l.append(r))
             }
@@ -108,10 +276,36 @@ impl Append for Array {
                 Array::Float32Array(l.append(r))
             }
             (&Array::BoolArray(ref l), &Array::BoolArray(ref r)) => Array::BoolArray(l.append(r)),
+            (&Array::CharArray(ref l), &Array::CharArray(ref r)) => Array::CharArray(l.append(r)),
             (&Array::StringArray(ref l), &Array::StringArray(ref r)) => {
                 Array::StringArray(l.append(r))
             }
-            (_, _) => panic!(""),
+            (&Array::Utf8Array(ref l), &Array::Utf8Array(ref r)) => Array::Utf8Array(l.append(r)),
+            (&Array::BinaryArray(ref l), &Array::BinaryArray(ref r)) => {
+                Array::BinaryArray(l.append(r))
+            }
+            (&Array::ListArray(ref l), &Array::ListArray(ref r)) => {
+                Array::ListArray(l.append(r))
+            }
+            (&Array::StructArray(ref l), &Array::StructArray(ref r)) => {
+                Array::StructArray(l.append(r))
+            }
+            (&Array::CategoricalArray(ref l), &Array::CategoricalArray(ref r)) => {
+                Array::CategoricalArray(l.append(r))
+            }
+            (&Array::Date32Array(ref l), &Array::Date32Array(ref r)) => {
+                Array::Date32Array(l.append(r))
+            }
+            (&Array::TimestampArray(ref l), &Array::TimestampArray(ref r)) => {
+                Array::TimestampArray(l.append(r))
+            }
+            (&Array::DurationArray(ref l), &Array::DurationArray(ref r)) => {
+                Array::DurationArray(l.append(r))
+            }
+            (&Array::DecimalArray(ref l), &Array::DecimalArray(ref r)) => {
+                Array::DecimalArray(l.append(r))
+            }
+            (_, _) => panic!("unable to append {} to {}", other.dtype(), self.dtype()),
         }
     }
 }
@@ -120,10 +314,33 @@ impl Append for Array {
 #[cfg(test)]
 mod tests {
 
-    use generic::Array;
+    use generic::{Array, DtypeError, Scalar};
     use nullvec::NullVec;
     use traits::Stringify;
 
+    #[test]
+    fn test_shift() {
+        let arr = Array::new(vec![1i64, 2, 3]);
+        let exp = Array::new(NullVec::with_mask(vec![0i64, 1, 2], Some(vec![true, false, false])));
+        assert_eq!(arr.shift(1, Scalar::Null), Ok(exp));
+        assert_eq!(arr.shift(-1, Scalar::i64(5)), Ok(Array::new(vec![2i64, 3, 5])));
+
+        let arr = Array::new(vec!["a", "b"]).cast("utf8").unwrap();
+        let exp = Array::new(vec!["x", "a"]).cast("utf8").unwrap();
+        assert_eq!(arr.shift(1, Scalar::from("x".to_string())), Ok(exp));
+    }
+
+    #[test]
+    fn test_shift_dtype_mismatch() {
+        let arr = Array::new(vec![1i64, 2, 3]);
+        let err = arr.shift(1, Scalar::f64(1.)).unwrap_err();
+        assert_eq!(err, DtypeError::new("shift with f64 fill value", "i64"));
+
+        let arr = Array::new(vec!["a", "b"]).cast("category").unwrap();
+        assert!(arr.shift(1, Scalar::from("a".to_string())).is_ok());
+        assert!(arr.shift(1, Scalar::from("x".to_string())).is_err());
+    }
+
     #[test]
     fn test_into_string_vec() {
         let values: Vec<usize> = vec![1, 2, 3];
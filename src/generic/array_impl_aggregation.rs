@@ -1,27 +1,39 @@
-use super::{Array, Scalar};
+use super::{Array, DtypeError, Scalar};
 use nullable::Nullable;
 use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation};
 
 impl BasicAggregation for Array {
-    type Kept = Scalar;
+    type Kept = Result<Scalar, DtypeError>;
     type Counted = usize;
 
     fn sum(&self) -> Self::Kept {
         match self {
-            &Array::Int64Array(ref vals) => vals.sum().into(),
-            &Array::Int32Array(ref vals) => vals.sum().into(),
-            &Array::Int16Array(ref vals) => vals.sum().into(),
-            &Array::Int8Array(ref vals) => vals.sum().into(),
-            &Array::IsizeArray(ref vals) => vals.sum().into(),
-            &Array::UInt64Array(ref vals) => vals.sum().into(),
-            &Array::UInt32Array(ref vals) => vals.sum().into(),
-            &Array::UInt16Array(ref vals) => vals.sum().into(),
-            &Array::UInt8Array(ref vals) => vals.sum().into(),
-            &Array::UsizeArray(ref vals) => vals.sum().into(),
-            &Array::Float64Array(ref vals) => vals.sum().into(),
-            &Array::Float32Array(ref vals) => vals.sum().into(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.sum().into()),
+            &Array::Int32Array(ref vals) => Ok(vals.sum().into()),
+            &Array::Int16Array(ref vals) => Ok(vals.sum().into()),
+            &Array::Int8Array(ref vals) => Ok(vals.sum().into()),
+            &Array::IsizeArray(ref vals) => Ok(vals.sum().into()),
+            &Array::UInt64Array(ref vals) => Ok(vals.sum().into()),
+            &Array::UInt32Array(ref vals) => Ok(vals.sum().into()),
+            &Array::UInt16Array(ref vals) => Ok(vals.sum().into()),
+            &Array::UInt8Array(ref vals) => Ok(vals.sum().into()),
+            &Array::UsizeArray(ref vals) => Ok(vals.sum().into()),
+            &Array::Int128Array(ref vals) => Ok(vals.sum().into()),
+            &Array::UInt128Array(ref vals) => Ok(vals.sum().into()),
+            &Array::Float64Array(ref vals) => Ok(vals.sum().into()),
+            &Array::Float32Array(ref vals) => Ok(vals.sum().into()),
+            &Array::BoolArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("sum", &self.dtype())),
+            &Array::DurationArray(ref vals) => Ok(vals.sum().into()),
+            &Array::DecimalArray(ref vals) => Ok(vals.sum().into()),
         }
     }
 
@@ -37,151 +49,247 @@ impl BasicAggregation for Array {
             &Array::UInt16Array(ref vals) => vals.count(),
             &Array::UInt8Array(ref vals) => vals.count(),
             &Array::UsizeArray(ref vals) => vals.count(),
+            &Array::Int128Array(ref vals) => vals.count(),
+            &Array::UInt128Array(ref vals) => vals.count(),
             &Array::Float64Array(ref vals) => vals.count(),
             &Array::Float32Array(ref vals) => vals.count(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::BoolArray(ref vals) => vals.iter_not_null().count(),
+            &Array::CharArray(ref vals) => vals.iter_not_null().count(),
+            &Array::StringArray(ref vals) => vals.iter_not_null().count(),
+            &Array::Utf8Array(ref vals) => vals.is_null().iter().filter(|&&n| !n).count(),
+            &Array::BinaryArray(ref vals) => vals.is_null().iter().filter(|&&n| !n).count(),
+            &Array::ListArray(ref vals) => vals.is_null().iter().filter(|&&n| !n).count(),
+            &Array::StructArray(ref vals) => vals.is_null().iter().filter(|&&n| !n).count(),
+            &Array::CategoricalArray(ref vals) => vals.is_null().iter().filter(|&&n| !n).count(),
+            &Array::Date32Array(ref vals) => vals.iter_not_null().count(),
+            &Array::TimestampArray(ref vals) => vals.iter_not_null().count(),
+            &Array::DurationArray(ref vals) => vals.count(),
+            &Array::DecimalArray(ref vals) => vals.count(),
         }
     }
 }
 
 impl NumericAggregation for Array {
-    type Coerced = Nullable<f64>;
+    type Coerced = Result<Nullable<f64>, DtypeError>;
 
     fn mean(&self) -> Self::Coerced {
         match self {
-            &Array::Int64Array(ref vals) => vals.mean(),
-            &Array::Int32Array(ref vals) => vals.mean(),
-            &Array::Int16Array(ref vals) => vals.mean(),
-            &Array::Int8Array(ref vals) => vals.mean(),
-            &Array::IsizeArray(ref vals) => vals.mean(),
-            &Array::UInt64Array(ref vals) => vals.mean(),
-            &Array::UInt32Array(ref vals) => vals.mean(),
-            &Array::UInt16Array(ref vals) => vals.mean(),
-            &Array::UInt8Array(ref vals) => vals.mean(),
-            &Array::UsizeArray(ref vals) => vals.mean(),
-            &Array::Float64Array(ref vals) => vals.mean(),
-            &Array::Float32Array(ref vals) => vals.mean(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.mean()),
+            &Array::Int32Array(ref vals) => Ok(vals.mean()),
+            &Array::Int16Array(ref vals) => Ok(vals.mean()),
+            &Array::Int8Array(ref vals) => Ok(vals.mean()),
+            &Array::IsizeArray(ref vals) => Ok(vals.mean()),
+            &Array::UInt64Array(ref vals) => Ok(vals.mean()),
+            &Array::UInt32Array(ref vals) => Ok(vals.mean()),
+            &Array::UInt16Array(ref vals) => Ok(vals.mean()),
+            &Array::UInt8Array(ref vals) => Ok(vals.mean()),
+            &Array::UsizeArray(ref vals) => Ok(vals.mean()),
+            &Array::Int128Array(ref vals) => Ok(vals.mean()),
+            &Array::UInt128Array(ref vals) => Ok(vals.mean()),
+            &Array::Float64Array(ref vals) => Ok(vals.mean()),
+            &Array::Float32Array(ref vals) => Ok(vals.mean()),
+            &Array::BoolArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::DurationArray(_) => Err(DtypeError::new("mean", &self.dtype())),
+            &Array::DecimalArray(ref vals) => Ok(vals.mean()),
         }
     }
 
     fn var(&self) -> Self::Coerced {
         match self {
-            &Array::Int64Array(ref vals) => vals.var(),
-            &Array::Int32Array(ref vals) => vals.var(),
-            &Array::Int16Array(ref vals) => vals.var(),
-            &Array::Int8Array(ref vals) => vals.var(),
-            &Array::IsizeArray(ref vals) => vals.var(),
-            &Array::UInt64Array(ref vals) => vals.var(),
-            &Array::UInt32Array(ref vals) => vals.var(),
-            &Array::UInt16Array(ref vals) => vals.var(),
-            &Array::UInt8Array(ref vals) => vals.var(),
-            &Array::UsizeArray(ref vals) => vals.var(),
-            &Array::Float64Array(ref vals) => vals.var(),
-            &Array::Float32Array(ref vals) => vals.var(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.var()),
+            &Array::Int32Array(ref vals) => Ok(vals.var()),
+            &Array::Int16Array(ref vals) => Ok(vals.var()),
+            &Array::Int8Array(ref vals) => Ok(vals.var()),
+            &Array::IsizeArray(ref vals) => Ok(vals.var()),
+            &Array::UInt64Array(ref vals) => Ok(vals.var()),
+            &Array::UInt32Array(ref vals) => Ok(vals.var()),
+            &Array::UInt16Array(ref vals) => Ok(vals.var()),
+            &Array::UInt8Array(ref vals) => Ok(vals.var()),
+            &Array::UsizeArray(ref vals) => Ok(vals.var()),
+            &Array::Int128Array(ref vals) => Ok(vals.var()),
+            &Array::UInt128Array(ref vals) => Ok(vals.var()),
+            &Array::Float64Array(ref vals) => Ok(vals.var()),
+            &Array::Float32Array(ref vals) => Ok(vals.var()),
+            &Array::BoolArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::DurationArray(_) => Err(DtypeError::new("var", &self.dtype())),
+            &Array::DecimalArray(ref vals) => Ok(vals.var()),
         }
     }
 
     fn unbiased_var(&self) -> Self::Coerced {
         match self {
-            &Array::Int64Array(ref vals) => vals.unbiased_var(),
-            &Array::Int32Array(ref vals) => vals.unbiased_var(),
-            &Array::Int16Array(ref vals) => vals.unbiased_var(),
-            &Array::Int8Array(ref vals) => vals.unbiased_var(),
-            &Array::IsizeArray(ref vals) => vals.unbiased_var(),
-            &Array::UInt64Array(ref vals) => vals.unbiased_var(),
-            &Array::UInt32Array(ref vals) => vals.unbiased_var(),
-            &Array::UInt16Array(ref vals) => vals.unbiased_var(),
-            &Array::UInt8Array(ref vals) => vals.unbiased_var(),
-            &Array::UsizeArray(ref vals) => vals.unbiased_var(),
-            &Array::Float64Array(ref vals) => vals.unbiased_var(),
-            &Array::Float32Array(ref vals) => vals.unbiased_var(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Int32Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Int16Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Int8Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::IsizeArray(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UInt64Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UInt32Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UInt16Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UInt8Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UsizeArray(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Int128Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::UInt128Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Float64Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::Float32Array(ref vals) => Ok(vals.unbiased_var()),
+            &Array::BoolArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::DurationArray(_) => Err(DtypeError::new("unbiased_var", &self.dtype())),
+            &Array::DecimalArray(ref vals) => Ok(vals.unbiased_var()),
         }
     }
 
     fn std(&self) -> Self::Coerced {
         match self {
-            &Array::Int64Array(ref vals) => vals.std(),
-            &Array::Int32Array(ref vals) => vals.std(),
-            &Array::Int16Array(ref vals) => vals.std(),
-            &Array::Int8Array(ref vals) => vals.std(),
-            &Array::IsizeArray(ref vals) => vals.std(),
-            &Array::UInt64Array(ref vals) => vals.std(),
-            &Array::UInt32Array(ref vals) => vals.std(),
-            &Array::UInt16Array(ref vals) => vals.std(),
-            &Array::UInt8Array(ref vals) => vals.std(),
-            &Array::UsizeArray(ref vals) => vals.std(),
-            &Array::Float64Array(ref vals) => vals.std(),
-            &Array::Float32Array(ref vals) => vals.std(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.std()),
+            &Array::Int32Array(ref vals) => Ok(vals.std()),
+            &Array::Int16Array(ref vals) => Ok(vals.std()),
+            &Array::Int8Array(ref vals) => Ok(vals.std()),
+            &Array::IsizeArray(ref vals) => Ok(vals.std()),
+            &Array::UInt64Array(ref vals) => Ok(vals.std()),
+            &Array::UInt32Array(ref vals) => Ok(vals.std()),
+            &Array::UInt16Array(ref vals) => Ok(vals.std()),
+            &Array::UInt8Array(ref vals) => Ok(vals.std()),
+            &Array::UsizeArray(ref vals) => Ok(vals.std()),
+            &Array::Int128Array(ref vals) => Ok(vals.std()),
+            &Array::UInt128Array(ref vals) => Ok(vals.std()),
+            &Array::Float64Array(ref vals) => Ok(vals.std()),
+            &Array::Float32Array(ref vals) => Ok(vals.std()),
+            &Array::BoolArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::DurationArray(_) => Err(DtypeError::new("std", &self.dtype())),
+            &Array::DecimalArray(ref vals) => Ok(vals.std()),
         }
     }
 
     fn unbiased_std(&self) -> Self::Coerced {
         match self {
-            &Array::Int64Array(ref vals) => vals.unbiased_std(),
-            &Array::Int32Array(ref vals) => vals.unbiased_std(),
-            &Array::Int16Array(ref vals) => vals.unbiased_std(),
-            &Array::Int8Array(ref vals) => vals.unbiased_std(),
-            &Array::IsizeArray(ref vals) => vals.unbiased_std(),
-            &Array::UInt64Array(ref vals) => vals.unbiased_std(),
-            &Array::UInt32Array(ref vals) => vals.unbiased_std(),
-            &Array::UInt16Array(ref vals) => vals.unbiased_std(),
-            &Array::UInt8Array(ref vals) => vals.unbiased_std(),
-            &Array::UsizeArray(ref vals) => vals.unbiased_std(),
-            &Array::Float64Array(ref vals) => vals.unbiased_std(),
-            &Array::Float32Array(ref vals) => vals.unbiased_std(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Int32Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Int16Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Int8Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::IsizeArray(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UInt64Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UInt32Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UInt16Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UInt8Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UsizeArray(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Int128Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::UInt128Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Float64Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::Float32Array(ref vals) => Ok(vals.unbiased_std()),
+            &Array::BoolArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::CharArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::StringArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::Date32Array(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::TimestampArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::DurationArray(_) => Err(DtypeError::new("unbiased_std", &self.dtype())),
+            &Array::DecimalArray(ref vals) => Ok(vals.unbiased_std()),
         }
     }
 }
 
 impl ComparisonAggregation for Array {
-    type Kept = Scalar;
+    type Kept = Result<Scalar, DtypeError>;
 
     fn min(&self) -> Self::Kept {
         match self {
-            &Array::Int64Array(ref vals) => vals.min().into(),
-            &Array::Int32Array(ref vals) => vals.min().into(),
-            &Array::Int16Array(ref vals) => vals.min().into(),
-            &Array::Int8Array(ref vals) => vals.min().into(),
-            &Array::IsizeArray(ref vals) => vals.min().into(),
-            &Array::UInt64Array(ref vals) => vals.min().into(),
-            &Array::UInt32Array(ref vals) => vals.min().into(),
-            &Array::UInt16Array(ref vals) => vals.min().into(),
-            &Array::UInt8Array(ref vals) => vals.min().into(),
-            &Array::UsizeArray(ref vals) => vals.min().into(),
-            &Array::Float64Array(ref vals) => vals.min().into(),
-            &Array::Float32Array(ref vals) => vals.min().into(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.min().into()),
+            &Array::Int32Array(ref vals) => Ok(vals.min().into()),
+            &Array::Int16Array(ref vals) => Ok(vals.min().into()),
+            &Array::Int8Array(ref vals) => Ok(vals.min().into()),
+            &Array::IsizeArray(ref vals) => Ok(vals.min().into()),
+            &Array::UInt64Array(ref vals) => Ok(vals.min().into()),
+            &Array::UInt32Array(ref vals) => Ok(vals.min().into()),
+            &Array::UInt16Array(ref vals) => Ok(vals.min().into()),
+            &Array::UInt8Array(ref vals) => Ok(vals.min().into()),
+            &Array::UsizeArray(ref vals) => Ok(vals.min().into()),
+            &Array::Int128Array(ref vals) => Ok(vals.min().into()),
+            &Array::UInt128Array(ref vals) => Ok(vals.min().into()),
+            &Array::Float64Array(ref vals) => Ok(vals.min().into()),
+            &Array::Float32Array(ref vals) => Ok(vals.min().into()),
+            &Array::BoolArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::CharArray(ref vals) => Ok(vals.min().into()),
+            &Array::StringArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("min", &self.dtype())),
+            &Array::Date32Array(ref vals) => Ok(vals.min().into()),
+            &Array::TimestampArray(ref vals) => Ok(vals.min().into()),
+            &Array::DurationArray(ref vals) => Ok(vals.min().into()),
+            &Array::DecimalArray(ref vals) => Ok(vals.min().into()),
         }
     }
 
     fn max(&self) -> Self::Kept {
         match self {
-            &Array::Int64Array(ref vals) => vals.max().into(),
-            &Array::Int32Array(ref vals) => vals.max().into(),
-            &Array::Int16Array(ref vals) => vals.max().into(),
-            &Array::Int8Array(ref vals) => vals.max().into(),
-            &Array::IsizeArray(ref vals) => vals.max().into(),
-            &Array::UInt64Array(ref vals) => vals.max().into(),
-            &Array::UInt32Array(ref vals) => vals.max().into(),
-            &Array::UInt16Array(ref vals) => vals.max().into(),
-            &Array::UInt8Array(ref vals) => vals.max().into(),
-            &Array::UsizeArray(ref vals) => vals.max().into(),
-            &Array::Float64Array(ref vals) => vals.max().into(),
-            &Array::Float32Array(ref vals) => vals.max().into(),
-            &Array::BoolArray(_) => unimplemented!(),
-            &Array::StringArray(_) => unimplemented!(),
+            &Array::Int64Array(ref vals) => Ok(vals.max().into()),
+            &Array::Int32Array(ref vals) => Ok(vals.max().into()),
+            &Array::Int16Array(ref vals) => Ok(vals.max().into()),
+            &Array::Int8Array(ref vals) => Ok(vals.max().into()),
+            &Array::IsizeArray(ref vals) => Ok(vals.max().into()),
+            &Array::UInt64Array(ref vals) => Ok(vals.max().into()),
+            &Array::UInt32Array(ref vals) => Ok(vals.max().into()),
+            &Array::UInt16Array(ref vals) => Ok(vals.max().into()),
+            &Array::UInt8Array(ref vals) => Ok(vals.max().into()),
+            &Array::UsizeArray(ref vals) => Ok(vals.max().into()),
+            &Array::Int128Array(ref vals) => Ok(vals.max().into()),
+            &Array::UInt128Array(ref vals) => Ok(vals.max().into()),
+            &Array::Float64Array(ref vals) => Ok(vals.max().into()),
+            &Array::Float32Array(ref vals) => Ok(vals.max().into()),
+            &Array::BoolArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::CharArray(ref vals) => Ok(vals.max().into()),
+            &Array::StringArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::Utf8Array(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::BinaryArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::ListArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::StructArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::CategoricalArray(_) => Err(DtypeError::new("max", &self.dtype())),
+            &Array::Date32Array(ref vals) => Ok(vals.max().into()),
+            &Array::TimestampArray(ref vals) => Ok(vals.max().into()),
+            &Array::DurationArray(ref vals) => Ok(vals.max().into()),
+            &Array::DecimalArray(ref vals) => Ok(vals.max().into()),
         }
     }
 }
@@ -0,0 +1,332 @@
+use super::{Array, DtypeError};
+
+impl Array {
+    /// Returns cumulative sum. See `NullVec::cumsum`.
+    ///
+    /// Returns `DtypeError` unless the dtype is numeric, duration or decimal.
+    pub fn cumsum(&self, skip_nulls: bool) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => Array::Int64Array(vals.cumsum(skip_nulls)),
+            Array::Int32Array(ref vals) => Array::Int32Array(vals.cumsum(skip_nulls)),
+            Array::Int16Array(ref vals) => Array::Int16Array(vals.cumsum(skip_nulls)),
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.cumsum(skip_nulls)),
+            Array::IsizeArray(ref vals) => Array::IsizeArray(vals.cumsum(skip_nulls)),
+            Array::UInt64Array(ref vals) => Array::UInt64Array(vals.cumsum(skip_nulls)),
+            Array::UInt32Array(ref vals) => Array::UInt32Array(vals.cumsum(skip_nulls)),
+            Array::UInt16Array(ref vals) => Array::UInt16Array(vals.cumsum(skip_nulls)),
+            Array::UInt8Array(ref vals) => Array::UInt8Array(vals.cumsum(skip_nulls)),
+            Array::UsizeArray(ref vals) => Array::UsizeArray(vals.cumsum(skip_nulls)),
+            Array::Int128Array(ref vals) => Array::Int128Array(vals.cumsum(skip_nulls)),
+            Array::UInt128Array(ref vals) => Array::UInt128Array(vals.cumsum(skip_nulls)),
+            Array::Float64Array(ref vals) => {
+                Array::Float64Array(vals.cum_apply(skip_nulls, |c, v| Some(c + *v)))
+            }
+            Array::Float32Array(ref vals) => {
+                Array::Float32Array(vals.cum_apply(skip_nulls, |c, v| Some(c + *v)))
+            }
+            Array::DurationArray(ref vals) => Array::DurationArray(vals.cumsum(skip_nulls)),
+            Array::DecimalArray(ref vals) => Array::DecimalArray(vals.cumsum(skip_nulls)),
+            Array::BoolArray(_)
+            | Array::CharArray(_)
+            | Array::StringArray(_)
+            | Array::Utf8Array(_)
+            | Array::BinaryArray(_)
+            | Array::ListArray(_)
+            | Array::StructArray(_)
+            | Array::CategoricalArray(_)
+            | Array::Date32Array(_)
+            | Array::TimestampArray(_) => return Err(DtypeError::new("cumsum", &self.dtype())),
+        })
+    }
+
+    /// Returns cumulative product. See `NullVec::cumprod`.
+    ///
+    /// Returns `DtypeError` unless the dtype is numeric or decimal.
+    pub fn cumprod(&self, skip_nulls: bool) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => Array::Int64Array(vals.cumprod(skip_nulls)),
+            Array::Int32Array(ref vals) => Array::Int32Array(vals.cumprod(skip_nulls)),
+            Array::Int16Array(ref vals) => Array::Int16Array(vals.cumprod(skip_nulls)),
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.cumprod(skip_nulls)),
+            Array::IsizeArray(ref vals) => Array::IsizeArray(vals.cumprod(skip_nulls)),
+            Array::UInt64Array(ref vals) => Array::UInt64Array(vals.cumprod(skip_nulls)),
+            Array::UInt32Array(ref vals) => Array::UInt32Array(vals.cumprod(skip_nulls)),
+            Array::UInt16Array(ref vals) => Array::UInt16Array(vals.cumprod(skip_nulls)),
+            Array::UInt8Array(ref vals) => Array::UInt8Array(vals.cumprod(skip_nulls)),
+            Array::UsizeArray(ref vals) => Array::UsizeArray(vals.cumprod(skip_nulls)),
+            Array::Int128Array(ref vals) => Array::Int128Array(vals.cumprod(skip_nulls)),
+            Array::UInt128Array(ref vals) => Array::UInt128Array(vals.cumprod(skip_nulls)),
+            Array::Float64Array(ref vals) => {
+                Array::Float64Array(vals.cum_apply(skip_nulls, |c, v| Some(c * *v)))
+            }
+            Array::Float32Array(ref vals) => {
+                Array::Float32Array(vals.cum_apply(skip_nulls, |c, v| Some(c * *v)))
+            }
+            Array::DecimalArray(ref vals) => Array::DecimalArray(vals.cumprod(skip_nulls)),
+            Array::BoolArray(_)
+            | Array::CharArray(_)
+            | Array::StringArray(_)
+            | Array::Utf8Array(_)
+            | Array::BinaryArray(_)
+            | Array::ListArray(_)
+            | Array::StructArray(_)
+            | Array::CategoricalArray(_)
+            | Array::Date32Array(_)
+            | Array::TimestampArray(_)
+            | Array::DurationArray(_) => return Err(DtypeError::new("cumprod", &self.dtype())),
+        })
+    }
+
+    /// Returns cumulative minimum. See `NullVec::cummin`.
+    ///
+    /// Returns `DtypeError` for list, struct and categorical dtypes.
+    pub fn cummin(&self, skip_nulls: bool) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => Array::Int64Array(vals.cummin(skip_nulls)),
+            Array::Int32Array(ref vals) => Array::Int32Array(vals.cummin(skip_nulls)),
+            Array::Int16Array(ref vals) => Array::Int16Array(vals.cummin(skip_nulls)),
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.cummin(skip_nulls)),
+            Array::IsizeArray(ref vals) => Array::IsizeArray(vals.cummin(skip_nulls)),
+            Array::UInt64Array(ref vals) => Array::UInt64Array(vals.cummin(skip_nulls)),
+            Array::UInt32Array(ref vals) => Array::UInt32Array(vals.cummin(skip_nulls)),
+            Array::UInt16Array(ref vals) => Array::UInt16Array(vals.cummin(skip_nulls)),
+            Array::UInt8Array(ref vals) => Array::UInt8Array(vals.cummin(skip_nulls)),
+            Array::UsizeArray(ref vals) => Array::UsizeArray(vals.cummin(skip_nulls)),
+            Array::Int128Array(ref vals) => Array::Int128Array(vals.cummin(skip_nulls)),
+            Array::UInt128Array(ref vals) => Array::UInt128Array(vals.cummin(skip_nulls)),
+            Array::Float64Array(ref vals) => Array::Float64Array(vals.cummin(skip_nulls)),
+            Array::Float32Array(ref vals) => Array::Float32Array(vals.cummin(skip_nulls)),
+            Array::BoolArray(ref vals) => Array::BoolArray(vals.cummin(skip_nulls)),
+            Array::CharArray(ref vals) => Array::CharArray(vals.cummin(skip_nulls)),
+            Array::StringArray(ref vals) => Array::StringArray(vals.cummin(skip_nulls)),
+            Array::Utf8Array(ref vals) => {
+                Array::Utf8Array(vals.to_nullvec().cummin(skip_nulls).into())
+            }
+            Array::BinaryArray(ref vals) => {
+                Array::BinaryArray(vals.to_nullvec().cummin(skip_nulls).into())
+            }
+            Array::Date32Array(ref vals) => Array::Date32Array(vals.cummin(skip_nulls)),
+            Array::TimestampArray(ref vals) => Array::TimestampArray(vals.cummin(skip_nulls)),
+            Array::DurationArray(ref vals) => Array::DurationArray(vals.cummin(skip_nulls)),
+            Array::DecimalArray(ref vals) => Array::DecimalArray(vals.cummin(skip_nulls)),
+            Array::ListArray(_) | Array::StructArray(_) | Array::CategoricalArray(_) => {
+                return Err(DtypeError::new("cummin", &self.dtype()))
+            }
+        })
+    }
+
+    /// Returns cumulative maximum. See `NullVec::cummax`.
+    ///
+    /// Returns `DtypeError` for list, struct and categorical dtypes.
+    pub fn cummax(&self, skip_nulls: bool) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => Array::Int64Array(vals.cummax(skip_nulls)),
+            Array::Int32Array(ref vals) => Array::Int32Array(vals.cummax(skip_nulls)),
+            Array::Int16Array(ref vals) => Array::Int16Array(vals.cummax(skip_nulls)),
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.cummax(skip_nulls)),
+            Array::IsizeArray(ref vals) => Array::IsizeArray(vals.cummax(skip_nulls)),
+            Array::UInt64Array(ref vals) => Array::UInt64Array(vals.cummax(skip_nulls)),
+            Array::UInt32Array(ref vals) => Array::UInt32Array(vals.cummax(skip_nulls)),
+            Array::UInt16Array(ref vals) => Array::UInt16Array(vals.cummax(skip_nulls)),
+            Array::UInt8Array(ref vals) => Array::UInt8Array(vals.cummax(skip_nulls)),
+            Array::UsizeArray(ref vals) => Array::UsizeArray(vals.cummax(skip_nulls)),
+            Array::Int128Array(ref vals) => Array::Int128Array(vals.cummax(skip_nulls)),
+            Array::UInt128Array(ref vals) => Array::UInt128Array(vals.cummax(skip_nulls)),
+            Array::Float64Array(ref vals) => Array::Float64Array(vals.cummax(skip_nulls)),
+            Array::Float32Array(ref vals) => Array::Float32Array(vals.cummax(skip_nulls)),
+            Array::BoolArray(ref vals) => Array::BoolArray(vals.cummax(skip_nulls)),
+            Array::CharArray(ref vals) => Array::CharArray(vals.cummax(skip_nulls)),
+            Array::StringArray(ref vals) => Array::StringArray(vals.cummax(skip_nulls)),
+            Array::Utf8Array(ref vals) => {
+                Array::Utf8Array(vals.to_nullvec().cummax(skip_nulls).into())
+            }
+            Array::BinaryArray(ref vals) => {
+                Array::BinaryArray(vals.to_nullvec().cummax(skip_nulls).into())
+            }
+            Array::Date32Array(ref vals) => Array::Date32Array(vals.cummax(skip_nulls)),
+            Array::TimestampArray(ref vals) => Array::TimestampArray(vals.cummax(skip_nulls)),
+            Array::DurationArray(ref vals) => Array::DurationArray(vals.cummax(skip_nulls)),
+            Array::DecimalArray(ref vals) => Array::DecimalArray(vals.cummax(skip_nulls)),
+            Array::ListArray(_) | Array::StructArray(_) | Array::CategoricalArray(_) => {
+                return Err(DtypeError::new("cummax", &self.dtype()))
+            }
+        })
+    }
+
+    /// Returns running count of non-null values as `UsizeArray`. See `NullVec::cumcount`.
+    ///
+    /// Returns `DtypeError` for list and struct dtypes.
+    pub fn cumcount(&self, skip_nulls: bool) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Int32Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Int16Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Int8Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::IsizeArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UInt64Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UInt32Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UInt16Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UInt8Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UsizeArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Int128Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::UInt128Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Float64Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Float32Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::BoolArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::CharArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::StringArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::Utf8Array(ref vals) => vals.to_nullvec().cumcount(skip_nulls).into(),
+            Array::BinaryArray(ref vals) => vals.to_nullvec().cumcount(skip_nulls).into(),
+            Array::CategoricalArray(ref vals) => vals.codes().cumcount(skip_nulls).into(),
+            Array::Date32Array(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::TimestampArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::DurationArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::DecimalArray(ref vals) => vals.cumcount(skip_nulls).into(),
+            Array::ListArray(_) | Array::StructArray(_) => {
+                return Err(DtypeError::new("cumcount", &self.dtype()))
+            }
+        })
+    }
+
+    /// Returns difference with the element `periods` before. See `NullVec::diff`.
+    ///
+    /// Returns `DtypeError` unless the dtype is numeric, duration or decimal.
+    pub fn diff(&self, periods: isize) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => Array::Int64Array(vals.diff(periods)),
+            Array::Int32Array(ref vals) => Array::Int32Array(vals.diff(periods)),
+            Array::Int16Array(ref vals) => Array::Int16Array(vals.diff(periods)),
+            Array::Int8Array(ref vals) => Array::Int8Array(vals.diff(periods)),
+            Array::IsizeArray(ref vals) => Array::IsizeArray(vals.diff(periods)),
+            Array::UInt64Array(ref vals) => Array::UInt64Array(vals.diff(periods)),
+            Array::UInt32Array(ref vals) => Array::UInt32Array(vals.diff(periods)),
+            Array::UInt16Array(ref vals) => Array::UInt16Array(vals.diff(periods)),
+            Array::UInt8Array(ref vals) => Array::UInt8Array(vals.diff(periods)),
+            Array::UsizeArray(ref vals) => Array::UsizeArray(vals.diff(periods)),
+            Array::Int128Array(ref vals) => Array::Int128Array(vals.diff(periods)),
+            Array::UInt128Array(ref vals) => Array::UInt128Array(vals.diff(periods)),
+            Array::Float64Array(ref vals) => {
+                Array::Float64Array(vals.diff_with(periods, |x, y| Some(*x - *y)))
+            }
+            Array::Float32Array(ref vals) => {
+                Array::Float32Array(vals.diff_with(periods, |x, y| Some(*x - *y)))
+            }
+            Array::DurationArray(ref vals) => Array::DurationArray(vals.diff(periods)),
+            Array::DecimalArray(ref vals) => Array::DecimalArray(vals.diff(periods)),
+            Array::BoolArray(_)
+            | Array::CharArray(_)
+            | Array::StringArray(_)
+            | Array::Utf8Array(_)
+            | Array::BinaryArray(_)
+            | Array::ListArray(_)
+            | Array::StructArray(_)
+            | Array::CategoricalArray(_)
+            | Array::Date32Array(_)
+            | Array::TimestampArray(_) => return Err(DtypeError::new("diff", &self.dtype())),
+        })
+    }
+
+    /// Returns percentage change as `Float64Array`. See `NullVec::pct_change`.
+    ///
+    /// Returns `DtypeError` unless the dtype is numeric or decimal.
+    pub fn pct_change(&self, periods: isize) -> Result<Array, DtypeError> {
+        Ok(match *self {
+            Array::Int64Array(ref vals) => vals.pct_change(periods).into(),
+            Array::Int32Array(ref vals) => vals.pct_change(periods).into(),
+            Array::Int16Array(ref vals) => vals.pct_change(periods).into(),
+            Array::Int8Array(ref vals) => vals.pct_change(periods).into(),
+            Array::IsizeArray(ref vals) => vals.pct_change(periods).into(),
+            Array::UInt64Array(ref vals) => vals.pct_change(periods).into(),
+            Array::UInt32Array(ref vals) => vals.pct_change(periods).into(),
+            Array::UInt16Array(ref vals) => vals.pct_change(periods).into(),
+            Array::UInt8Array(ref vals) => vals.pct_change(periods).into(),
+            Array::UsizeArray(ref vals) => vals.pct_change(periods).into(),
+            Array::Int128Array(ref vals) => vals.pct_change(periods).into(),
+            Array::UInt128Array(ref vals) => vals.pct_change(periods).into(),
+            Array::Float64Array(ref vals) => vals.pct_change(periods).into(),
+            Array::Float32Array(ref vals) => vals.pct_change(periods).into(),
+            Array::DecimalArray(ref vals) => vals.pct_change(periods).into(),
+            Array::BoolArray(_)
+            | Array::CharArray(_)
+            | Array::StringArray(_)
+            | Array::Utf8Array(_)
+            | Array::BinaryArray(_)
+            | Array::ListArray(_)
+            | Array::StructArray(_)
+            | Array::CategoricalArray(_)
+            | Array::Date32Array(_)
+            | Array::TimestampArray(_)
+            | Array::DurationArray(_) => return Err(DtypeError::new("pct_change", &self.dtype())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use generic::{Array, DtypeError};
+    use nullvec::NullVec;
+
+    #[test]
+    fn test_cumsum() {
+        let arr = Array::new(vec![1, 2, 3]);
+        assert_eq!(arr.cumsum(true), Ok(Array::new(vec![1, 3, 6])));
+
+        let arr = Array::new(vec![1.5, 2., 3.]);
+        assert_eq!(arr.cumsum(true), Ok(Array::new(vec![1.5, 3.5, 6.5])));
+    }
+
+    #[test]
+    fn test_float() {
+        let arr = Array::new(vec![1., 2., f64::NAN, 3.]);
+        let exp = NullVec::with_mask(vec![1., 2., 0., 6.], Some(vec![false, false, true, false]));
+        assert_eq!(arr.cumprod(true), Ok(Array::new(exp)));
+        let exp = NullVec::with_mask(vec![1., 2., 0., 0.], Some(vec![false, false, true, true]));
+        assert_eq!(arr.cumprod(false), Ok(Array::new(exp)));
+
+        let exp = NullVec::with_mask(vec![0., 1., 0., 0.], Some(vec![true, false, true, true]));
+        assert_eq!(arr.diff(1), Ok(Array::new(exp)));
+
+        let arr = Array::new(vec![1f32, 2.]);
+        assert_eq!(
+            arr.diff(isize::MIN),
+            Ok(Array::new(NullVec::with_mask(
+                vec![0f32, 0.],
+                Some(vec![true, true])
+            )))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_dtype() {
+        let arr = Array::new(vec!["a".to_string()]);
+        let err = arr.cumsum(true).unwrap_err();
+        assert_eq!(err, DtypeError::new("cumsum", "str"));
+        assert_eq!(err.to_string(), "cumsum is not supported for dtype str");
+        assert!(arr.diff(1).is_err());
+        assert!(arr.pct_change(1).is_err());
+        assert!(arr.cummin(true).is_ok());
+    }
+
+    #[test]
+    fn test_cumcount() {
+        let arr = Array::new(NullVec::with_mask(
+            vec![true, false, true],
+            Some(vec![false, true, false]),
+        ));
+        let exp = Array::new(NullVec::with_mask(
+            vec![1usize, 0, 2],
+            Some(vec![false, true, false]),
+        ));
+        assert_eq!(arr.cumcount(true), Ok(exp));
+    }
+
+    #[test]
+    fn test_pct_change() {
+        let arr = Array::new(vec![2i64, 3]);
+        let exp = Array::new(NullVec::with_mask(vec![0., 0.5], Some(vec![true, false])));
+        assert_eq!(arr.pct_change(1), Ok(exp));
+    }
+}
@@ -16,10 +16,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => vals.len(),
             &Array::UInt8Array(ref vals) => vals.len(),
             &Array::UsizeArray(ref vals) => vals.len(),
+            &Array::Int128Array(ref vals) => vals.len(),
+            &Array::UInt128Array(ref vals) => vals.len(),
             &Array::Float64Array(ref vals) => vals.len(),
             &Array::Float32Array(ref vals) => vals.len(),
             &Array::BoolArray(ref vals) => vals.len(),
+            &Array::CharArray(ref vals) => vals.len(),
             &Array::StringArray(ref vals) => vals.len(),
+            &Array::Utf8Array(ref vals) => vals.len(),
+            &Array::BinaryArray(ref vals) => vals.len(),
+            &Array::ListArray(ref vals) => vals.len(),
+            &Array::StructArray(ref vals) => vals.len(),
+            &Array::CategoricalArray(ref vals) => vals.len(),
+            &Array::Date32Array(ref vals) => vals.len(),
+            &Array::TimestampArray(ref vals) => vals.len(),
+            &Array::DurationArray(ref vals) => vals.len(),
+            &Array::DecimalArray(ref vals) => vals.len(),
         }
     }
 
@@ -35,10 +47,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => vals.iloc(location).into(),
             &Array::UInt8Array(ref vals) => vals.iloc(location).into(),
             &Array::UsizeArray(ref vals) => vals.iloc(location).into(),
+            &Array::Int128Array(ref vals) => vals.iloc(location).into(),
+            &Array::UInt128Array(ref vals) => vals.iloc(location).into(),
             &Array::Float64Array(ref vals) => vals.iloc(location).into(),
             &Array::Float32Array(ref vals) => vals.iloc(location).into(),
             &Array::BoolArray(ref vals) => vals.iloc(location).into(),
+            &Array::CharArray(ref vals) => vals.iloc(location).into(),
             &Array::StringArray(ref vals) => vals.iloc(location).into(),
+            &Array::Utf8Array(ref vals) => vals.iloc(location).into(),
+            &Array::BinaryArray(ref vals) => vals.iloc(location).into(),
+            &Array::ListArray(ref vals) => vals.iloc(location),
+            &Array::StructArray(ref vals) => vals.iloc(location),
+            &Array::CategoricalArray(ref vals) => vals.iloc(location).into(),
+            &Array::Date32Array(ref vals) => vals.iloc(location).into(),
+            &Array::TimestampArray(ref vals) => vals.iloc(location).into(),
+            &Array::DurationArray(ref vals) => vals.iloc(location).into(),
+            &Array::DecimalArray(ref vals) => vals.iloc(location).into(),
         }
     }
 
@@ -54,10 +78,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::UInt8Array(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::UsizeArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::Int128Array(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::UInt128Array(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::Float64Array(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::Float32Array(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::BoolArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::CharArray(ref vals) => vals.iloc_unchecked(location).into(),
             &Array::StringArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::Utf8Array(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::BinaryArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::ListArray(ref vals) => vals.iloc_unchecked(location),
+            &Array::StructArray(ref vals) => vals.iloc_unchecked(location),
+            &Array::CategoricalArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::Date32Array(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::TimestampArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::DurationArray(ref vals) => vals.iloc_unchecked(location).into(),
+            &Array::DecimalArray(ref vals) => vals.iloc_unchecked(location).into(),
         }
     }
 
@@ -73,10 +109,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => Array::UInt16Array(vals.ilocs(locations)),
             &Array::UInt8Array(ref vals) => Array::UInt8Array(vals.ilocs(locations)),
             &Array::UsizeArray(ref vals) => Array::UsizeArray(vals.ilocs(locations)),
+            &Array::Int128Array(ref vals) => Array::Int128Array(vals.ilocs(locations)),
+            &Array::UInt128Array(ref vals) => Array::UInt128Array(vals.ilocs(locations)),
             &Array::Float64Array(ref vals) => Array::Float64Array(vals.ilocs(locations)),
             &Array::Float32Array(ref vals) => Array::Float32Array(vals.ilocs(locations)),
             &Array::BoolArray(ref vals) => Array::BoolArray(vals.ilocs(locations)),
+            &Array::CharArray(ref vals) => Array::CharArray(vals.ilocs(locations)),
             &Array::StringArray(ref vals) => Array::StringArray(vals.ilocs(locations)),
+            &Array::Utf8Array(ref vals) => Array::Utf8Array(vals.ilocs(locations)),
+            &Array::BinaryArray(ref vals) => Array::BinaryArray(vals.ilocs(locations)),
+            &Array::ListArray(ref vals) => Array::ListArray(vals.ilocs(locations)),
+            &Array::StructArray(ref vals) => Array::StructArray(vals.ilocs(locations)),
+            &Array::CategoricalArray(ref vals) => Array::CategoricalArray(vals.ilocs(locations)),
+            &Array::Date32Array(ref vals) => Array::Date32Array(vals.ilocs(locations)),
+            &Array::TimestampArray(ref vals) => Array::TimestampArray(vals.ilocs(locations)),
+            &Array::DurationArray(ref vals) => Array::DurationArray(vals.ilocs(locations)),
+            &Array::DecimalArray(ref vals) => Array::DecimalArray(vals.ilocs(locations)),
         }
     }
 
@@ -92,10 +140,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => Array::UInt16Array(vals.ilocs_unchecked(locations)),
             &Array::UInt8Array(ref vals) => Array::UInt8Array(vals.ilocs_unchecked(locations)),
             &Array::UsizeArray(ref vals) => Array::UsizeArray(vals.ilocs_unchecked(locations)),
+            &Array::Int128Array(ref vals) => Array::Int128Array(vals.ilocs_unchecked(locations)),
+            &Array::UInt128Array(ref vals) => Array::UInt128Array(vals.ilocs_unchecked(locations)),
             &Array::Float64Array(ref vals) => Array::Float64Array(vals.ilocs_unchecked(locations)),
             &Array::Float32Array(ref vals) => Array::Float32Array(vals.ilocs_unchecked(locations)),
             &Array::BoolArray(ref vals) => Array::BoolArray(vals.ilocs_unchecked(locations)),
+            &Array::CharArray(ref vals) => Array::CharArray(vals.ilocs_unchecked(locations)),
             &Array::StringArray(ref vals) => Array::StringArray(vals.ilocs_unchecked(locations)),
+            &Array::Utf8Array(ref vals) => Array::Utf8Array(vals.ilocs_unchecked(locations)),
+            &Array::BinaryArray(ref vals) => Array::BinaryArray(vals.ilocs_unchecked(locations)),
+            &Array::ListArray(ref vals) => Array::ListArray(vals.ilocs_unchecked(locations)),
+            &Array::StructArray(ref vals) => Array::StructArray(vals.ilocs_unchecked(locations)),
+            &Array::CategoricalArray(ref vals) => Array::CategoricalArray(vals.ilocs_unchecked(locations)),
+            &Array::Date32Array(ref vals) => Array::Date32Array(vals.ilocs_unchecked(locations)),
+            &Array::TimestampArray(ref vals) => Array::TimestampArray(vals.ilocs_unchecked(locations)),
+            &Array::DurationArray(ref vals) => Array::DurationArray(vals.ilocs_unchecked(locations)),
+            &Array::DecimalArray(ref vals) => Array::DecimalArray(vals.ilocs_unchecked(locations)),
         }
     }
 
@@ -111,10 +171,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => Array::UInt16Array(vals.ilocs_forced(locations)),
             &Array::UInt8Array(ref vals) => Array::UInt8Array(vals.ilocs_forced(locations)),
             &Array::UsizeArray(ref vals) => Array::UsizeArray(vals.ilocs_forced(locations)),
+            &Array::Int128Array(ref vals) => Array::Int128Array(vals.ilocs_forced(locations)),
+            &Array::UInt128Array(ref vals) => Array::UInt128Array(vals.ilocs_forced(locations)),
             &Array::Float64Array(ref vals) => Array::Float64Array(vals.ilocs_forced(locations)),
             &Array::Float32Array(ref vals) => Array::Float32Array(vals.ilocs_forced(locations)),
             &Array::BoolArray(ref vals) => Array::BoolArray(vals.ilocs_forced(locations)),
+            &Array::CharArray(ref vals) => Array::CharArray(vals.ilocs_forced(locations)),
             &Array::StringArray(ref vals) => Array::StringArray(vals.ilocs_forced(locations)),
+            &Array::Utf8Array(ref vals) => Array::Utf8Array(vals.ilocs_forced(locations)),
+            &Array::BinaryArray(ref vals) => Array::BinaryArray(vals.ilocs_forced(locations)),
+            &Array::ListArray(ref vals) => Array::ListArray(vals.ilocs_forced(locations)),
+            &Array::StructArray(ref vals) => Array::StructArray(vals.ilocs_forced(locations)),
+            &Array::CategoricalArray(ref vals) => Array::CategoricalArray(vals.ilocs_forced(locations)),
+            &Array::Date32Array(ref vals) => Array::Date32Array(vals.ilocs_forced(locations)),
+            &Array::TimestampArray(ref vals) => Array::TimestampArray(vals.ilocs_forced(locations)),
+            &Array::DurationArray(ref vals) => Array::DurationArray(vals.ilocs_forced(locations)),
+            &Array::DecimalArray(ref vals) => Array::DecimalArray(vals.ilocs_forced(locations)),
         }
     }
 
@@ -130,10 +202,22 @@ impl Slicer for Array {
             &Array::UInt16Array(ref vals) => Array::UInt16Array(vals.blocs(flags)),
             &Array::UInt8Array(ref vals) => Array::UInt8Array(vals.blocs(flags)),
             &Array::UsizeArray(ref vals) => Array::UsizeArray(vals.blocs(flags)),
+            &Array::Int128Array(ref vals) => Array::Int128Array(vals.blocs(flags)),
+            &Array::UInt128Array(ref vals) => Array::UInt128Array(vals.blocs(flags)),
             &Array::Float64Array(ref vals) => Array::Float64Array(vals.blocs(flags)),
             &Array::Float32Array(ref vals) => Array::Float32Array(vals.blocs(flags)),
             &Array::BoolArray(ref vals) => Array::BoolArray(vals.blocs(flags)),
+            &Array::CharArray(ref vals) => Array::CharArray(vals.blocs(flags)),
             &Array::StringArray(ref vals) => Array::StringArray(vals.blocs(flags)),
+            &Array::Utf8Array(ref vals) => Array::Utf8Array(vals.blocs(flags)),
+            &Array::BinaryArray(ref vals) => Array::BinaryArray(vals.blocs(flags)),
+            &Array::ListArray(ref vals) => Array::ListArray(vals.blocs(flags)),
+            &Array::StructArray(ref vals) => Array::StructArray(vals.blocs(flags)),
+            &Array::CategoricalArray(ref vals) => Array::CategoricalArray(vals.blocs(flags)),
+            &Array::Date32Array(ref vals) => Array::Date32Array(vals.blocs(flags)),
+            &Array::TimestampArray(ref vals) => Array::TimestampArray(vals.blocs(flags)),
+            &Array::DurationArray(ref vals) => Array::DurationArray(vals.blocs(flags)),
+            &Array::DecimalArray(ref vals) => Array::DecimalArray(vals.blocs(flags)),
         }
     }
 }
@@ -1,6 +1,10 @@
-use super::{Array, Scalar};
+use super::{same_fields, Array, Scalar};
+use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+use decimal::Decimal;
+use temporal::{Date32, Duration, Timestamp};
 use nullable::Nullable;
 use nullvec::NullVec;
+use traits::Slicer;
 
 // ToDo: check all impls are enough efficient
 
@@ -60,10 +64,53 @@ add_array_conversion!(u32, UInt32Array);
 add_array_conversion!(u16, UInt16Array);
 add_array_conversion!(u8, UInt8Array);
 add_array_conversion!(usize, UsizeArray);
+add_array_conversion!(i128, Int128Array);
+add_array_conversion!(u128, UInt128Array);
 add_array_conversion!(f64, Float64Array);
 add_array_conversion!(f32, Float32Array);
 add_array_conversion!(bool, BoolArray);
+add_array_conversion!(char, CharArray);
 add_array_conversion!(String, StringArray);
+add_array_conversion!(Date32, Date32Array);
+add_array_conversion!(Timestamp, TimestampArray);
+add_array_conversion!(Duration, DurationArray);
+add_array_conversion!(Decimal, DecimalArray);
+
+impl From<Utf8Column> for Array {
+    fn from(values: Utf8Column) -> Self {
+        Array::Utf8Array(values)
+    }
+}
+
+impl From<BinaryColumn> for Array {
+    fn from(values: BinaryColumn) -> Self {
+        Array::BinaryArray(values)
+    }
+}
+
+impl From<ListColumn> for Array {
+    fn from(values: ListColumn) -> Self {
+        Array::ListArray(values)
+    }
+}
+
+impl From<StructColumn> for Array {
+    fn from(values: StructColumn) -> Self {
+        Array::StructArray(values)
+    }
+}
+
+impl From<NullVec<Vec<u8>>> for Array {
+    fn from(values: NullVec<Vec<u8>>) -> Self {
+        Array::BinaryArray(values.into())
+    }
+}
+
+impl From<Categorical> for Array {
+    fn from(values: Categorical) -> Self {
+        Array::CategoricalArray(values)
+    }
+}
 
 // &str handling
 impl<'a> From<Vec<&'a str>> for Array {
@@ -78,9 +125,13 @@ impl From<Vec<Scalar>> for Array {
     fn from(values: Vec<Scalar>) -> Self {
         assert!(values.len() > 0, "Unable to infer dtype");
 
-        // ToDo: use better logic to infer dtype (take first not null?)
+        // infer dtype from the first non-null value
         // ToDo: Directly create Nullable internal data
-        match &values[0] {
+        let first = values
+            .iter()
+            .find(|x| **x != Scalar::Null)
+            .unwrap_or(&values[0]);
+        match first {
             &Scalar::i64(_) => {
                 values
                     .iter()
@@ -151,6 +202,20 @@ impl From<Vec<Scalar>> for Array {
                     .collect::<NullVec<usize>>()
                     .into()
             }
+            &Scalar::i128(_) => {
+                values
+                    .iter()
+                    .map(|ref x| x.as_i128())
+                    .collect::<NullVec<i128>>()
+                    .into()
+            }
+            &Scalar::u128(_) => {
+                values
+                    .iter()
+                    .map(|ref x| x.as_u128())
+                    .collect::<NullVec<u128>>()
+                    .into()
+            }
             &Scalar::f64(_) => {
                 values
                     .iter()
@@ -172,6 +237,13 @@ impl From<Vec<Scalar>> for Array {
                     .collect::<NullVec<bool>>()
                     .into()
             }
+            &Scalar::char(_) => {
+                values
+                    .iter()
+                    .map(|ref x| x.as_char())
+                    .collect::<NullVec<char>>()
+                    .into()
+            }
             &Scalar::String(_) => {
                 values
                     .iter()
@@ -179,6 +251,77 @@ impl From<Vec<Scalar>> for Array {
                     .collect::<NullVec<String>>()
                     .into()
             }
+            &Scalar::Binary(_) => {
+                values
+                    .iter()
+                    .map(|x| x.as_binary())
+                    .collect::<NullVec<Vec<u8>>>()
+                    .into()
+            }
+            &Scalar::List(_) => {
+                let rows: Vec<Option<Array>> = values
+                    .iter()
+                    .map(|x| match *x {
+                        Scalar::List(ref val) => Some(val.clone()),
+                        Scalar::Null => None,
+                        _ => panic!("cannot convert to specified type"),
+                    })
+                    .collect();
+                Array::ListArray(ListColumn::from_rows(rows))
+            }
+            &Scalar::Struct(ref names) => {
+                // check all rows up front, ragged rows can't be indexed by position
+                for x in values.iter() {
+                    if let Scalar::Struct(ref val) = *x {
+                        assert!(same_fields(val, names), "struct must have the same fields");
+                    }
+                }
+                let fields: Vec<(String, Array)> = names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, _))| {
+                        let field: Vec<Scalar> = values
+                            .iter()
+                            .map(|x| match *x {
+                                Scalar::Struct(ref val) => val[i].1.clone(),
+                                Scalar::Null => Scalar::Null,
+                                _ => panic!("cannot convert to specified type"),
+                            })
+                            .collect();
+                        (name.clone(), field.into())
+                    })
+                    .collect();
+                let mask: Vec<bool> = values.iter().map(|x| *x == Scalar::Null).collect();
+                Array::StructArray(StructColumn::with_mask(fields, Some(mask)))
+            }
+            &Scalar::Date32(_) => {
+                values
+                    .iter()
+                    .map(|x| Nullable::<Date32>::from(x.clone()))
+                    .collect::<NullVec<Date32>>()
+                    .into()
+            }
+            &Scalar::Timestamp(_) => {
+                values
+                    .iter()
+                    .map(|x| Nullable::<Timestamp>::from(x.clone()))
+                    .collect::<NullVec<Timestamp>>()
+                    .into()
+            }
+            &Scalar::Duration(_) => {
+                values
+                    .iter()
+                    .map(|x| Nullable::<Duration>::from(x.clone()))
+                    .collect::<NullVec<Duration>>()
+                    .into()
+            }
+            &Scalar::Decimal(_) => {
+                values
+                    .iter()
+                    .map(|x| Nullable::<Decimal>::from(x.clone()))
+                    .collect::<NullVec<Decimal>>()
+                    .into()
+            }
             // ToDo: Fix me
             &Scalar::Null => panic!("unable to infer dtype"),
         }
@@ -269,6 +412,22 @@ impl From<Array> for Vec<Scalar> {
                     })
                     .collect()
             }
+            Array::Int128Array(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::i128(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::UInt128Array(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::u128(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
             Array::Float64Array(vals) => {
                 vals.into_iter()
                     .map(|x| match x {
@@ -293,6 +452,14 @@ impl From<Array> for Vec<Scalar> {
                     })
                     .collect()
             }
+            Array::CharArray(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::char(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
             Array::StringArray(vals) => {
                 vals.into_iter()
                     .map(|x| match x {
@@ -301,6 +468,65 @@ impl From<Array> for Vec<Scalar> {
                     })
                     .collect()
             }
+            Array::Utf8Array(vals) => {
+                vals.iter()
+                    .map(|x| match x {
+                        Some(val) => Scalar::String(val.to_string()),
+                        None => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::BinaryArray(vals) => {
+                vals.iter()
+                    .map(|x| match x {
+                        Some(val) => Scalar::Binary(val.to_vec()),
+                        None => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::ListArray(vals) => (0..vals.len()).map(|i| vals.iloc(&i)).collect(),
+            Array::StructArray(vals) => (0..vals.len()).map(|i| vals.iloc(&i)).collect(),
+            Array::CategoricalArray(vals) => {
+                vals.to_nullvec()
+                    .into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::String(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::Date32Array(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::Date32(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::TimestampArray(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::Timestamp(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::DurationArray(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::Duration(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
+            Array::DecimalArray(vals) => {
+                vals.into_iter()
+                    .map(|x| match x {
+                        Nullable::Value(val) => Scalar::Decimal(val),
+                        Nullable::Null => Scalar::Null,
+                    })
+                    .collect()
+            }
         }
     }
 }
@@ -394,12 +620,42 @@ add_scalar_conversion!(u32);
 add_scalar_conversion!(u16);
 add_scalar_conversion!(u8);
 add_scalar_conversion!(usize);
+add_scalar_conversion!(i128);
+add_scalar_conversion!(u128);
 add_scalar_conversion!(f64);
 add_scalar_conversion!(f32);
 add_scalar_conversion!(bool);
+add_scalar_conversion!(char);
+add_scalar_conversion!(Date32);
+add_scalar_conversion!(Timestamp);
+add_scalar_conversion!(Duration);
+add_scalar_conversion!(Decimal);
 // String does not add a From<Scalar> impl to not conflict with the impls below
 add_scalar_conversion_str!(String);
 
+// binary conversions
+
+impl From<Vec<u8>> for Scalar {
+    fn from(value: Vec<u8>) -> Self {
+        Scalar::Binary(value)
+    }
+}
+
+impl From<Nullable<Vec<u8>>> for Scalar {
+    fn from(value: Nullable<Vec<u8>>) -> Self {
+        match value {
+            Nullable::Null => Scalar::Null,
+            Nullable::Value(val) => Scalar::Binary(val),
+        }
+    }
+}
+
+impl From<Scalar> for Nullable<Vec<u8>> {
+    fn from(value: Scalar) -> Self {
+        value.as_binary()
+    }
+}
+
 // &str and String conversions
 // Libraries serializing Scalars will first create strings from them before writing them out
 
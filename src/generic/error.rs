@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when an operation doesn't support the dtype of `Array`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DtypeError {
+    operation: String,
+    dtype: String,
+}
+
+impl DtypeError {
+    pub fn new(operation: &str, dtype: &str) -> Self {
+        DtypeError {
+            operation: operation.to_string(),
+            dtype: dtype.to_string(),
+        }
+    }
+
+    /// Returns the name of the operation.
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+
+    /// Returns the dtype which the operation doesn't support.
+    pub fn dtype(&self) -> &str {
+        &self.dtype
+    }
+}
+
+impl fmt::Display for DtypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not supported for dtype {}", self.operation, self.dtype)
+    }
+}
+
+impl Error for DtypeError {}
@@ -1,10 +1,19 @@
+use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+use decimal::Decimal;
 use nullvec::NullVec;
+use temporal::{Date32, Duration, Timestamp};
 
 mod array_impl;
 mod array_impl_aggregation;
+mod array_impl_cum;
 mod array_impl_slicer;
 mod convert;
+mod error;
 mod scalar_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub use self::error::DtypeError;
 
 /// Generic scalar which can contain arbitrary primitive types.
 #[allow(non_camel_case_types)]
@@ -30,14 +39,34 @@ pub enum Scalar {
     u8(u8),
     /// Store `usize` value
     usize(usize),
+    /// Store `i128` value
+    i128(i128),
+    /// Store `u128` value
+    u128(u128),
     /// Store `f64` value
     f64(f64),
     /// Store `f32` value
     f32(f32),
     /// Store `bool` value
     bool(bool),
+    /// Store `char` value
+    char(char),
     /// Store `String` value
     String(String),
+    /// Store binary value
+    Binary(Vec<u8>),
+    /// Store `Date32` value
+    Date32(Date32),
+    /// Store `Timestamp` value
+    Timestamp(Timestamp),
+    /// Store `Duration` value
+    Duration(Duration),
+    /// Store `Decimal` value
+    Decimal(Decimal),
+    /// Store list value as `Array`
+    List(Array),
+    /// Store struct value as named fields
+    Struct(Vec<(String, Scalar)>),
     /// Store `Null`
     Null,
 }
@@ -65,12 +94,41 @@ pub enum Array {
     UInt8Array(NullVec<u8>),
     /// Nullable `usize` array
     UsizeArray(NullVec<usize>),
+    /// Nullable `i128` array
+    Int128Array(NullVec<i128>),
+    /// Nullable `u128` array
+    UInt128Array(NullVec<u128>),
     /// Nullable `f64` array
     Float64Array(NullVec<f64>),
     /// Nullable `f32` array
     Float32Array(NullVec<f32>),
     /// Nullable `bool` array
     BoolArray(NullVec<bool>),
+    /// Nullable `char` array
+    CharArray(NullVec<char>),
     /// Nullable `String` array
     StringArray(NullVec<String>),
+    /// Nullable `String` array stored in a single byte buffer
+    Utf8Array(Utf8Column),
+    /// Nullable binary array stored in a single byte buffer
+    BinaryArray(BinaryColumn),
+    /// Nullable `String` array encoded as codes which refer to categories
+    CategoricalArray(Categorical),
+    /// Nullable `Date32` array
+    Date32Array(NullVec<Date32>),
+    /// Nullable `Timestamp` array
+    TimestampArray(NullVec<Timestamp>),
+    /// Nullable `Duration` array
+    DurationArray(NullVec<Duration>),
+    /// Nullable `Decimal` array
+    DecimalArray(NullVec<Decimal>),
+    /// Nullable list array whose elements are stored in a child `Array`
+    ListArray(ListColumn),
+    /// Nullable struct array whose fields are stored in named child `Array`s
+    StructArray(StructColumn),
+}
+
+/// Returns whether struct scalars have the same field names in the same order.
+fn same_fields(left: &[(String, Scalar)], right: &[(String, Scalar)]) -> bool {
+    left.len() == right.len() && left.iter().zip(right.iter()).all(|(l, r)| l.0 == r.0)
 }
@@ -1,7 +1,10 @@
 use std::fmt;
 
 use super::Scalar;
+use decimal::Decimal;
 use nullable::Nullable;
+use temporal::{Date32, Duration, Timestamp};
+use traits::Stringify;
 
 
 macro_rules! iml_scalar_as {
@@ -25,6 +28,8 @@ macro_rules! iml_scalar_as {
                     &Scalar::u16(val) => Nullable::Value(val as $t),
                     &Scalar::u8(val) => Nullable::Value(val as $t),
                     &Scalar::usize(val) => Nullable::Value(val as $t),
+                    &Scalar::i128(val) => Nullable::Value(val as $t),
+                    &Scalar::u128(val) => Nullable::Value(val as $t),
                     &Scalar::f64(val) => Nullable::Value(val as $t),
                     &Scalar::f32(val) => Nullable::Value(val as $t),
                     _ => panic!("cannot convert to specified type")
@@ -51,6 +56,8 @@ iml_scalar_as!(u32, as_u32, is_u32);
 iml_scalar_as!(u16, as_u16, is_u16);
 iml_scalar_as!(u8, as_u8, is_u8);
 iml_scalar_as!(usize, as_usize, is_usize);
+iml_scalar_as!(i128, as_i128, is_i128);
+iml_scalar_as!(u128, as_u128, is_u128);
 iml_scalar_as!(f64, as_f64, is_f64);
 iml_scalar_as!(f32, as_f32, is_f32);
 
@@ -88,6 +95,45 @@ impl Scalar {
     }
 }
 
+impl Scalar {
+    pub fn as_binary(&self) -> Nullable<Vec<u8>> {
+        match *self {
+            Scalar::Null => Nullable::Null,
+            Scalar::Binary(ref val) => Nullable::Value(val.clone()),
+            _ => panic!("cannot convert to specified type"),
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        matches!(*self, Scalar::Binary(_))
+    }
+}
+
+macro_rules! impl_scalar_as_copy {
+    ($t:ident, $as_op:ident, $is_op:ident) => {
+        impl Scalar {
+            /// Convert the value to specified type
+            pub fn $as_op(&self) -> Nullable<$t> {
+                match *self {
+                    Scalar::Null => Nullable::Null,
+                    Scalar::$t(val) => Nullable::Value(val),
+                    _ => panic!("cannot convert to specified type"),
+                }
+            }
+
+            /// Return whether the scalar is specific type
+            pub fn $is_op(&self) -> bool {
+                matches!(*self, Scalar::$t(_))
+            }
+        }
+    };
+}
+impl_scalar_as_copy!(char, as_char, is_char);
+impl_scalar_as_copy!(Date32, as_date32, is_date32);
+impl_scalar_as_copy!(Timestamp, as_timestamp, is_timestamp);
+impl_scalar_as_copy!(Duration, as_duration, is_duration);
+impl_scalar_as_copy!(Decimal, as_decimal, is_decimal);
+
 impl Scalar {
     pub fn dtype(&self) -> String {
         match self {
@@ -102,10 +148,20 @@ impl Scalar {
             &Scalar::u16(_) => "u16".to_string(),
             &Scalar::u8(_) => "u8".to_string(),
             &Scalar::usize(_) => "usize".to_string(),
+            &Scalar::i128(_) => "i128".to_string(),
+            &Scalar::u128(_) => "u128".to_string(),
             &Scalar::f64(_) => "f64".to_string(),
             &Scalar::f32(_) => "f32".to_string(),
             &Scalar::bool(_) => "bool".to_string(),
+            &Scalar::char(_) => "char".to_string(),
             &Scalar::String(_) => "str".to_string(),
+            &Scalar::Binary(_) => "binary".to_string(),
+            &Scalar::List(_) => "list".to_string(),
+            &Scalar::Struct(_) => "struct".to_string(),
+            &Scalar::Date32(_) => "date32".to_string(),
+            &Scalar::Timestamp(_) => "timestamp".to_string(),
+            &Scalar::Duration(_) => "duration".to_string(),
+            &Scalar::Decimal(_) => "decimal".to_string(),
         }
     }
 }
@@ -126,10 +182,34 @@ impl fmt::Display for Scalar {
             &Scalar::u16(ref val) => write!(f, "{}", val),
             &Scalar::u8(ref val) => write!(f, "{}", val),
             &Scalar::usize(ref val) => write!(f, "{}", val),
+            &Scalar::i128(ref val) => write!(f, "{}", val),
+            &Scalar::u128(ref val) => write!(f, "{}", val),
             &Scalar::f64(ref val) => write!(f, "{}", val),
             &Scalar::f32(ref val) => write!(f, "{}", val),
             &Scalar::bool(ref val) => write!(f, "{}", val),
+            &Scalar::char(ref val) => write!(f, "{}", val),
             &Scalar::String(ref val) => write!(f, "{}", val),
+            &Scalar::Binary(ref val) => {
+                for b in val {
+                    write!(f, "{:02x}", b)?;
+                }
+                Ok(())
+            }
+            &Scalar::List(ref val) => write!(f, "[{}]", val.into_string_vec().join(", ")),
+            &Scalar::Struct(ref val) => {
+                write!(f, "{{")?;
+                for (i, (name, v)) in val.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, v)?;
+                }
+                write!(f, "}}")
+            }
+            &Scalar::Date32(ref val) => write!(f, "{}", val),
+            &Scalar::Timestamp(ref val) => write!(f, "{}", val),
+            &Scalar::Duration(ref val) => write!(f, "{}", val),
+            &Scalar::Decimal(ref val) => write!(f, "{}", val),
         }
     }
 }
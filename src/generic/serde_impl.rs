@@ -0,0 +1,764 @@
+//! `Scalar` is serialized as an untagged value, thus its type is inferred on
+//! deserialization: integers are restored as `i64` (`u64`, `i128` or `u128` if out
+//! of range), floats as `f64`, strings as `String` and sequences as `List`.
+//!
+//! `Array` is serialized as a map such as `{"dtype": "i64", "values": [1, null]}`.
+//! `dtype` must precede `values` to deserialize. Some dtypes have additional keys:
+//!
+//! - `category`: `values` are strings, with `categories` and `ordered`
+//! - `list`: `values` is the child `Array`, with `offsets` and `mask`
+//! - `struct`: `values` is a map from field names to child `Array`s, with `mask`
+
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{same_fields, Array, Scalar};
+use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::Slicer;
+
+const DTYPES: &[&str] = &[
+    "i64",
+    "i32",
+    "i16",
+    "i8",
+    "isize",
+    "u64",
+    "u32",
+    "u16",
+    "u8",
+    "usize",
+    "i128",
+    "u128",
+    "f64",
+    "f32",
+    "bool",
+    "char",
+    "str",
+    "utf8",
+    "binary",
+    "category",
+    "date32",
+    "timestamp",
+    "duration",
+    "decimal",
+    "list",
+    "struct",
+];
+
+const KEYS: &[&str] = &[
+    "dtype",
+    "values",
+    "categories",
+    "ordered",
+    "offsets",
+    "mask",
+];
+
+// Scalar
+
+impl Serialize for Scalar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Scalar::i64(v) => serializer.serialize_i64(v),
+            Scalar::i32(v) => serializer.serialize_i32(v),
+            Scalar::i16(v) => serializer.serialize_i16(v),
+            Scalar::i8(v) => serializer.serialize_i8(v),
+            Scalar::isize(v) => serializer.serialize_i64(v as i64),
+            Scalar::u64(v) => serializer.serialize_u64(v),
+            Scalar::u32(v) => serializer.serialize_u32(v),
+            Scalar::u16(v) => serializer.serialize_u16(v),
+            Scalar::u8(v) => serializer.serialize_u8(v),
+            Scalar::usize(v) => serializer.serialize_u64(v as u64),
+            Scalar::i128(v) => serializer.serialize_i128(v),
+            Scalar::u128(v) => serializer.serialize_u128(v),
+            Scalar::f64(v) => serializer.serialize_f64(v),
+            Scalar::f32(v) => serializer.serialize_f32(v),
+            Scalar::bool(v) => serializer.serialize_bool(v),
+            Scalar::char(v) => serializer.serialize_char(v),
+            Scalar::String(ref v) => serializer.serialize_str(v),
+            Scalar::Binary(ref v) => serializer.serialize_bytes(v),
+            Scalar::Date32(ref v) => v.serialize(serializer),
+            Scalar::Timestamp(ref v) => v.serialize(serializer),
+            Scalar::Duration(ref v) => v.serialize(serializer),
+            Scalar::Decimal(ref v) => v.serialize(serializer),
+            Scalar::List(ref vals) => {
+                let mut seq = serializer.serialize_seq(Some(vals.len()))?;
+                for i in 0..vals.len() {
+                    seq.serialize_element(&unsafe { vals.iloc_unchecked(&i) })?;
+                }
+                seq.end()
+            }
+            Scalar::Struct(ref fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields.iter() {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            Scalar::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+struct ScalarVisitor;
+
+impl<'de> Visitor<'de> for ScalarVisitor {
+    type Value = Scalar;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("scalar value")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Scalar, E> {
+        Ok(Scalar::bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Scalar, E> {
+        Ok(Scalar::i64(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Scalar, E> {
+        if value <= i64::MAX as u64 {
+            Ok(Scalar::i64(value as i64))
+        } else {
+            Ok(Scalar::u64(value))
+        }
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Scalar, E> {
+        Ok(Scalar::i128(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Scalar, E> {
+        Ok(Scalar::u128(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Scalar, E> {
+        Ok(Scalar::f64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Scalar, E> {
+        Ok(Scalar::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Scalar, E> {
+        Ok(Scalar::String(value))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Scalar, E> {
+        Ok(Scalar::Binary(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Scalar, E> {
+        Ok(Scalar::Binary(value))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Scalar, E> {
+        Ok(Scalar::Null)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Scalar, E> {
+        Ok(Scalar::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Scalar, D::Error> {
+        Scalar::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Scalar, A::Error> {
+        let mut values: Vec<Scalar> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        let rows: Vec<&Scalar> = values.iter().collect();
+        check_elements(&rows).map_err(de::Error::custom)?;
+        Ok(Scalar::List(values.into()))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Scalar, A::Error> {
+        let mut fields: Vec<(String, Scalar)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            fields.push(entry);
+        }
+        Ok(Scalar::Struct(fields))
+    }
+}
+
+/// Checks that list elements can be stored in a single `Array`, recursing into
+/// struct fields and nested lists.
+fn check_elements(values: &[&Scalar]) -> Result<(), &'static str> {
+    let first = match values.iter().find(|v| ***v != Scalar::Null) {
+        Some(first) => *first,
+        None => return Err("unable to infer dtype of list"),
+    };
+    let dtype = first.dtype();
+    for value in values.iter() {
+        match (first, *value) {
+            (_, &Scalar::Null) => {}
+            (_, value) if value.dtype() != dtype => {
+                return Err("list elements must have the same dtype");
+            }
+            (Scalar::Struct(first), Scalar::Struct(row)) if !same_fields(row, first) => {
+                return Err("struct elements must have the same fields");
+            }
+            (Scalar::List(first), Scalar::List(row)) if !same_array_type(row, first) => {
+                return Err("list elements must have the same dtype");
+            }
+            _ => {}
+        }
+    }
+    if let Scalar::Struct(first) = first {
+        for i in 0..first.len() {
+            let field: Vec<&Scalar> = values
+                .iter()
+                .filter_map(|v| match *v {
+                    Scalar::Struct(row) => Some(&row[i].1),
+                    _ => None,
+                })
+                .collect();
+            check_elements(&field)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether arrays have the same dtype, including nested children.
+fn same_array_type(left: &Array, right: &Array) -> bool {
+    match (left, right) {
+        (Array::ListArray(l), Array::ListArray(r)) => same_array_type(l.values(), r.values()),
+        (Array::StructArray(l), Array::StructArray(r)) => {
+            l.names() == r.names()
+                && l.fields()
+                    .iter()
+                    .zip(r.fields().iter())
+                    .all(|(l, r)| same_array_type(l, r))
+        }
+        _ => left.dtype() == right.dtype(),
+    }
+}
+
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ScalarVisitor)
+    }
+}
+
+// Array
+
+/// Serialize binary values as bytes rather than a sequence of integers.
+struct Bytes<'a>(&'a [u8]);
+
+impl<'a> Serialize for Bytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Deserialize binary values from either bytes or a sequence of integers.
+struct ByteBuf(Vec<u8>);
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+    type Value = ByteBuf;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<ByteBuf, E> {
+        Ok(ByteBuf(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ByteBuf, A::Error> {
+        let mut values: Vec<u8> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(ByteBuf(values))
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+struct Utf8Values<'a>(&'a Utf8Column);
+
+impl<'a> Serialize for Utf8Values<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+struct BinaryValues<'a>(&'a BinaryColumn);
+
+impl<'a> Serialize for BinaryValues<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|v| v.map(Bytes)))
+    }
+}
+
+struct FieldValues<'a>(&'a StructColumn);
+
+impl<'a> Serialize for FieldValues<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.names().iter().zip(self.0.fields().iter()))
+    }
+}
+
+impl Serialize for Array {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // dtype and values, with extra keys to restore some dtypes
+        let len = match *self {
+            Array::CategoricalArray(_) | Array::ListArray(_) => 4,
+            Array::StructArray(_) => 3,
+            _ => 2,
+        };
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("dtype", &self.dtype())?;
+        match *self {
+            Array::Int64Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Int32Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Int16Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Int8Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::IsizeArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UInt64Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UInt32Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UInt16Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UInt8Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UsizeArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Int128Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::UInt128Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Float64Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Float32Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::BoolArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::CharArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::StringArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::Utf8Array(ref vals) => map.serialize_entry("values", &Utf8Values(vals))?,
+            Array::BinaryArray(ref vals) => map.serialize_entry("values", &BinaryValues(vals))?,
+            Array::CategoricalArray(ref vals) => {
+                map.serialize_entry("values", &vals.to_nullvec())?;
+                map.serialize_entry("categories", vals.categories())?;
+                map.serialize_entry("ordered", &vals.is_ordered())?;
+            }
+            Array::Date32Array(ref vals) => map.serialize_entry("values", vals)?,
+            Array::TimestampArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::DurationArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::DecimalArray(ref vals) => map.serialize_entry("values", vals)?,
+            Array::ListArray(ref vals) => {
+                map.serialize_entry("values", vals.values())?;
+                map.serialize_entry("offsets", vals.offsets())?;
+                map.serialize_entry("mask", &vals.mask())?;
+            }
+            Array::StructArray(ref vals) => {
+                map.serialize_entry("values", &FieldValues(vals))?;
+                map.serialize_entry("mask", &vals.mask())?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// Values deserialized according to `dtype`, to be completed with other keys.
+enum Values {
+    Array(Array),
+    Fields(Vec<(String, Array)>),
+}
+
+struct ValuesSeed<'a>(&'a str);
+
+impl<'a, 'de> DeserializeSeed<'de> for ValuesSeed<'a> {
+    type Value = Values;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Values, D::Error> {
+        let array = match self.0 {
+            "i64" => Array::Int64Array(NullVec::deserialize(deserializer)?),
+            "i32" => Array::Int32Array(NullVec::deserialize(deserializer)?),
+            "i16" => Array::Int16Array(NullVec::deserialize(deserializer)?),
+            "i8" => Array::Int8Array(NullVec::deserialize(deserializer)?),
+            "isize" => Array::IsizeArray(NullVec::deserialize(deserializer)?),
+            "u64" => Array::UInt64Array(NullVec::deserialize(deserializer)?),
+            "u32" => Array::UInt32Array(NullVec::deserialize(deserializer)?),
+            "u16" => Array::UInt16Array(NullVec::deserialize(deserializer)?),
+            "u8" => Array::UInt8Array(NullVec::deserialize(deserializer)?),
+            "usize" => Array::UsizeArray(NullVec::deserialize(deserializer)?),
+            "i128" => Array::Int128Array(NullVec::deserialize(deserializer)?),
+            "u128" => Array::UInt128Array(NullVec::deserialize(deserializer)?),
+            "f64" => Array::Float64Array(NullVec::deserialize(deserializer)?),
+            "f32" => Array::Float32Array(NullVec::deserialize(deserializer)?),
+            "bool" => Array::BoolArray(NullVec::deserialize(deserializer)?),
+            "char" => Array::CharArray(NullVec::deserialize(deserializer)?),
+            // categories are applied after all keys are read
+            "str" | "category" => Array::StringArray(NullVec::deserialize(deserializer)?),
+            "utf8" => {
+                let vals: NullVec<String> = NullVec::deserialize(deserializer)?;
+                Array::Utf8Array(vals.into())
+            }
+            "binary" => {
+                let vals: NullVec<Vec<u8>> = Vec::<Option<ByteBuf>>::deserialize(deserializer)?
+                    .into_iter()
+                    .map(|v| v.map_or(Nullable::Null, |b| Nullable::new(b.0)))
+                    .collect();
+                Array::BinaryArray(vals.into())
+            }
+            "date32" => Array::Date32Array(NullVec::deserialize(deserializer)?),
+            "timestamp" => Array::TimestampArray(NullVec::deserialize(deserializer)?),
+            "duration" => Array::DurationArray(NullVec::deserialize(deserializer)?),
+            "decimal" => Array::DecimalArray(NullVec::deserialize(deserializer)?),
+            "list" => Array::deserialize(deserializer)?,
+            "struct" => {
+                return deserializer
+                    .deserialize_map(FieldsVisitor)
+                    .map(Values::Fields)
+            }
+            dtype => return Err(de::Error::unknown_variant(dtype, DTYPES)),
+        };
+        Ok(Values::Array(array))
+    }
+}
+
+struct FieldsVisitor;
+
+impl<'de> Visitor<'de> for FieldsVisitor {
+    type Value = Vec<(String, Array)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("map from field names to arrays")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields: Vec<(String, Array)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((name, field)) = map.next_entry::<String, Array>()? {
+            if fields.iter().any(|f| f.0 == name) {
+                return Err(de::Error::custom(format!("duplicated field {}", name)));
+            }
+            fields.push((name, field));
+        }
+        Ok(fields)
+    }
+}
+
+struct ArrayVisitor;
+
+impl<'de> Visitor<'de> for ArrayVisitor {
+    type Value = Array;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("map with dtype and values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Array, A::Error> {
+        let mut dtype: Option<String> = None;
+        let mut values: Option<Values> = None;
+        let mut categories: Option<Vec<String>> = None;
+        let mut ordered = false;
+        let mut offsets: Option<Vec<u64>> = None;
+        let mut mask: Option<Vec<bool>> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "dtype" => dtype = Some(map.next_value()?),
+                "values" => {
+                    let seed = match dtype {
+                        Some(ref dtype) => ValuesSeed(dtype),
+                        None => return Err(de::Error::custom("dtype must precede values")),
+                    };
+                    values = Some(map.next_value_seed(seed)?);
+                }
+                "categories" => categories = Some(map.next_value()?),
+                "ordered" => ordered = map.next_value()?,
+                "offsets" => offsets = Some(map.next_value()?),
+                "mask" => mask = map.next_value()?,
+                _ => return Err(de::Error::unknown_field(&key, KEYS)),
+            }
+        }
+
+        let dtype = dtype.ok_or_else(|| de::Error::missing_field("dtype"))?;
+        let values = values.ok_or_else(|| de::Error::missing_field("values"))?;
+        match (dtype.as_str(), values) {
+            ("category", Values::Array(Array::StringArray(vals))) => {
+                let categories =
+                    categories.ok_or_else(|| de::Error::missing_field("categories"))?;
+                let mut sorted = categories.clone();
+                sorted.sort();
+                sorted.dedup();
+                if sorted.len() != categories.len() {
+                    return Err(de::Error::custom("categories must be unique"));
+                }
+                if let Some(v) = vals
+                    .iter_not_null()
+                    .find(|v| sorted.binary_search(v).is_err())
+                {
+                    return Err(de::Error::custom(format!(
+                        "value {} is not in categories",
+                        v
+                    )));
+                }
+                Ok(Categorical::with_categories(&vals, categories, ordered).into())
+            }
+            ("list", Values::Array(child)) => {
+                let offsets = offsets.ok_or_else(|| de::Error::missing_field("offsets"))?;
+                let valid = offsets.first() == Some(&0)
+                    && offsets.windows(2).all(|w| w[0] <= w[1])
+                    && offsets.last().is_some_and(|&o| o as usize <= child.len())
+                    && mask.as_ref().is_none_or(|m| m.len() + 1 == offsets.len());
+                if !valid {
+                    return Err(de::Error::custom("invalid list offsets or mask"));
+                }
+                Ok(ListColumn::new(child, offsets, mask).into())
+            }
+            ("struct", Values::Fields(fields)) => {
+                let len = match fields.first() {
+                    Some(f) => f.1.len(),
+                    None => return Err(de::Error::custom("struct must have at least one field")),
+                };
+                if fields.iter().any(|f| f.1.len() != len)
+                    || mask.as_ref().is_some_and(|m| m.len() != len)
+                {
+                    return Err(de::Error::custom("struct fields must have the same length"));
+                }
+                Ok(StructColumn::with_mask(fields, mask).into())
+            }
+            (_, Values::Array(array)) => Ok(array),
+            (_, Values::Fields(_)) => unreachable!(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Array {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ArrayVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+    use decimal::Decimal;
+    use generic::{Array, Scalar};
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use serde_json;
+    use temporal::{Date32, Duration, TimeUnit, Timestamp};
+    use traits::NullStorable;
+
+    // the second element is Null
+    fn masked<T: NullStorable>(values: Vec<T>) -> NullVec<T> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i == 1 {
+                    Nullable::Null
+                } else {
+                    Nullable::new(v)
+                }
+            })
+            .collect()
+    }
+
+    fn roundtrip(array: Array) {
+        let s = serde_json::to_string(&array).unwrap();
+        let res: Array = serde_json::from_str(&s).unwrap();
+        assert_eq!(res, array);
+        assert_eq!(res.dtype(), array.dtype());
+    }
+
+    #[test]
+    fn test_array_json() {
+        let a = Array::Int64Array(masked(vec![1, 2, 3]));
+        let s = serde_json::to_string(&a).unwrap();
+        assert_eq!(s, "{\"dtype\":\"i64\",\"values\":[1,null,3]}");
+
+        let res: Array = serde_json::from_str("{\"dtype\":\"u8\",\"values\":[null,2]}").unwrap();
+        assert_eq!(
+            res,
+            Array::UInt8Array(NullVec::with_mask(vec![0, 2], Some(vec![true, false])))
+        );
+    }
+
+    #[test]
+    fn test_array_roundtrip_primitive() {
+        roundtrip(Array::Int64Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int32Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int16Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int8Array(masked(vec![1, 2, -3])));
+        roundtrip(Array::IsizeArray(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt64Array(masked(vec![1, 2, u64::MAX])));
+        roundtrip(Array::UInt32Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt16Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt8Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UsizeArray(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int128Array(masked(vec![1, 2, i128::MIN])));
+        roundtrip(Array::UInt128Array(masked(vec![1, 2, u128::MAX])));
+        roundtrip(Array::Float64Array(masked(vec![1.5, 2., 0.1])));
+        roundtrip(Array::Float32Array(masked(vec![1.5, 2., 0.1])));
+        roundtrip(Array::BoolArray(masked(vec![true, false, false])));
+        roundtrip(Array::CharArray(masked(vec!['a', 'b', 'c'])));
+    }
+
+    #[test]
+    fn test_array_roundtrip_string() {
+        let values = vec!["a".to_string(), "b".to_string(), "\"c\"".to_string()];
+        roundtrip(Array::StringArray(masked(values.clone())));
+        roundtrip(Array::Utf8Array(Utf8Column::with_mask(
+            values.clone(),
+            Some(vec![false, true, false]),
+        )));
+        roundtrip(Array::BinaryArray(BinaryColumn::with_mask(
+            vec![vec![0, 255], vec![], vec![1]],
+            Some(vec![false, true, false]),
+        )));
+        let c = Categorical::with_categories(
+            &masked(values.clone()),
+            vec!["\"c\"".to_string(), "a".to_string()],
+            true,
+        );
+        roundtrip(Array::CategoricalArray(c));
+    }
+
+    #[test]
+    fn test_array_roundtrip_temporal() {
+        roundtrip(Array::Date32Array(masked(vec![
+            Date32::from_ymd(2017, 3, 1),
+            Date32::from_ymd(1960, 1, 1),
+        ])));
+        roundtrip(Array::TimestampArray(masked(vec![
+            Timestamp::new(1_500, TimeUnit::Millisecond),
+            Timestamp::new(0, TimeUnit::Millisecond),
+            Timestamp::new(-1, TimeUnit::Millisecond).with_offset(-3_600),
+        ])));
+        roundtrip(Array::DurationArray(masked(vec![
+            Duration::new(90, TimeUnit::Second),
+            Duration::new(0, TimeUnit::Second),
+        ])));
+        roundtrip(Array::DecimalArray(masked(vec![
+            Decimal::new(-12345, 5, 2),
+            Decimal::new(0, 1, 0),
+            Decimal::new(5, 3, 3),
+        ])));
+    }
+
+    #[test]
+    fn test_array_roundtrip_nested() {
+        let list = ListColumn::from_rows(vec![
+            Some(Array::new(vec![1i64, 2])),
+            None,
+            Some(Array::new(Vec::<i64>::new())),
+        ]);
+        roundtrip(Array::ListArray(list.clone()));
+
+        let s = StructColumn::with_mask(
+            vec![
+                ("b".to_string(), Array::ListArray(list)),
+                (
+                    "a".to_string(),
+                    Array::StringArray(masked(vec![
+                        "x".to_string(),
+                        "y".to_string(),
+                        "z".to_string(),
+                    ])),
+                ),
+            ],
+            Some(vec![true, false, false]),
+        );
+        roundtrip(Array::StructArray(s));
+    }
+
+    #[test]
+    fn test_array_invalid() {
+        let invalid = [
+            "{\"values\":[1],\"dtype\":\"i64\"}",
+            "{\"dtype\":\"i64\"}",
+            "{\"dtype\":\"x\",\"values\":[1]}",
+            "{\"dtype\":\"i64\",\"values\":[\"a\"]}",
+            "{\"dtype\":\"i64\",\"values\":[1],\"other\":1}",
+            "{\"dtype\":\"category\",\"values\":[\"a\"],\"categories\":[\"a\",\"a\"]}",
+            "{\"dtype\":\"category\",\"values\":[\"a\",\"b\"],\"categories\":[\"a\"]}",
+            "{\"dtype\":\"list\",\"values\":{\"dtype\":\"i64\",\"values\":[1]},\"offsets\":[0,2]}",
+            "{\"dtype\":\"struct\",\"values\":{}}",
+        ];
+        for s in invalid.iter() {
+            assert!(serde_json::from_str::<Array>(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_scalar_roundtrip() {
+        let scalars = vec![
+            Scalar::i64(-1),
+            Scalar::u64(u64::MAX),
+            Scalar::f64(1.5),
+            Scalar::bool(true),
+            Scalar::String("a".to_string()),
+            Scalar::Null,
+            Scalar::List(Array::Int64Array(masked(vec![1, 2]))),
+            Scalar::Struct(vec![
+                ("b".to_string(), Scalar::i64(1)),
+                ("a".to_string(), Scalar::Null),
+            ]),
+        ];
+        for scalar in scalars.into_iter() {
+            let s = serde_json::to_string(&scalar).unwrap();
+            assert_eq!(serde_json::from_str::<Scalar>(&s).unwrap(), scalar);
+        }
+    }
+
+    #[test]
+    fn test_scalar_untagged() {
+        let s = serde_json::to_string(&Scalar::List(Array::new(vec![1i64, 2]))).unwrap();
+        assert_eq!(s, "[1,2]");
+        let s = serde_json::to_string(&Scalar::Date32(Date32::from_ymd(2017, 3, 1))).unwrap();
+        assert_eq!(s, "\"2017-03-01\"");
+        let s = serde_json::to_string(&Scalar::u8(3)).unwrap();
+        assert_eq!(serde_json::from_str::<Scalar>(&s).unwrap(), Scalar::i64(3));
+
+        let res = serde_json::from_str::<Scalar>("[null, 1]").unwrap();
+        assert_eq!(
+            res,
+            Scalar::List(Array::Int64Array(NullVec::with_mask(
+                vec![0, 1],
+                Some(vec![true, false])
+            )))
+        );
+
+        assert!(serde_json::from_str::<Scalar>("[]").is_err());
+        assert!(serde_json::from_str::<Scalar>("[1, \"a\"]").is_err());
+        assert!(serde_json::from_str::<Scalar>("[{\"a\": 1}, {\"a\": 2, \"b\": 3}]").is_err());
+        assert!(serde_json::from_str::<Scalar>("[{\"a\": 1}, {\"b\": 2}]").is_err());
+    }
+
+    #[test]
+    fn test_scalar_nested_dtype() {
+        let invalid = [
+            "[[1], [\"a\"]]",
+            "[[1], [1.5]]",
+            "[[[1]], [[\"a\"]]]",
+            "[[{\"a\": 1}], [{\"a\": \"x\"}]]",
+            "[{\"a\": 1}, {\"a\": \"x\"}]",
+            "[{\"a\": [1]}, {\"a\": [1.5]}]",
+            "[{\"a\": null}, {\"a\": null}]",
+        ];
+        for s in invalid.iter() {
+            assert!(serde_json::from_str::<Scalar>(s).is_err(), "{}", s);
+        }
+
+        let res = serde_json::from_str::<Scalar>("[[1], null, [2, null]]").unwrap();
+        assert_eq!(res.dtype(), "list");
+        let res = serde_json::from_str::<Scalar>("[{\"a\": null}, null, {\"a\": 1}]").unwrap();
+        assert_eq!(res.dtype(), "list");
+    }
+}
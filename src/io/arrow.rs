@@ -0,0 +1,1197 @@
+//! Arrow IPC streaming and file formats.
+//!
+//! Each message is encapsulated as
+//!
+//! ```text
+//! continuation 0xFFFFFFFF | metadata length: i32 | Message flatbuffer | body
+//! ```
+//!
+//! where the metadata is padded to 8 bytes. A stream is a `Schema` message
+//! followed by `RecordBatch` messages and the end-of-stream marker, which is the
+//! continuation with zero length. A file is a stream enclosed by magic "ARROW1",
+//! with a `Footer` flatbuffer locating record batches, followed by its length as
+//! `i32`, before the trailing magic.
+//!
+//! Supported Arrow types are integers, single and double precision floating
+//! points, boolean, utf8 and binary (large utf8 and binary only on read).
+//! Dictionary-encoded fields and compressed bodies are not supported.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::str;
+
+use columnar::{BinaryColumn, Utf8Column};
+use frame::DataFrame;
+use generic::Array;
+use nullvec::NullVec;
+use traits::{Append, NullStorable, Slicer};
+
+use super::flatbuf::{self, get_i64, Fields, Table, Value};
+use super::native::{check_offsets, invalid_data, Element};
+
+const MAGIC: &[u8; 6] = b"ARROW1";
+const CONTINUATION: u32 = 0xFFFF_FFFF;
+
+// MetadataVersion
+const V4: i16 = 3;
+const V5: i16 = 4;
+
+// MessageHeader
+const SCHEMA: u8 = 1;
+const RECORD_BATCH: u8 = 3;
+
+// Type, names are used in error messages
+const TYPE_NAMES: [&str; 27] = [
+    "NONE",
+    "Null",
+    "Int",
+    "FloatingPoint",
+    "Binary",
+    "Utf8",
+    "Bool",
+    "Decimal",
+    "Date",
+    "Time",
+    "Timestamp",
+    "Interval",
+    "List",
+    "Struct",
+    "Union",
+    "FixedSizeBinary",
+    "FixedSizeList",
+    "Map",
+    "Duration",
+    "LargeBinary",
+    "LargeUtf8",
+    "LargeList",
+    "RunEndEncoded",
+    "BinaryView",
+    "Utf8View",
+    "ListView",
+    "LargeListView",
+];
+const INT: u8 = 2;
+const FLOATING_POINT: u8 = 3;
+const BINARY: u8 = 4;
+const UTF8: u8 = 5;
+const BOOL: u8 = 6;
+const LARGE_BINARY: u8 = 19;
+const LARGE_UTF8: u8 = 20;
+
+// Precision
+const SINGLE: i16 = 1;
+const DOUBLE: i16 = 2;
+
+// FieldNode and Buffer
+const STRUCT_LEN: usize = 16;
+// Block
+const BLOCK_LEN: usize = 24;
+
+/// Arrow type of a column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DataType {
+    /// bit width and signedness
+    Int(i32, bool),
+    /// precision
+    Float(i16),
+    Bool,
+    /// whether offsets are 64-bit
+    Utf8(bool),
+    Binary(bool),
+}
+
+impl DataType {
+    /// Returns the Arrow type `array` is written as.
+    fn of(array: &Array) -> Option<DataType> {
+        match *array {
+            Array::Int64Array(_) | Array::IsizeArray(_) => Some(DataType::Int(64, true)),
+            Array::Int32Array(_) => Some(DataType::Int(32, true)),
+            Array::Int16Array(_) => Some(DataType::Int(16, true)),
+            Array::Int8Array(_) => Some(DataType::Int(8, true)),
+            Array::UInt64Array(_) | Array::UsizeArray(_) => Some(DataType::Int(64, false)),
+            Array::UInt32Array(_) => Some(DataType::Int(32, false)),
+            Array::UInt16Array(_) => Some(DataType::Int(16, false)),
+            Array::UInt8Array(_) => Some(DataType::Int(8, false)),
+            Array::Float64Array(_) => Some(DataType::Float(DOUBLE)),
+            Array::Float32Array(_) => Some(DataType::Float(SINGLE)),
+            Array::BoolArray(_) => Some(DataType::Bool),
+            Array::StringArray(_) | Array::Utf8Array(_) => Some(DataType::Utf8(false)),
+            Array::BinaryArray(_) => Some(DataType::Binary(false)),
+            _ => None,
+        }
+    }
+
+    /// Returns type id and the type table.
+    fn encode(&self) -> (u8, Fields) {
+        match *self {
+            DataType::Int(width, signed) => {
+                (INT, vec![(0, Value::Int(width)), (1, Value::Bool(signed))])
+            }
+            DataType::Float(precision) => (FLOATING_POINT, vec![(0, Value::Short(precision))]),
+            DataType::Bool => (BOOL, vec![]),
+            DataType::Utf8(large) => (if large { LARGE_UTF8 } else { UTF8 }, vec![]),
+            DataType::Binary(large) => (if large { LARGE_BINARY } else { BINARY }, vec![]),
+        }
+    }
+
+    /// Decode the type of `field`.
+    fn decode(field: &Table) -> io::Result<DataType> {
+        let id = field.u8(2, 0)?;
+        let table = field.table(3)?;
+        let data_type = match (id, table) {
+            (INT, Some(t)) => match (t.i32(0, 0)?, t.bool(1, false)?) {
+                (w @ 8, s) | (w @ 16, s) | (w @ 32, s) | (w @ 64, s) => Some(DataType::Int(w, s)),
+                _ => None,
+            },
+            (FLOATING_POINT, Some(t)) => match t.i16(0, 0)? {
+                p @ SINGLE | p @ DOUBLE => Some(DataType::Float(p)),
+                _ => None,
+            },
+            (BOOL, _) => Some(DataType::Bool),
+            (UTF8, _) => Some(DataType::Utf8(false)),
+            (LARGE_UTF8, _) => Some(DataType::Utf8(true)),
+            (BINARY, _) => Some(DataType::Binary(false)),
+            (LARGE_BINARY, _) => Some(DataType::Binary(true)),
+            _ => None,
+        };
+        data_type.ok_or_else(|| {
+            let name = TYPE_NAMES.get(id as usize).unwrap_or(&"unknown");
+            invalid_data(format!("unsupported Arrow type {}", name))
+        })
+    }
+
+    /// Returns an empty `Array` of the type.
+    fn empty(&self) -> Array {
+        match *self {
+            DataType::Int(64, true) => Array::Int64Array(NullVec::new(vec![])),
+            DataType::Int(32, true) => Array::Int32Array(NullVec::new(vec![])),
+            DataType::Int(16, true) => Array::Int16Array(NullVec::new(vec![])),
+            DataType::Int(_, true) => Array::Int8Array(NullVec::new(vec![])),
+            DataType::Int(64, false) => Array::UInt64Array(NullVec::new(vec![])),
+            DataType::Int(32, false) => Array::UInt32Array(NullVec::new(vec![])),
+            DataType::Int(16, false) => Array::UInt16Array(NullVec::new(vec![])),
+            DataType::Int(_, false) => Array::UInt8Array(NullVec::new(vec![])),
+            DataType::Float(SINGLE) => Array::Float32Array(NullVec::new(vec![])),
+            DataType::Float(_) => Array::Float64Array(NullVec::new(vec![])),
+            DataType::Bool => Array::BoolArray(NullVec::new(vec![])),
+            DataType::Utf8(_) => Array::Utf8Array(Utf8Column::new(vec![])),
+            DataType::Binary(_) => Array::BinaryArray(BinaryColumn::new(vec![])),
+        }
+    }
+}
+
+type Schema = Vec<(String, DataType)>;
+
+fn encode_schema(schema: &[(String, DataType)]) -> Fields {
+    let fields = schema
+        .iter()
+        .map(|&(ref name, data_type)| {
+            let (id, table) = data_type.encode();
+            vec![
+                (0, Value::Str(name.clone())),
+                (1, Value::Bool(true)),
+                (2, Value::UByte(id)),
+                (3, Value::Table(table)),
+                (5, Value::Tables(vec![])),
+            ]
+        })
+        .collect();
+    vec![(0, Value::Short(0)), (1, Value::Tables(fields))]
+}
+
+fn decode_schema(schema: &Table) -> io::Result<Schema> {
+    if schema.i16(0, 0)? != 0 {
+        return Err(invalid_data("big-endian data is not supported"));
+    }
+    schema
+        .tables(1)?
+        .iter()
+        .map(|field| {
+            let name = field.str(0)?.unwrap_or("").to_string();
+            if field.table(4)?.is_some() {
+                let msg = format!("dictionary-encoded field {} is not supported", name);
+                return Err(invalid_data(msg));
+            }
+            let data_type = DataType::decode(field)
+                .map_err(|e| invalid_data(format!("field {}: {}", name, e)))?;
+            Ok((name, data_type))
+        })
+        .collect()
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+// Writing
+
+/// Bit-packed `bits`, LSB first.
+fn bitmap<I: Iterator<Item = bool>>(bits: I, len: usize) -> Vec<u8> {
+    let mut bitmap = vec![0u8; len.div_ceil(8)];
+    for (i, bit) in bits.enumerate() {
+        if bit {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+    }
+    bitmap
+}
+
+fn null_mask<T: Clone + NullStorable>(values: &NullVec<T>) -> Option<Vec<bool>> {
+    if values.has_null() {
+        Some(values.is_null())
+    } else {
+        None
+    }
+}
+
+/// Body of a record batch with its field nodes and buffers.
+struct Body {
+    data: Vec<u8>,
+    nodes: Vec<u8>,
+    buffers: Vec<u8>,
+}
+
+impl Body {
+    fn buffer(&mut self, bytes: &[u8]) {
+        self.buffers
+            .extend_from_slice(&(self.data.len() as i64).to_le_bytes());
+        self.buffers
+            .extend_from_slice(&(bytes.len() as i64).to_le_bytes());
+        self.data.extend_from_slice(bytes);
+        let len = self.data.len().div_ceil(8) * 8;
+        self.data.resize(len, 0);
+    }
+
+    /// Write field node and validity buffer.
+    fn validity(&mut self, len: usize, mask: Option<&[bool]>) {
+        let null_count = mask.map_or(0, |m| m.iter().filter(|&&n| n).count());
+        self.nodes.extend_from_slice(&(len as i64).to_le_bytes());
+        self.nodes
+            .extend_from_slice(&(null_count as i64).to_le_bytes());
+        match mask {
+            Some(mask) if null_count > 0 => self.buffer(&bitmap(mask.iter().map(|&n| !n), len)),
+            _ => self.buffer(&[]),
+        }
+    }
+
+    fn nullvec<T: Element + Clone + NullStorable>(&mut self, values: &NullVec<T>) {
+        self.validity(values.len(), null_mask(values).as_deref());
+        let mut data = Vec::with_capacity(values.len() * T::WIDTH);
+        for (_, v) in values.iter_raw() {
+            v.put(&mut data);
+        }
+        self.buffer(&data);
+    }
+
+    /// Write offsets as `i32` and values, rebasing offsets to start from 0.
+    fn variable(&mut self, offsets: &[u64], values: &[u8]) -> io::Result<()> {
+        let first = offsets[0];
+        let last = offsets[offsets.len() - 1];
+        if last - first > i32::MAX as u64 {
+            let msg = "values exceed the limit of 32-bit offsets".to_string();
+            return Err(invalid_input(msg));
+        }
+        let mut data = Vec::with_capacity(offsets.len() * 4);
+        for &o in offsets.iter() {
+            data.extend_from_slice(&((o - first) as i32).to_le_bytes());
+        }
+        self.buffer(&data);
+        self.buffer(&values[first as usize..last as usize]);
+        Ok(())
+    }
+
+    fn column(&mut self, array: &Array) -> io::Result<()> {
+        match *array {
+            Array::Int64Array(ref vals) => self.nullvec(vals),
+            Array::Int32Array(ref vals) => self.nullvec(vals),
+            Array::Int16Array(ref vals) => self.nullvec(vals),
+            Array::Int8Array(ref vals) => self.nullvec(vals),
+            Array::IsizeArray(ref vals) => self.nullvec(vals),
+            Array::UInt64Array(ref vals) => self.nullvec(vals),
+            Array::UInt32Array(ref vals) => self.nullvec(vals),
+            Array::UInt16Array(ref vals) => self.nullvec(vals),
+            Array::UInt8Array(ref vals) => self.nullvec(vals),
+            Array::UsizeArray(ref vals) => self.nullvec(vals),
+            Array::Float64Array(ref vals) => self.nullvec(vals),
+            Array::Float32Array(ref vals) => self.nullvec(vals),
+            Array::BoolArray(ref vals) => {
+                self.validity(vals.len(), null_mask(vals).as_deref());
+                self.buffer(&bitmap(vals.iter_raw().map(|(_, &v)| v), vals.len()));
+            }
+            Array::StringArray(ref vals) => {
+                self.validity(vals.len(), null_mask(vals).as_deref());
+                let mut offsets: Vec<u64> = vec![0];
+                let mut values: Vec<u8> = vec![];
+                for (_, v) in vals.iter_raw() {
+                    values.extend_from_slice(v.as_bytes());
+                    offsets.push(values.len() as u64);
+                }
+                self.variable(&offsets, &values)?;
+            }
+            Array::Utf8Array(ref vals) => {
+                self.validity(vals.len(), vals.mask());
+                self.variable(vals.offsets(), vals.values_buffer())?;
+            }
+            Array::BinaryArray(ref vals) => {
+                self.validity(vals.len(), vals.mask());
+                self.variable(vals.offsets(), vals.values_buffer())?;
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+/// Writer of encapsulated messages, shared by stream and file writers.
+struct MessageWriter<W: Write> {
+    writer: W,
+    position: u64,
+    schema: Option<Schema>,
+}
+
+impl<W: Write> MessageWriter<W> {
+    fn new(writer: W) -> Self {
+        MessageWriter {
+            writer,
+            position: 0,
+            schema: None,
+        }
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.writer.write_all(bytes)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Write a message, returning its offset, metadata length including prefix
+    /// and body length.
+    fn message(&mut self, header_type: u8, header: Fields, body: &[u8]) -> io::Result<[u64; 3]> {
+        let offset = self.position;
+        let message = flatbuf::finish(&[
+            (0, Value::Short(V5)),
+            (1, Value::UByte(header_type)),
+            (2, Value::Table(header)),
+            (3, Value::Long(body.len() as i64)),
+        ]);
+        let padded = message.len().div_ceil(8) * 8;
+        self.write_all(&CONTINUATION.to_le_bytes())?;
+        self.write_all(&(padded as i32).to_le_bytes())?;
+        self.write_all(&message)?;
+        self.write_all(&vec![0; padded - message.len()])?;
+        self.write_all(body)?;
+        Ok([offset, padded as u64 + 8, body.len() as u64])
+    }
+
+    /// Write schema message if not written yet.
+    fn schema(&mut self, schema: Schema) -> io::Result<()> {
+        if self.schema.is_none() {
+            self.message(SCHEMA, encode_schema(&schema), &[])?;
+            self.schema = Some(schema);
+        }
+        Ok(())
+    }
+
+    fn batch(&mut self, columns: &[(&str, &Array)]) -> io::Result<[u64; 3]> {
+        let mut schema: Schema = Vec::with_capacity(columns.len());
+        for &(name, array) in columns.iter() {
+            let data_type = DataType::of(array).ok_or_else(|| {
+                let msg = format!(
+                    "dtype {} of column {} is not supported",
+                    array.dtype(),
+                    name
+                );
+                invalid_input(msg)
+            })?;
+            schema.push((name.to_string(), data_type));
+        }
+        let len = columns.first().map_or(0, |c| c.1.len());
+        if let Some(&(name, array)) = columns.iter().find(|c| c.1.len() != len) {
+            let msg = format!("column {} has {} rows, expected {}", name, array.len(), len);
+            return Err(invalid_input(msg));
+        }
+        if self.schema.as_ref().is_some_and(|s| *s != schema) {
+            let msg = "columns differ from the schema of the first batch".to_string();
+            return Err(invalid_input(msg));
+        }
+        self.schema(schema)?;
+
+        let mut body = Body {
+            data: vec![],
+            nodes: vec![],
+            buffers: vec![],
+        };
+        for &(_, array) in columns.iter() {
+            body.column(array)?;
+        }
+        let nbuffers = body.buffers.len() / STRUCT_LEN;
+        let header = vec![
+            (0, Value::Long(len as i64)),
+            (1, Value::Structs(body.nodes, columns.len())),
+            (2, Value::Structs(body.buffers, nbuffers)),
+        ];
+        self.message(RECORD_BATCH, header, &body.data)
+    }
+
+    fn end_of_stream(&mut self) -> io::Result<()> {
+        if self.schema.is_none() {
+            self.schema(vec![])?;
+        }
+        self.write_all(&CONTINUATION.to_le_bytes())?;
+        self.write_all(&[0; 4])
+    }
+}
+
+fn frame_columns(df: &DataFrame) -> Vec<(&str, &Array)> {
+    df.names()
+        .iter()
+        .map(|n| n.as_str())
+        .zip(df.columns().iter())
+        .collect()
+}
+
+/// Writer which writes named `Array`s in the Arrow IPC streaming format.
+///
+/// Each call of `write_columns` writes a record batch. `i64`, `i32`, `i16`,
+/// `i8`, unsigned integers, `f64`, `f32`, `bool`, `str`, `utf8` and `binary`
+/// columns are supported, where `isize` and `usize` are written as 64-bit
+/// integers and `str` as utf8. All batches must have the same column names and
+/// types. The stream is complete only after `finish` is called.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let a = Array::Int64Array(NullVec::with_mask(vec![1, 0], Some(vec![false, true])));
+/// let b = Array::new(vec!["x", "y"]);
+/// let mut writer = ArrowStreamWriter::new(vec![]);
+/// writer.write_columns(&[("a", &a), ("b", &b)]).unwrap();
+/// let stream = writer.finish().unwrap();
+///
+/// let columns = ArrowStreamReader::new(&stream[..]).unwrap().read().unwrap();
+/// assert_eq!(columns[0], ("a".to_string(), a));
+/// // str is read as utf8
+/// assert_eq!(columns[1].1.dtype(), "utf8");
+/// ```
+pub struct ArrowStreamWriter<W: Write> {
+    inner: MessageWriter<W>,
+}
+
+impl<W: Write> ArrowStreamWriter<W> {
+    /// Create new `ArrowStreamWriter`.
+    pub fn new(writer: W) -> Self {
+        ArrowStreamWriter {
+            inner: MessageWriter::new(writer),
+        }
+    }
+
+    /// Write named `Array`s as a record batch.
+    ///
+    /// Returns an error of `InvalidInput` kind if a dtype is not supported,
+    /// columns have different lengths or differ from the first batch.
+    pub fn write_columns(&mut self, columns: &[(&str, &Array)]) -> io::Result<()> {
+        self.inner.batch(columns).map(|_| ())
+    }
+
+    /// Write `DataFrame` as a record batch.
+    pub fn write(&mut self, df: &DataFrame) -> io::Result<()> {
+        self.write_columns(&frame_columns(df))
+    }
+
+    /// Write the end-of-stream marker and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.end_of_stream()?;
+        self.inner.writer.flush()?;
+        Ok(self.inner.writer)
+    }
+}
+
+/// Writer which writes named `Array`s in the Arrow IPC file format.
+///
+/// Supported dtypes are the same as `ArrowStreamWriter`. The file is complete
+/// only after `finish` is called.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nullvec::prelude::*;
+///
+/// let a = Array::new(vec![1.5, 2.5]);
+/// let mut writer = ArrowFileWriter::new(Cursor::new(vec![]));
+/// writer.write_columns(&[("a", &a)]).unwrap();
+/// writer.write_columns(&[("a", &a)]).unwrap();
+/// let file = writer.finish().unwrap();
+///
+/// let mut reader = ArrowFileReader::new(file).unwrap();
+/// assert_eq!(reader.num_batches(), 2);
+/// assert_eq!(reader.read_batch(1).unwrap(), vec![("a".to_string(), a)]);
+/// ```
+pub struct ArrowFileWriter<W: Write> {
+    inner: MessageWriter<W>,
+    // offset, metadata length and body length of each record batch
+    blocks: Vec<[u64; 3]>,
+}
+
+impl<W: Write> ArrowFileWriter<W> {
+    /// Create new `ArrowFileWriter`.
+    pub fn new(writer: W) -> Self {
+        ArrowFileWriter {
+            inner: MessageWriter::new(writer),
+            blocks: vec![],
+        }
+    }
+
+    /// Write named `Array`s as a record batch.
+    ///
+    /// Returns an error of `InvalidInput` kind if a dtype is not supported,
+    /// columns have different lengths or differ from the first batch.
+    pub fn write_columns(&mut self, columns: &[(&str, &Array)]) -> io::Result<()> {
+        self.write_header()?;
+        let block = self.inner.batch(columns)?;
+        self.blocks.push(block);
+        Ok(())
+    }
+
+    /// Write `DataFrame` as a record batch.
+    pub fn write(&mut self, df: &DataFrame) -> io::Result<()> {
+        self.write_columns(&frame_columns(df))
+    }
+
+    /// Write the footer and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_header()?;
+        self.inner.end_of_stream()?;
+        let mut blocks: Vec<u8> = Vec::with_capacity(self.blocks.len() * BLOCK_LEN);
+        for &[offset, metadata, body] in self.blocks.iter() {
+            blocks.extend_from_slice(&offset.to_le_bytes());
+            blocks.extend_from_slice(&(metadata as i32).to_le_bytes());
+            blocks.extend_from_slice(&[0; 4]);
+            blocks.extend_from_slice(&body.to_le_bytes());
+        }
+        let schema = self.inner.schema.as_ref().unwrap();
+        let footer = flatbuf::finish(&[
+            (0, Value::Short(V5)),
+            (1, Value::Table(encode_schema(schema))),
+            (2, Value::Structs(vec![], 0)),
+            (3, Value::Structs(blocks, self.blocks.len())),
+        ]);
+        self.inner.write_all(&footer)?;
+        self.inner.write_all(&(footer.len() as i32).to_le_bytes())?;
+        self.inner.write_all(MAGIC)?;
+        self.inner.writer.flush()?;
+        Ok(self.inner.writer)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if self.inner.position == 0 {
+            self.inner.write_all(MAGIC)?;
+            self.inner.write_all(&[0; 2])?;
+        }
+        Ok(())
+    }
+}
+
+// Reading
+
+/// Read exactly `len` bytes without allocating for corrupted length.
+fn read_bytes<R: Read>(reader: &mut R, len: u64) -> io::Result<Vec<u8>> {
+    let mut buf: Vec<u8> = vec![];
+    reader.by_ref().take(len).read_to_end(&mut buf)?;
+    if (buf.len() as u64) < len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "truncated message",
+        ));
+    }
+    Ok(buf)
+}
+
+/// Read the next message as metadata and body, `None` at the end of stream.
+fn read_message<R: Read>(reader: &mut R) -> io::Result<Option<(Vec<u8>, Vec<u8>)>> {
+    let prefix = read_bytes(reader, 4).or_else(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => Ok(vec![]),
+        _ => Err(e),
+    })?;
+    if prefix.is_empty() {
+        // stream without the end-of-stream marker
+        return Ok(None);
+    }
+    let mut len = u32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+    if len == CONTINUATION {
+        let b = read_bytes(reader, 4)?;
+        len = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+    }
+    if len == 0 {
+        return Ok(None);
+    }
+    if len > i32::MAX as u32 {
+        return Err(invalid_data("invalid message length"));
+    }
+    let metadata = read_bytes(reader, u64::from(len))?;
+    let message = Table::root(&metadata)?;
+    let version = message.i16(0, 0)?;
+    if version != V4 && version != V5 {
+        let msg = format!("unsupported metadata version {}", version);
+        return Err(invalid_data(msg));
+    }
+    let body_len = message.i64(3, 0)?;
+    if body_len < 0 {
+        return Err(invalid_data("invalid body length"));
+    }
+    let body = read_bytes(reader, body_len as u64)?;
+    Ok(Some((metadata, body)))
+}
+
+/// Returns header type and header of message.
+fn message_header(metadata: &[u8]) -> io::Result<(u8, Table<'_>)> {
+    let message = Table::root(metadata)?;
+    let header = message
+        .table(2)?
+        .ok_or_else(|| invalid_data("message has no header"))?;
+    Ok((message.u8(1, 0)?, header))
+}
+
+/// Buffers of a record batch body.
+struct Buffers<'a> {
+    body: &'a [u8],
+    buffers: ::std::vec::IntoIter<&'a [u8]>,
+}
+
+impl<'a> Buffers<'a> {
+    fn next(&mut self) -> io::Result<&'a [u8]> {
+        let buffer = self
+            .buffers
+            .next()
+            .ok_or_else(|| invalid_data("missing buffer"))?;
+        let (offset, len) = (get_i64(&buffer[..8]), get_i64(&buffer[8..]));
+        if offset < 0 || len < 0 {
+            return Err(invalid_data("invalid buffer"));
+        }
+        match (offset as u64).checked_add(len as u64) {
+            Some(end) if end <= self.body.len() as u64 => {
+                Ok(&self.body[offset as usize..end as usize])
+            }
+            _ => Err(invalid_data("buffer exceeds body")),
+        }
+    }
+
+    fn validity(&mut self, len: usize, null_count: usize) -> io::Result<Option<Vec<bool>>> {
+        let bitmap = self.next()?;
+        if null_count == 0 {
+            return Ok(None);
+        }
+        if bitmap.len() < len.div_ceil(8) {
+            return Err(invalid_data("validity buffer is too short"));
+        }
+        Ok(Some(
+            (0..len)
+                .map(|i| bitmap[i / 8] & (1 << (i % 8)) == 0)
+                .collect(),
+        ))
+    }
+
+    fn values<T: Element>(&mut self, len: usize) -> io::Result<Vec<T>> {
+        let data = self.next()?;
+        if data.len() / T::WIDTH < len {
+            return Err(invalid_data("data buffer is too short"));
+        }
+        data[..len * T::WIDTH]
+            .chunks(T::WIDTH)
+            .map(|b| T::get(b).ok_or_else(|| invalid_data("invalid value")))
+            .collect()
+    }
+
+    fn nullvec<T: Element + NullStorable>(
+        &mut self,
+        len: usize,
+        null_count: usize,
+    ) -> io::Result<NullVec<T>> {
+        let mask = self.validity(len, null_count)?;
+        Ok(NullVec::with_mask(self.values(len)?, mask))
+    }
+
+    /// Read offsets and values, rebasing offsets to start from 0.
+    fn variable(&mut self, len: usize, large: bool) -> io::Result<(Vec<u64>, Vec<u8>)> {
+        let offsets: Vec<i64> = if large {
+            self.values(len + 1)?
+        } else {
+            let offsets: Vec<i32> = match self.values(len + 1) {
+                // offsets of an empty array may be omitted
+                Err(_) if len == 0 => vec![0],
+                res => res?,
+            };
+            offsets.into_iter().map(i64::from).collect()
+        };
+        let data = self.next()?;
+        let first = offsets[0];
+        let last = offsets[len];
+        if first < 0 || first > last || last as u64 > data.len() as u64 {
+            return Err(invalid_data("invalid offsets"));
+        }
+        let offsets: Vec<u64> = offsets.iter().map(|&o| (o - first) as u64).collect();
+        let values = data[first as usize..last as usize].to_vec();
+        check_offsets(&offsets, values.len())?;
+        Ok((offsets, values))
+    }
+
+    fn column(&mut self, data_type: DataType, node: &[u8]) -> io::Result<Array> {
+        let (len, null_count) = (get_i64(&node[..8]), get_i64(&node[8..]));
+        if len < 0 || null_count < 0 || null_count > len || len as u64 > self.body.len() as u64 * 8
+        {
+            return Err(invalid_data("invalid field node"));
+        }
+        let (len, null_count) = (len as usize, null_count as usize);
+        let array = match data_type {
+            DataType::Int(64, true) => Array::Int64Array(self.nullvec(len, null_count)?),
+            DataType::Int(32, true) => Array::Int32Array(self.nullvec(len, null_count)?),
+            DataType::Int(16, true) => Array::Int16Array(self.nullvec(len, null_count)?),
+            DataType::Int(_, true) => Array::Int8Array(self.nullvec(len, null_count)?),
+            DataType::Int(64, false) => Array::UInt64Array(self.nullvec(len, null_count)?),
+            DataType::Int(32, false) => Array::UInt32Array(self.nullvec(len, null_count)?),
+            DataType::Int(16, false) => Array::UInt16Array(self.nullvec(len, null_count)?),
+            DataType::Int(_, false) => Array::UInt8Array(self.nullvec(len, null_count)?),
+            DataType::Float(SINGLE) => Array::Float32Array(self.nullvec(len, null_count)?),
+            DataType::Float(_) => Array::Float64Array(self.nullvec(len, null_count)?),
+            DataType::Bool => {
+                let mask = self.validity(len, null_count)?;
+                let bits = self.next()?;
+                if bits.len() < len.div_ceil(8) {
+                    return Err(invalid_data("data buffer is too short"));
+                }
+                let values = (0..len)
+                    .map(|i| bits[i / 8] & (1 << (i % 8)) != 0)
+                    .collect();
+                Array::BoolArray(NullVec::with_mask(values, mask))
+            }
+            DataType::Utf8(large) => {
+                let mask = self.validity(len, null_count)?;
+                let (offsets, values) = self.variable(len, large)?;
+                let valid = str::from_utf8(&values)
+                    .is_ok_and(|s| offsets.iter().all(|&o| s.is_char_boundary(o as usize)));
+                if !valid {
+                    return Err(invalid_data("invalid UTF-8"));
+                }
+                Array::Utf8Array(Utf8Column::from_buffers(values, offsets, mask))
+            }
+            DataType::Binary(large) => {
+                let mask = self.validity(len, null_count)?;
+                let (offsets, values) = self.variable(len, large)?;
+                Array::BinaryArray(BinaryColumn::from_buffers(values, offsets, mask))
+            }
+        };
+        Ok(array)
+    }
+}
+
+/// Decode record batch message into `Array`s.
+fn decode_batch(
+    metadata: &[u8],
+    body: &[u8],
+    schema: &[(String, DataType)],
+) -> io::Result<Vec<(String, Array)>> {
+    let (header_type, header) = message_header(metadata)?;
+    if header_type != RECORD_BATCH {
+        let msg = format!("unexpected message type {}", header_type);
+        return Err(invalid_data(msg));
+    }
+    if header.table(3)?.is_some() {
+        return Err(invalid_data("compressed record batch is not supported"));
+    }
+    let len = header.i64(0, 0)?;
+    let nodes = header.structs(1, STRUCT_LEN)?;
+    if nodes.len() != schema.len() {
+        return Err(invalid_data("field nodes don't match the schema"));
+    }
+    let mut buffers = Buffers {
+        body,
+        buffers: header.structs(2, STRUCT_LEN)?.into_iter(),
+    };
+    let mut columns = Vec::with_capacity(schema.len());
+    for (&(ref name, data_type), node) in schema.iter().zip(nodes) {
+        if get_i64(&node[..8]) != len {
+            return Err(invalid_data(format!("column {} has invalid length", name)));
+        }
+        columns.push((name.clone(), buffers.column(data_type, node)?));
+    }
+    Ok(columns)
+}
+
+/// Concatenate batches, returning empty columns if there are no batches.
+fn concat(
+    schema: &[(String, DataType)],
+    batches: Vec<Vec<(String, Array)>>,
+) -> Vec<(String, Array)> {
+    let mut columns: Vec<(String, Array)> = schema
+        .iter()
+        .map(|&(ref name, data_type)| (name.clone(), data_type.empty()))
+        .collect();
+    for (i, batch) in batches.into_iter().enumerate() {
+        if i == 0 {
+            columns = batch;
+            continue;
+        }
+        for (column, (_, array)) in columns.iter_mut().zip(batch) {
+            column.1 = column.1.append(&array);
+        }
+    }
+    columns
+}
+
+/// Reader which reads the Arrow IPC streaming format, such as written by
+/// `pyarrow.ipc.new_stream`.
+///
+/// Integers, single and double precision floating points and boolean are read
+/// as the corresponding primitive dtypes, utf8 as `utf8` and binary as `binary`
+/// dtype. Validity bitmaps become masks.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let a = Array::new(vec![true, false]);
+/// let mut writer = ArrowStreamWriter::new(vec![]);
+/// writer.write_columns(&[("a", &a)]).unwrap();
+/// writer.write_columns(&[("a", &a)]).unwrap();
+/// let stream = writer.finish().unwrap();
+///
+/// let reader = ArrowStreamReader::new(&stream[..]).unwrap();
+/// assert_eq!(reader.names(), &["a".to_string()]);
+/// let columns = reader.read().unwrap();
+/// assert_eq!(columns[0].1, Array::new(vec![true, false, true, false]));
+/// ```
+pub struct ArrowStreamReader<R: Read> {
+    reader: R,
+    names: Vec<String>,
+    schema: Schema,
+    finished: bool,
+}
+
+impl<R: Read> ArrowStreamReader<R> {
+    /// Create new `ArrowStreamReader`, reading the schema.
+    ///
+    /// Returns an error of `InvalidData` kind if the stream doesn't start with a
+    /// schema or it contains unsupported types.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let (metadata, _) =
+            read_message(&mut reader)?.ok_or_else(|| invalid_data("stream has no schema"))?;
+        let (header_type, header) = message_header(&metadata)?;
+        if header_type != SCHEMA {
+            return Err(invalid_data("stream doesn't start with schema"));
+        }
+        let schema = decode_schema(&header)?;
+        Ok(ArrowStreamReader {
+            reader,
+            names: schema.iter().map(|f| f.0.clone()).collect(),
+            schema,
+            finished: false,
+        })
+    }
+
+    /// Returns column names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Read the next record batch, `None` at the end of stream.
+    pub fn next_batch(&mut self) -> io::Result<Option<Vec<(String, Array)>>> {
+        if self.finished {
+            return Ok(None);
+        }
+        match read_message(&mut self.reader)? {
+            Some((metadata, body)) => Ok(Some(decode_batch(&metadata, &body, &self.schema)?)),
+            None => {
+                self.finished = true;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Read all remaining record batches, concatenated into a column each.
+    pub fn read(mut self) -> io::Result<Vec<(String, Array)>> {
+        let mut batches = vec![];
+        while let Some(batch) = self.next_batch()? {
+            batches.push(batch);
+        }
+        Ok(concat(&self.schema, batches))
+    }
+}
+
+/// Reader which reads the Arrow IPC file format, such as written by
+/// `pyarrow.ipc.new_file` or `pyarrow.feather.write_feather` without
+/// compression.
+///
+/// Record batches are located by the footer, so that any of them can be read
+/// without reading the others. Supported types are the same as
+/// `ArrowStreamReader`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nullvec::prelude::*;
+///
+/// let a = Array::new(vec![1u8, 2]);
+/// let mut writer = ArrowFileWriter::new(Cursor::new(vec![]));
+/// writer.write_columns(&[("a", &a)]).unwrap();
+/// let file = writer.finish().unwrap();
+///
+/// let mut reader = ArrowFileReader::new(file).unwrap();
+/// let df = DataFrame::new(reader.read().unwrap());
+/// assert_eq!(df.column("a"), Some(&a));
+/// ```
+pub struct ArrowFileReader<R: Read + Seek> {
+    reader: R,
+    names: Vec<String>,
+    schema: Schema,
+    // offset of each record batch
+    blocks: Vec<u64>,
+}
+
+impl<R: Read + Seek> ArrowFileReader<R> {
+    /// Create new `ArrowFileReader`, reading the footer.
+    ///
+    /// Returns an error of `InvalidData` kind if input is not an Arrow file or
+    /// it contains unsupported types.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let size = reader.seek(SeekFrom::End(0))?;
+        // magic, padding, end-of-stream marker, footer length and magic
+        if size < 24 {
+            return Err(invalid_data("not an Arrow file"));
+        }
+        reader.seek(SeekFrom::Start(0))?;
+        let head = read_bytes(&mut reader, 6)?;
+        reader.seek(SeekFrom::End(-10))?;
+        let tail = read_bytes(&mut reader, 10)?;
+        if &head[..] != MAGIC || &tail[4..] != MAGIC {
+            return Err(invalid_data("not an Arrow file"));
+        }
+        let footer_len = i32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]);
+        if footer_len < 0 || footer_len as u64 > size - 18 {
+            return Err(invalid_data("invalid footer length"));
+        }
+        reader.seek(SeekFrom::End(-10 - i64::from(footer_len)))?;
+        let buf = read_bytes(&mut reader, footer_len as u64)?;
+        let footer = Table::root(&buf)?;
+        let schema = footer
+            .table(1)?
+            .ok_or_else(|| invalid_data("footer has no schema"))?;
+        let schema = decode_schema(&schema)?;
+        let mut blocks = vec![];
+        for block in footer.structs(3, BLOCK_LEN)? {
+            let offset = get_i64(&block[..8]);
+            if offset < 0 || offset as u64 >= size {
+                return Err(invalid_data("invalid block offset"));
+            }
+            blocks.push(offset as u64);
+        }
+        Ok(ArrowFileReader {
+            reader,
+            names: schema.iter().map(|f| f.0.clone()).collect(),
+            schema,
+            blocks,
+        })
+    }
+
+    /// Returns column names.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the number of record batches.
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Read the `i`-th record batch.
+    ///
+    /// Returns an error of `InvalidInput` kind if `i` is out of range.
+    pub fn read_batch(&mut self, i: usize) -> io::Result<Vec<(String, Array)>> {
+        let offset = match self.blocks.get(i) {
+            Some(&offset) => offset,
+            None => {
+                let msg = format!("batch {} is out of range", i);
+                return Err(invalid_input(msg));
+            }
+        };
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let (metadata, body) = read_message(&mut self.reader)?
+            .ok_or_else(|| invalid_data("record batch not found"))?;
+        decode_batch(&metadata, &body, &self.schema)
+    }
+
+    /// Read all record batches, concatenated into a column each.
+    pub fn read(&mut self) -> io::Result<Vec<(String, Array)>> {
+        let batches = (0..self.blocks.len())
+            .map(|i| self.read_batch(i))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(concat(&self.schema, batches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+
+    use super::{ArrowFileReader, ArrowFileWriter, ArrowStreamReader, ArrowStreamWriter};
+    use columnar::{BinaryColumn, Utf8Column};
+    use generic::Array;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{Append, NullStorable, Slicer};
+
+    // the second element is Null
+    fn masked<T: NullStorable>(values: Vec<T>) -> NullVec<T> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i == 1 {
+                    Nullable::Null
+                } else {
+                    Nullable::new(v)
+                }
+            })
+            .collect()
+    }
+
+    fn columns() -> Vec<(String, Array)> {
+        vec![
+            ("i64".to_string(), Array::Int64Array(masked(vec![1, 2, -3]))),
+            ("i32".to_string(), Array::new(vec![1i32, 2, 3])),
+            ("i16".to_string(), Array::Int16Array(masked(vec![1, 2, 3]))),
+            ("i8".to_string(), Array::new(vec![-1i8, 2, 3])),
+            (
+                "u64".to_string(),
+                Array::UInt64Array(masked(vec![1, 2, u64::MAX])),
+            ),
+            ("u32".to_string(), Array::new(vec![1u32, 2, 3])),
+            ("u16".to_string(), Array::new(vec![1u16, 2, 3])),
+            ("u8".to_string(), Array::UInt8Array(masked(vec![1, 2, 255]))),
+            (
+                "f64".to_string(),
+                Array::Float64Array(masked(vec![1.5, 2., 3.])),
+            ),
+            ("f32".to_string(), Array::new(vec![1.5f32, -0., 3.])),
+            (
+                "bool".to_string(),
+                Array::BoolArray(masked(vec![true, true, false])),
+            ),
+            (
+                "utf8".to_string(),
+                Array::Utf8Array(Utf8Column::with_mask(
+                    vec!["a".to_string(), "".to_string(), "αβ".to_string()],
+                    Some(vec![false, true, false]),
+                )),
+            ),
+            (
+                "binary".to_string(),
+                Array::BinaryArray(BinaryColumn::new(vec![vec![0], vec![], vec![1, 255]])),
+            ),
+        ]
+    }
+
+    fn refs(columns: &[(String, Array)]) -> Vec<(&str, &Array)> {
+        columns.iter().map(|c| (c.0.as_str(), &c.1)).collect()
+    }
+
+    #[test]
+    fn test_stream_roundtrip() {
+        let columns = columns();
+        let mut writer = ArrowStreamWriter::new(vec![]);
+        writer.write_columns(&refs(&columns)).unwrap();
+        let stream = writer.finish().unwrap();
+        let reader = ArrowStreamReader::new(&stream[..]).unwrap();
+        assert_eq!(reader.names().len(), columns.len());
+        assert_eq!(reader.read().unwrap(), columns);
+    }
+
+    #[test]
+    fn test_file_roundtrip() {
+        let columns = columns();
+        let mut writer = ArrowFileWriter::new(Cursor::new(vec![]));
+        writer.write_columns(&refs(&columns)).unwrap();
+        writer.write_columns(&refs(&columns)).unwrap();
+        let file = writer.finish().unwrap();
+        let mut reader = ArrowFileReader::new(file).unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        assert_eq!(reader.read_batch(1).unwrap(), columns);
+        let res = reader.read().unwrap();
+        assert_eq!(res[0].1, columns[0].1.append(&columns[0].1));
+        assert_eq!(res[11].1.len(), 6);
+        assert!(reader.read_batch(2).is_err());
+    }
+
+    #[test]
+    fn test_converted_dtypes() {
+        let s = Array::StringArray(masked(vec!["x".to_string(), "y".to_string()]));
+        let i = Array::new(vec![1isize, 2]);
+        let mut writer = ArrowStreamWriter::new(vec![]);
+        writer.write_columns(&[("s", &s), ("i", &i)]).unwrap();
+        let stream = writer.finish().unwrap();
+        let res = ArrowStreamReader::new(&stream[..]).unwrap().read().unwrap();
+        let exp = Utf8Column::with_mask(
+            vec!["x".to_string(), "".to_string()],
+            Some(vec![false, true]),
+        );
+        assert_eq!(res[0].1, Array::Utf8Array(exp));
+        assert_eq!(res[1].1, Array::new(vec![1i64, 2]));
+    }
+
+    #[test]
+    fn test_empty() {
+        let a = Array::new(Vec::<f32>::new());
+        let mut writer = ArrowStreamWriter::new(vec![]);
+        writer.write_columns(&[("a", &a)]).unwrap();
+        let stream = writer.finish().unwrap();
+        let res = ArrowStreamReader::new(&stream[..]).unwrap().read().unwrap();
+        assert_eq!(res, vec![("a".to_string(), a)]);
+
+        // no batches
+        let file = ArrowFileWriter::new(Cursor::new(vec![])).finish().unwrap();
+        let mut reader = ArrowFileReader::new(file).unwrap();
+        assert_eq!(reader.num_batches(), 0);
+        assert!(reader.read().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_errors() {
+        let a = Array::new(vec![1i64, 2]);
+        let b = Array::new(vec![1i64]);
+        let c = Array::new(vec!['a', 'b']);
+        let mut writer = ArrowStreamWriter::new(vec![]);
+        let err = writer.write_columns(&[("a", &a), ("c", &c)]).unwrap_err();
+        assert_eq!(err.to_string(), "dtype char of column c is not supported");
+        let err = writer.write_columns(&[("a", &a), ("b", &b)]).unwrap_err();
+        assert_eq!(err.to_string(), "column b has 1 rows, expected 2");
+        writer.write_columns(&[("a", &a)]).unwrap();
+        let err = writer.write_columns(&[("b", &b)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "columns differ from the schema of the first batch"
+        );
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let a = Array::new(vec!["x", "y"]);
+        let mut writer = ArrowFileWriter::new(Cursor::new(vec![]));
+        writer.write_columns(&[("a", &a)]).unwrap();
+        let file = writer.finish().unwrap().into_inner();
+        // truncated or flipped bytes are errors, not panics
+        for i in 0..file.len() {
+            let mut data = file.clone();
+            data[i] ^= 0xFF;
+            let _ = ArrowFileReader::new(Cursor::new(data)).and_then(|mut r| r.read());
+        }
+        for i in 8..file.len() {
+            let _ = ArrowStreamReader::new(&file[8..i]).and_then(|r| r.read());
+        }
+        let err = ArrowStreamReader::new(&file[..]).err().unwrap();
+        assert_eq!(err.to_string(), "truncated message");
+        let err = ArrowFileReader::new(Cursor::new(&file[8..])).err().unwrap();
+        assert_eq!(err.to_string(), "not an Arrow file");
+    }
+}
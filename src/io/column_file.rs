@@ -0,0 +1,385 @@
+//! Multi-column container of the native binary format.
+//!
+//! ```text
+//! magic "NVCF" | version: u8 | arrays | footer | footer length: u64
+//!     | CRC-32 of footer: u32 | magic "NVCF"
+//! ```
+//!
+//! Each array is written by `Array::write_to`. The footer is the index of columns:
+//! the number of columns as `u64`, then for each column, the byte length of its
+//! name as `u64`, the name, the offset and the byte length of the array as `u64`.
+//! Readers locate the footer from the end of file, so that any column can be read
+//! without scanning the others.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::str;
+
+use frame::DataFrame;
+use generic::Array;
+use traits::Slicer;
+
+use super::native::{crc32, invalid_data, FORMAT_VERSION};
+
+const MAGIC: &[u8; 4] = b"NVCF";
+
+// footer length, checksum and magic
+const TRAILER_LEN: usize = 16;
+
+/// Writer which writes named `Array`s into a single file with a footer index.
+///
+/// All columns must have the same length. The file is complete only after
+/// `finish` is called.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nullvec::prelude::*;
+///
+/// let mut writer = ColumnFileWriter::new(Cursor::new(vec![]));
+/// writer.write_column("a", &Array::new(vec![1i64, 2])).unwrap();
+/// writer.write_column("b", &Array::new(vec!["x", "y"])).unwrap();
+/// let file = writer.finish().unwrap();
+///
+/// let mut reader = ColumnFileReader::new(file).unwrap();
+/// assert_eq!(reader.names(), &["a".to_string(), "b".to_string()]);
+/// assert_eq!(reader.read_column("b").unwrap(), Array::new(vec!["x", "y"]));
+/// ```
+pub struct ColumnFileWriter<W: Write> {
+    writer: W,
+    // name, offset and byte length of each column
+    index: Vec<(String, u64, u64)>,
+    len: usize,
+    position: u64,
+}
+
+impl<W: Write> ColumnFileWriter<W> {
+    /// Create new `ColumnFileWriter`.
+    pub fn new(writer: W) -> Self {
+        ColumnFileWriter {
+            writer,
+            index: vec![],
+            len: 0,
+            position: 0,
+        }
+    }
+
+    /// Write `Array` as a column named `name`.
+    ///
+    /// Returns an error of `InvalidInput` kind if the name is already written or
+    /// the length differs from the previous columns.
+    pub fn write_column(&mut self, name: &str, array: &Array) -> io::Result<()> {
+        if self.index.iter().any(|c| c.0 == name) {
+            let msg = format!("column {} is already written", name);
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        if !self.index.is_empty() && array.len() != self.len {
+            let msg = format!(
+                "column {} has {} rows, expected {}",
+                name,
+                array.len(),
+                self.len
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        self.write_header()?;
+        let mut buf: Vec<u8> = vec![];
+        array.write_to(&mut buf)?;
+        self.writer.write_all(&buf)?;
+        self.index
+            .push((name.to_string(), self.position, buf.len() as u64));
+        self.position += buf.len() as u64;
+        self.len = array.len();
+        Ok(())
+    }
+
+    /// Write all columns of `DataFrame`.
+    pub fn write(&mut self, df: &DataFrame) -> io::Result<()> {
+        for (name, column) in df.names().iter().zip(df.columns().iter()) {
+            self.write_column(name, column)?;
+        }
+        Ok(())
+    }
+
+    /// Write the footer index and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_header()?;
+        let mut footer: Vec<u8> = vec![];
+        footer.extend_from_slice(&(self.index.len() as u64).to_le_bytes());
+        for &(ref name, offset, size) in self.index.iter() {
+            footer.extend_from_slice(&(name.len() as u64).to_le_bytes());
+            footer.extend_from_slice(name.as_bytes());
+            footer.extend_from_slice(&offset.to_le_bytes());
+            footer.extend_from_slice(&size.to_le_bytes());
+        }
+        self.writer.write_all(&footer)?;
+        self.writer
+            .write_all(&(footer.len() as u64).to_le_bytes())?;
+        self.writer.write_all(&crc32(&footer).to_le_bytes())?;
+        self.writer.write_all(MAGIC)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if self.position == 0 {
+            self.writer.write_all(MAGIC)?;
+            self.writer.write_all(&[FORMAT_VERSION])?;
+            self.position = MAGIC.len() as u64 + 1;
+        }
+        Ok(())
+    }
+}
+
+/// Reader which reads columns from a file written by `ColumnFileWriter`.
+///
+/// The footer index is read on creation, and each column is read on demand.
+pub struct ColumnFileReader<R: Read + Seek> {
+    reader: R,
+    names: Vec<String>,
+    // offset and byte length of each column
+    locations: Vec<(u64, u64)>,
+}
+
+impl<R: Read + Seek> ColumnFileReader<R> {
+    /// Create new `ColumnFileReader`, reading the footer index.
+    ///
+    /// Returns an error of `InvalidData` kind if the file is not complete, corrupted
+    /// or written in unsupported version.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let (names, locations) = read_index(&mut reader)?;
+        Ok(ColumnFileReader {
+            reader,
+            names,
+            locations,
+        })
+    }
+
+    /// Returns column names in the written order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Read a column specified with the name.
+    ///
+    /// Returns an error of `InvalidInput` kind if the column doesn't exist.
+    pub fn read_column(&mut self, name: &str) -> io::Result<Array> {
+        let (offset, size) = match self.names.iter().position(|n| n == name) {
+            Some(i) => self.locations[i],
+            None => {
+                let msg = format!("column {} not found", name);
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+            }
+        };
+        self.reader.seek(SeekFrom::Start(offset))?;
+        let mut column = (&mut self.reader).take(size);
+        Array::read_from(&mut column)
+    }
+
+    /// Read the specified columns into `DataFrame`.
+    pub fn select(&mut self, names: &[&str]) -> io::Result<DataFrame> {
+        let mut columns: Vec<(String, Array)> = Vec::with_capacity(names.len());
+        for name in names.iter() {
+            let column = self.read_column(name)?;
+            if columns.first().is_some_and(|c| c.1.len() != column.len()) {
+                return Err(invalid_data("columns have different lengths"));
+            }
+            columns.push((name.to_string(), column));
+        }
+        Ok(DataFrame::new(columns))
+    }
+
+    /// Read all columns into `DataFrame`.
+    pub fn read(&mut self) -> io::Result<DataFrame> {
+        let names = self.names.clone();
+        let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        self.select(&names)
+    }
+}
+
+// column names, and offset and byte length of each column
+type Index = (Vec<String>, Vec<(u64, u64)>);
+
+/// Read the footer index.
+pub fn read_index<R: Read + Seek>(reader: &mut R) -> io::Result<Index> {
+    let mut header = [0u8; 5];
+    reader.seek(SeekFrom::Start(0))?;
+    reader.read_exact(&mut header)?;
+    if &header[..4] != MAGIC {
+        return Err(invalid_data("not a column file"));
+    }
+    if header[4] != FORMAT_VERSION {
+        return Err(invalid_data(format!("unsupported version {}", header[4])));
+    }
+
+    let end = reader.seek(SeekFrom::End(0))?;
+    if end < (header.len() + TRAILER_LEN) as u64 {
+        return Err(invalid_data("column file is truncated"));
+    }
+    let mut trailer = [0u8; TRAILER_LEN];
+    reader.seek(SeekFrom::Start(end - TRAILER_LEN as u64))?;
+    reader.read_exact(&mut trailer)?;
+    if &trailer[12..] != MAGIC {
+        return Err(invalid_data("column file is not finished"));
+    }
+    let mut buf = &trailer[..];
+    let footer_len = take_u64(&mut buf)?;
+    let checksum = take_bytes(&mut buf, 4)?;
+    let footer_start = end - TRAILER_LEN as u64;
+    if footer_len > footer_start - header.len() as u64 {
+        return Err(invalid_data("invalid footer length"));
+    }
+
+    let mut footer = vec![0u8; footer_len as usize];
+    reader.seek(SeekFrom::Start(footer_start - footer_len))?;
+    reader.read_exact(&mut footer)?;
+    if checksum != crc32(&footer).to_le_bytes() {
+        return Err(invalid_data("checksum mismatch"));
+    }
+
+    let mut buf = &footer[..];
+    let n = take_u64(&mut buf)?;
+    let mut names: Vec<String> = vec![];
+    let mut locations: Vec<(u64, u64)> = vec![];
+    for _ in 0..n {
+        let len = take_u64(&mut buf)?;
+        let name = take_bytes(&mut buf, len)?;
+        let name = str::from_utf8(name).map_err(|_| invalid_data("invalid UTF-8"))?;
+        let offset = take_u64(&mut buf)?;
+        let size = take_u64(&mut buf)?;
+        if offset
+            .checked_add(size)
+            .is_none_or(|e| e > footer_start - footer_len)
+        {
+            return Err(invalid_data("invalid column location"));
+        }
+        names.push(name.to_string());
+        locations.push((offset, size));
+    }
+    Ok((names, locations))
+}
+
+fn take_bytes<'a>(buf: &mut &'a [u8], len: u64) -> io::Result<&'a [u8]> {
+    if (buf.len() as u64) < len {
+        return Err(invalid_data("unexpected end of footer"));
+    }
+    let (bytes, rest) = buf.split_at(len as usize);
+    *buf = rest;
+    Ok(bytes)
+}
+
+fn take_u64(buf: &mut &[u8]) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(take_bytes(buf, 8)?);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+
+    use super::{ColumnFileReader, ColumnFileWriter};
+    use columnar::ListColumn;
+    use frame::DataFrame;
+    use generic::Array;
+    use nullvec::NullVec;
+    use traits::Slicer;
+
+    fn sample() -> DataFrame {
+        DataFrame::new(vec![
+            (
+                "a".to_string(),
+                Array::Int64Array(NullVec::with_mask(
+                    vec![1, 0, 3],
+                    Some(vec![false, true, false]),
+                )),
+            ),
+            ("b".to_string(), Array::new(vec!["x", "y", "z"])),
+            (
+                "c".to_string(),
+                Array::ListArray(ListColumn::from_rows(vec![
+                    Some(Array::new(vec![1.5])),
+                    None,
+                    Some(Array::new(vec![2.5, 3.5])),
+                ])),
+            ),
+        ])
+    }
+
+    fn written() -> Vec<u8> {
+        let mut writer = ColumnFileWriter::new(vec![]);
+        writer.write(&sample()).unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut reader = ColumnFileReader::new(Cursor::new(written())).unwrap();
+        assert_eq!(reader.names(), &["a", "b", "c"]);
+        assert_eq!(reader.read().unwrap(), sample());
+        // random access
+        assert_eq!(
+            &reader.read_column("c").unwrap(),
+            sample().column("c").unwrap()
+        );
+        assert_eq!(
+            reader.select(&["b", "a"]).unwrap(),
+            sample().select(&["b", "a"])
+        );
+        assert!(reader.read_column("d").is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        let file = ColumnFileWriter::new(vec![]).finish().unwrap();
+        let mut reader = ColumnFileReader::new(Cursor::new(file)).unwrap();
+        assert!(reader.names().is_empty());
+        assert_eq!(reader.read().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_write_errors() {
+        let mut writer = ColumnFileWriter::new(vec![]);
+        writer
+            .write_column("a", &Array::new(vec![1i64, 2]))
+            .unwrap();
+        let err = writer
+            .write_column("a", &Array::new(vec![1i64, 2]))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "column a is already written");
+        let err = writer
+            .write_column("b", &Array::new(vec![1i64]))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "column b has 1 rows, expected 2");
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let file = written();
+
+        let err = ColumnFileReader::new(Cursor::new(&file[..file.len() - 1]))
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "column file is not finished");
+
+        // corrupt the footer
+        let mut corrupted = file.clone();
+        let pos = corrupted.len() - 20;
+        corrupted[pos] ^= 1;
+        let err = ColumnFileReader::new(Cursor::new(corrupted)).err().unwrap();
+        assert_eq!(err.to_string(), "checksum mismatch");
+
+        // corrupt the first column
+        let mut corrupted = file.clone();
+        corrupted[30] ^= 1;
+        let mut reader = ColumnFileReader::new(Cursor::new(corrupted)).unwrap();
+        assert!(reader.read_column("a").is_err());
+        assert!(reader.read_column("b").is_ok());
+
+        let err = ColumnFileReader::new(Cursor::new(&b"NVAR"[..]))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ::std::io::ErrorKind::UnexpectedEof);
+    }
+}
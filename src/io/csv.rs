@@ -0,0 +1,656 @@
+use std::io::{self, BufRead, BufReader, Read};
+use std::mem;
+
+use columnar::Categorical;
+use decimal::Decimal;
+use frame::DataFrame;
+use generic::{Array, Scalar};
+use nullable::Nullable;
+use nullvec::NullVec;
+use temporal::{Date32, TimeUnit, Timestamp};
+use traits::NullStorable;
+
+/// Reader which parses CSV (RFC 4180) into `DataFrame`.
+///
+/// Each column's dtype is inferred from its values in the order of `i64`, `f64`,
+/// `bool`, `date32` (`%Y-%m-%d`), `timestamp` (ISO 8601 in nanoseconds) and `str`,
+/// unless an explicit schema is supplied.
+///
+/// Empty lines are skipped, except that they are read as `Null` if there is only
+/// one column named by the header row or schema.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let data = "a,b\n1,x\nNA,\"y,z\"\n";
+/// let df = CsvReader::new(data.as_bytes()).read().unwrap();
+/// assert_eq!(df.names(), &["a".to_string(), "b".to_string()]);
+/// assert_eq!(df.column("a").unwrap().dtype(), "i64");
+/// assert_eq!(df.column("b"), Some(&Array::new(vec!["x", "y,z"])));
+/// ```
+pub struct CsvReader<R: Read> {
+    reader: BufReader<R>,
+    delimiter: char,
+    has_header: bool,
+    null_values: Vec<String>,
+    schema: Option<Vec<(String, String)>>,
+    chunk_size: usize,
+    // number of physical lines and data records consumed, used in error messages
+    lines: usize,
+    records: usize,
+}
+
+impl<R: Read> CsvReader<R> {
+    /// Create new `CsvReader` with default options.
+    ///
+    /// Defaults are `,` delimiter, with header row, `""`, `"NA"` and `"NULL"` as
+    /// null tokens, and 65,536 records per chunk.
+    pub fn new(reader: R) -> Self {
+        CsvReader {
+            reader: BufReader::new(reader),
+            delimiter: ',',
+            has_header: true,
+            null_values: DEFAULT_NULL_VALUES.iter().map(|s| s.to_string()).collect(),
+            schema: None,
+            chunk_size: 65_536,
+            lines: 0,
+            records: 0,
+        }
+    }
+
+    /// Set the field delimiter.
+    ///
+    /// # Panics
+    ///
+    /// - if `delimiter` is a quote or a line break
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        assert!(
+            !['"', '\n', '\r'].contains(&delimiter),
+            "delimiter must not be a quote or a line break"
+        );
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the first record is a header row.
+    ///
+    /// If `false`, columns are named `column_0`, `column_1`, ... unless schema is
+    /// supplied.
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set tokens which are regarded as `Null`. Only unquoted fields are compared, so
+    /// that quoted `""` is read as an empty string.
+    pub fn null_values(mut self, null_values: Vec<String>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Set explicit schema as `(name, dtype)` pairs, skipping dtype inference.
+    ///
+    /// Names in the schema take precedence over the header row. Value which can't
+    /// be parsed as specified dtype results in an error. Supported dtypes are
+    /// integers, floats, `bool`, `str`, `utf8`, `category`, `date32` (`%Y-%m-%d`),
+    /// `timestamp` (ISO 8601 in nanoseconds) and `decimal`.
+    pub fn schema(mut self, schema: Vec<(String, String)>) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Set the number of records per `DataFrame` in chunked mode.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Read all records into a single `DataFrame`.
+    pub fn read(mut self) -> io::Result<DataFrame> {
+        self.chunk_size = usize::MAX;
+        let mut chunks = self.chunks()?;
+        match chunks.next() {
+            Some(df) => df,
+            None => Ok(chunks.empty_frame()),
+        }
+    }
+
+    /// Returns an iterator which reads records into `DataFrame`s of `chunk_size`
+    /// rows, so that input larger than memory can be processed.
+    ///
+    /// Without schema, dtypes are inferred from the first chunk and applied to all
+    /// the following chunks.
+    ///
+    /// Returns an error of `InvalidInput` kind if the schema contains unsupported
+    /// dtype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    ///
+    /// let data = "a\n1\n2\n3\n";
+    /// let chunks = CsvReader::new(data.as_bytes()).chunk_size(2).chunks().unwrap();
+    /// let lens: Vec<usize> = chunks.map(|df| df.unwrap().len()).collect();
+    /// assert_eq!(lens, vec![2, 1]);
+    /// ```
+    pub fn chunks(mut self) -> io::Result<CsvChunks<R>> {
+        if let Some(ref schema) = self.schema {
+            if let Some((name, dtype)) = schema.iter().find(|f| !DTYPES.contains(&f.1.as_str())) {
+                let msg = format!("unsupported dtype {} in column {}", dtype, name);
+                return Err(invalid_input(msg));
+            }
+        }
+        let header = if self.has_header {
+            self.read_record(true)?
+                .map(|record| record.into_iter().map(|f| f.0).collect())
+        } else {
+            None
+        };
+        let (names, dtypes) = match self.schema.take() {
+            Some(schema) => {
+                let (names, dtypes) = schema.into_iter().unzip();
+                (Some(names), Some(dtypes))
+            }
+            None => (header, None),
+        };
+        Ok(CsvChunks {
+            reader: self,
+            names,
+            dtypes,
+        })
+    }
+
+    /// Read a single record as pairs of the field and whether it is quoted. Returns
+    /// `None` at the end of input.
+    ///
+    /// If `skip_empty` is `false`, an empty line is read as a record with an empty
+    /// field, which is `Null` of the single column written by `CsvWriter`.
+    fn read_record(&mut self, skip_empty: bool) -> io::Result<Option<Vec<(String, bool)>>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            self.lines += 1;
+            if !skip_empty || !line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        let mut fields: Vec<(String, bool)> = vec![];
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut quoted = false;
+        loop {
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                if in_quotes {
+                    if c != '"' {
+                        field.push(c);
+                    } else if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                } else if c == '"' && field.is_empty() && !quoted {
+                    in_quotes = true;
+                    quoted = true;
+                } else if c == self.delimiter {
+                    fields.push((mem::take(&mut field), quoted));
+                    quoted = false;
+                } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n')) {
+                    // end of record
+                } else {
+                    field.push(c);
+                }
+            }
+            if !in_quotes {
+                break;
+            }
+            // quoted field continues to the next line
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                let msg = format!("unterminated quoted field at line {}", self.lines);
+                return Err(invalid_data(msg));
+            }
+            self.lines += 1;
+        }
+        fields.push((field, quoted));
+        Ok(Some(fields))
+    }
+}
+
+/// Iterator which reads CSV into `DataFrame`s of the fixed number of rows, created by
+/// `CsvReader::chunks`.
+pub struct CsvChunks<R: Read> {
+    reader: CsvReader<R>,
+    names: Option<Vec<String>>,
+    dtypes: Option<Vec<String>>,
+}
+
+impl<R: Read> CsvChunks<R> {
+    fn read_chunk(&mut self) -> io::Result<Option<DataFrame>> {
+        let mut columns: Vec<Vec<Option<String>>> = vec![];
+        let mut nrows = 0;
+        let start = self.reader.records;
+        // empty lines are skipped unless they are a row of the single column
+        let skip_empty = self.names.as_ref().is_none_or(|n| n.len() != 1);
+        while nrows < self.reader.chunk_size {
+            let record = match self.reader.read_record(skip_empty)? {
+                Some(record) => record,
+                None => break,
+            };
+            if self.names.is_none() {
+                let names = (0..record.len()).map(|i| format!("column_{}", i)).collect();
+                self.names = Some(names);
+            }
+            let ncols = self.names.as_ref().map_or(0, |n| n.len());
+            if record.len() != ncols {
+                let msg = format!(
+                    "line {} has {} fields, expected {}",
+                    self.reader.lines,
+                    record.len(),
+                    ncols
+                );
+                return Err(invalid_data(msg));
+            }
+            if columns.is_empty() {
+                columns = vec![vec![]; ncols];
+            }
+            for (column, (value, quoted)) in columns.iter_mut().zip(record) {
+                if !quoted && self.reader.null_values.contains(&value) {
+                    column.push(None);
+                } else {
+                    column.push(Some(value));
+                }
+            }
+            nrows += 1;
+            self.reader.records += 1;
+        }
+        if nrows == 0 {
+            return Ok(None);
+        }
+
+        if self.dtypes.is_none() {
+            let dtypes = columns.iter().map(|c| infer_dtype(c).to_string()).collect();
+            self.dtypes = Some(dtypes);
+        }
+        let names = self.names.as_ref().unwrap();
+        let dtypes = self.dtypes.as_ref().unwrap();
+        let mut fields: Vec<(String, Array)> = Vec::with_capacity(names.len());
+        for ((name, dtype), column) in names.iter().zip(dtypes.iter()).zip(columns.iter()) {
+            match parse_column(column, dtype) {
+                Ok(values) => fields.push((name.clone(), values)),
+                Err(i) => {
+                    let msg = format!(
+                        "unable to parse \"{}\" as {} in column {} at record {}",
+                        column[i].as_ref().unwrap(),
+                        dtype,
+                        name,
+                        start + i + 1
+                    );
+                    return Err(invalid_data(msg));
+                }
+            }
+        }
+        Ok(Some(DataFrame::new(fields)))
+    }
+
+    /// Returns `DataFrame` without rows, used when input has no record.
+    fn empty_frame(&self) -> DataFrame {
+        let names = match self.names {
+            Some(ref names) => names.clone(),
+            None => return DataFrame::new(vec![]),
+        };
+        let fields = match self.dtypes {
+            Some(ref dtypes) => names
+                .into_iter()
+                .zip(dtypes.iter())
+                .map(|(name, dtype)| (name, parse_column(&[], dtype).unwrap()))
+                .collect(),
+            None => names
+                .into_iter()
+                .map(|name| (name, parse_column(&[], "str").unwrap()))
+                .collect(),
+        };
+        DataFrame::new(fields)
+    }
+}
+
+impl<R: Read> Iterator for CsvChunks<R> {
+    type Item = io::Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_chunk() {
+            Ok(Some(df)) => Some(Ok(df)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+/// Tokens regarded as `Null` by default, which `CsvWriter` quotes so that they
+/// are read back as strings
+pub(crate) const DEFAULT_NULL_VALUES: [&str; 3] = ["", "NA", "NULL"];
+
+/// Dtypes which `parse_column` supports
+const DTYPES: [&str; 21] = [
+    "i64",
+    "i32",
+    "i16",
+    "i8",
+    "isize",
+    "u64",
+    "u32",
+    "u16",
+    "u8",
+    "usize",
+    "i128",
+    "u128",
+    "f64",
+    "f32",
+    "bool",
+    "str",
+    "utf8",
+    "category",
+    "date32",
+    "timestamp",
+    "decimal",
+];
+
+fn parse_date(value: &str) -> Option<Date32> {
+    Date32::parse(value, Some("%Y-%m-%d"))
+}
+
+fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    Timestamp::parse(value, None, TimeUnit::Nanosecond)
+}
+
+type Checker = fn(&str) -> bool;
+
+/// Infer dtype which can represent all non-null values.
+///
+/// `i64`, `f64` and `bool` follow the same rules as `From<&str> for Scalar`.
+fn infer_dtype(values: &[Option<String>]) -> &'static str {
+    let candidates: [(&'static str, Checker); 5] = [
+        ("i64", |s| Scalar::from(s).is_i64()),
+        ("f64", |s| {
+            let scalar = Scalar::from(s);
+            scalar.is_i64() || scalar.is_f64()
+        }),
+        ("bool", |s| Scalar::from(s).is_bool()),
+        ("date32", |s| parse_date(s).is_some()),
+        ("timestamp", |s| parse_timestamp(s).is_some()),
+    ];
+    if values.iter().all(Option::is_none) {
+        return "str";
+    }
+    for &(dtype, check) in candidates.iter() {
+        if values.iter().flatten().all(|s| check(s)) {
+            return dtype;
+        }
+    }
+    "str"
+}
+
+/// Parse values with `f`, returning the location of the value which can't be parsed.
+fn parse_values<T, F>(values: &[Option<String>], f: F) -> Result<NullVec<T>, usize>
+where
+    T: NullStorable,
+    F: Fn(&str) -> Option<T>,
+{
+    let parsed: Vec<Nullable<T>> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| match *v {
+            Some(ref s) => f(s).map(Nullable::Value).ok_or(i),
+            None => Ok(Nullable::Null),
+        })
+        .collect::<Result<_, usize>>()?;
+    Ok(parsed.into())
+}
+
+/// Parse values as specified dtype, which must be one of `DTYPES`.
+fn parse_column(values: &[Option<String>], dtype: &str) -> Result<Array, usize> {
+    macro_rules! parse_std {
+        ($t:ty, $klass:ident) => {
+            Array::$klass(parse_values(values, |s| s.parse::<$t>().ok())?)
+        };
+    }
+    let strings = || -> NullVec<String> {
+        values
+            .iter()
+            .map(|v| match *v {
+                Some(ref s) => Nullable::Value(s.clone()),
+                None => Nullable::Null,
+            })
+            .collect()
+    };
+    let array = match dtype {
+        "i64" => parse_std!(i64, Int64Array),
+        "i32" => parse_std!(i32, Int32Array),
+        "i16" => parse_std!(i16, Int16Array),
+        "i8" => parse_std!(i8, Int8Array),
+        "isize" => parse_std!(isize, IsizeArray),
+        "u64" => parse_std!(u64, UInt64Array),
+        "u32" => parse_std!(u32, UInt32Array),
+        "u16" => parse_std!(u16, UInt16Array),
+        "u8" => parse_std!(u8, UInt8Array),
+        "usize" => parse_std!(usize, UsizeArray),
+        "i128" => parse_std!(i128, Int128Array),
+        "u128" => parse_std!(u128, UInt128Array),
+        "f64" => parse_std!(f64, Float64Array),
+        "f32" => parse_std!(f32, Float32Array),
+        "bool" => parse_std!(bool, BoolArray),
+        "str" => Array::StringArray(strings()),
+        "utf8" => Array::Utf8Array(strings().into()),
+        "category" => Array::CategoricalArray(Categorical::new(&strings(), false)),
+        "date32" => Array::Date32Array(parse_values(values, parse_date)?),
+        "timestamp" => Array::TimestampArray(parse_values(values, parse_timestamp)?),
+        "decimal" => Array::DecimalArray(parse_values(values, Decimal::parse)?),
+        _ => unreachable!("unsupported dtype {}", dtype),
+    };
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io;
+
+    use super::CsvReader;
+    use generic::{Array, Scalar};
+    use nullvec::NullVec;
+    use temporal::Date32;
+    use traits::Slicer;
+
+    #[test]
+    fn test_infer_dtypes() {
+        let data = "i,f,b,d,t,s\n\
+                    1,1.5,true,2017-01-01,2017-01-01T00:00:00,x\n\
+                    -2,2,false,2017-01-02,2017-01-01 12:00:00Z,1\n";
+        let df = CsvReader::new(data.as_bytes()).read().unwrap();
+        assert_eq!(
+            df.dtypes(),
+            vec!["i64", "f64", "bool", "date32", "timestamp", "str"]
+        );
+        assert_eq!(df.column("i"), Some(&Array::new(vec![1i64, -2])));
+        assert_eq!(df.column("f"), Some(&Array::new(vec![1.5, 2.0])));
+        assert_eq!(df.column("b"), Some(&Array::new(vec![true, false])));
+        assert_eq!(
+            df.column("d").unwrap().iloc(&1),
+            Scalar::Date32(Date32::from_ymd(2017, 1, 2))
+        );
+        assert_eq!(df.column("s"), Some(&Array::new(vec!["x", "1"])));
+
+        // same rules as converting &str to Scalar
+        let data = "b,f\nTRUE,1e3\nfalse,inf\n";
+        let df = CsvReader::new(data.as_bytes()).read().unwrap();
+        assert_eq!(df.dtypes(), vec!["str", "f64"]);
+        assert_eq!(Scalar::from("TRUE"), Scalar::String("TRUE".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "delimiter must not be a quote or a line break")]
+    fn test_delimiter_line_break() {
+        CsvReader::new("a\n".as_bytes()).delimiter('\n');
+    }
+
+    #[test]
+    fn test_quoting() {
+        let data = "a,b\r\n\"x,y\",\"say \"\"hi\"\"\"\r\n\"multi\nline\",z\r\n";
+        let df = CsvReader::new(data.as_bytes()).read().unwrap();
+        assert_eq!(
+            df.column("a"),
+            Some(&Array::new(vec!["x,y", "multi\nline"]))
+        );
+        assert_eq!(df.column("b"), Some(&Array::new(vec!["say \"hi\"", "z"])));
+    }
+
+    #[test]
+    fn test_null_values() {
+        let data = "a,b\n1,NA\n,x\nNULL,\n\n4,\"\"\n";
+        let df = CsvReader::new(data.as_bytes()).read().unwrap();
+        let exp = NullVec::with_mask(vec![1, 0, 0, 4], Some(vec![false, true, true, false]));
+        assert_eq!(df.column("a"), Some(&Array::Int64Array(exp)));
+        assert_eq!(df.column("b").unwrap().dtype(), "str");
+        assert_eq!(
+            df.column("b").unwrap().iloc(&1),
+            Scalar::String("x".to_string())
+        );
+        assert_eq!(df.column("b").unwrap().iloc(&2), Scalar::Null);
+        // quoted field is never regarded as Null
+        assert_eq!(
+            df.column("b").unwrap().iloc(&3),
+            Scalar::String("".to_string())
+        );
+
+        let df = CsvReader::new(data.as_bytes())
+            .null_values(vec!["-".to_string()])
+            .read()
+            .unwrap();
+        // empty string is no longer regarded as Null
+        assert_eq!(df.column("a").unwrap().dtype(), "str");
+        assert_eq!(
+            df.column("a").unwrap().iloc(&1),
+            Scalar::String("".to_string())
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let data = "1;x\n2;y\n";
+        let df = CsvReader::new(data.as_bytes())
+            .delimiter(';')
+            .has_header(false)
+            .read()
+            .unwrap();
+        assert_eq!(
+            df.names(),
+            &["column_0".to_string(), "column_1".to_string()]
+        );
+        assert_eq!(df.len(), 2);
+
+        let df = CsvReader::new(data.as_bytes())
+            .delimiter(';')
+            .has_header(false)
+            .schema(vec![
+                ("a".to_string(), "u8".to_string()),
+                ("b".to_string(), "category".to_string()),
+            ])
+            .read()
+            .unwrap();
+        assert_eq!(df.names(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(df.dtypes(), vec!["u8", "category"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = "a,b\n1,2\n3\n";
+        let err = CsvReader::new(data.as_bytes()).read().unwrap_err();
+        assert_eq!(err.to_string(), "line 3 has 1 fields, expected 2");
+
+        let data = "a\n\"x\n";
+        let err = CsvReader::new(data.as_bytes()).read().unwrap_err();
+        assert_eq!(err.to_string(), "unterminated quoted field at line 2");
+
+        let data = "a\n1\nx\n";
+        let err = CsvReader::new(data.as_bytes())
+            .schema(vec![("a".to_string(), "i64".to_string())])
+            .read()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to parse \"x\" as i64 in column a at record 2"
+        );
+
+        let err = CsvReader::new(data.as_bytes())
+            .schema(vec![("a".to_string(), "list".to_string())])
+            .read()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "unsupported dtype list in column a");
+
+        // date32 uses the same format as inference
+        let err = CsvReader::new("a\n2017-03-01T10:00:00\n".as_bytes())
+            .schema(vec![("a".to_string(), "date32".to_string())])
+            .read()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to parse \"2017-03-01T10:00:00\" as date32 in column a at record 1"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let df = CsvReader::new("a,b\n".as_bytes()).read().unwrap();
+        assert_eq!(df.names(), &["a".to_string(), "b".to_string()]);
+        assert_eq!(df.len(), 0);
+
+        let df = CsvReader::new("".as_bytes()).read().unwrap();
+        assert_eq!(df.len(), 0);
+        assert_eq!(df.names().len(), 0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let data = "a,b\n1,x\n2,y\n3,z\n";
+        let chunks: Vec<_> = CsvReader::new(data.as_bytes())
+            .chunk_size(2)
+            .chunks()
+            .unwrap()
+            .map(|df| df.unwrap())
+            .collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].column("a"), Some(&Array::new(vec![1i64, 2])));
+        assert_eq!(chunks[1].column("b"), Some(&Array::new(vec!["z"])));
+
+        // dtypes are inferred from the first chunk
+        let data = "a\n1\n2\nx\n";
+        let mut chunks = CsvReader::new(data.as_bytes())
+            .chunk_size(2)
+            .chunks()
+            .unwrap();
+        assert!(chunks.next().unwrap().is_ok());
+        let err = chunks.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to parse \"x\" as i64 in column a at record 3"
+        );
+    }
+}
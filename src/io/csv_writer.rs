@@ -0,0 +1,359 @@
+use std::io::{self, Write};
+
+use super::csv::DEFAULT_NULL_VALUES;
+use frame::DataFrame;
+use generic::{Array, Scalar};
+use traits::Slicer;
+
+/// Policy to quote fields in `CsvWriter`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote fields only if they contain delimiter, quote or line break, or are
+    /// the same as the null representation or the default null tokens of `CsvReader`
+    Necessary,
+    /// Quote all non-null fields
+    Always,
+    /// Quote all non-null fields of non-numeric columns
+    NonNumeric,
+    /// Never quote fields. Writing a field which needs quoting results in an error.
+    Never,
+}
+
+/// Writer which writes `DataFrame` or named `Array`s as CSV (RFC 4180).
+///
+/// Values are formatted as the same as `Stringify::into_string_vec` except `Null`,
+/// and are written row by row without materializing all strings at once. Calling
+/// `write` multiple times appends rows, so chunks from `CsvReader::chunks` can be
+/// streamed.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let df = DataFrame::new(vec![
+///     ("a".to_string(), Array::new(vec![1.5, 2.25])),
+///     ("b".to_string(), Array::new(vec!["x", "y,z"])),
+/// ]);
+/// let mut writer = CsvWriter::new(vec![]).float_precision(Some(1));
+/// writer.write(&df).unwrap();
+/// let written = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(written, "a,b\n1.5,x\n2.2,\"y,z\"\n");
+/// ```
+pub struct CsvWriter<W: Write> {
+    writer: W,
+    delimiter: char,
+    quote_style: QuoteStyle,
+    null_value: String,
+    float_precision: Option<usize>,
+    has_header: bool,
+    // names written in the first call, None until then
+    names: Option<Vec<String>>,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Create new `CsvWriter` with default options.
+    ///
+    /// Defaults are `,` delimiter, `QuoteStyle::Necessary`, empty string as null
+    /// representation, shortest float representation, and with header row.
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer,
+            delimiter: ',',
+            quote_style: QuoteStyle::Necessary,
+            null_value: String::new(),
+            float_precision: None,
+            has_header: true,
+            names: None,
+        }
+    }
+
+    /// Set the field delimiter.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        assert!(delimiter != '"', "delimiter must not be a quote");
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quoting policy.
+    pub fn quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Set the representation of `Null`.
+    pub fn null_value(mut self, null_value: &str) -> Self {
+        self.null_value = null_value.to_string();
+        self
+    }
+
+    /// Set the number of digits after the decimal point of floats, `None` to use the
+    /// shortest representation.
+    pub fn float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.float_precision = float_precision;
+        self
+    }
+
+    /// Set whether to write a header row.
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Write all rows of `DataFrame`.
+    ///
+    /// The header row is written only in the first call. Following calls must have
+    /// the same column names.
+    pub fn write(&mut self, df: &DataFrame) -> io::Result<()> {
+        let columns: Vec<&Array> = df.columns().iter().collect();
+        self.write_rows(df.names(), &columns)
+    }
+
+    /// Write named `Array`s as columns.
+    ///
+    /// # Panics
+    ///
+    /// - if columns have different lengths
+    pub fn write_columns(&mut self, columns: &[(&str, &Array)]) -> io::Result<()> {
+        let names: Vec<String> = columns.iter().map(|c| c.0.to_string()).collect();
+        let arrays: Vec<&Array> = columns.iter().map(|c| c.1).collect();
+        if let Some(first) = arrays.first() {
+            assert!(
+                arrays.iter().all(|a| a.len() == first.len()),
+                "columns must have the same length"
+            );
+        }
+        self.write_rows(&names, &arrays)
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_rows(&mut self, names: &[String], columns: &[&Array]) -> io::Result<()> {
+        match self.names {
+            Some(ref written) => {
+                if written.as_slice() != names {
+                    let msg = "columns must be the same as previously written";
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+                }
+            }
+            None => {
+                if self.has_header {
+                    let mut record = String::new();
+                    for (i, name) in names.iter().enumerate() {
+                        let quote = self.should_quote(name, false)?;
+                        self.push_field(&mut record, i, name, quote);
+                    }
+                    record.push('\n');
+                    self.writer.write_all(record.as_bytes())?;
+                }
+                self.names = Some(names.to_vec());
+            }
+        }
+
+        let len = columns.first().map_or(0, |c| c.len());
+        let numeric: Vec<bool> = columns.iter().map(|c| c.is_numeric()).collect();
+        let mut record = String::new();
+        for i in 0..len {
+            record.clear();
+            for (j, column) in columns.iter().enumerate() {
+                let value = unsafe { column.iloc_unchecked(&i) };
+                if value == Scalar::Null {
+                    let null_value = self.null_value.clone();
+                    self.push_field(&mut record, j, &null_value, false);
+                    continue;
+                }
+                let field = self.format(&value);
+                let quote = self.should_quote(&field, numeric[j])?;
+                self.push_field(&mut record, j, &field, quote);
+            }
+            record.push('\n');
+            self.writer.write_all(record.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn format(&self, value: &Scalar) -> String {
+        match (value, self.float_precision) {
+            (Scalar::f64(v), Some(p)) => format!("{:.*}", p, v),
+            (Scalar::f32(v), Some(p)) => format!("{:.*}", p, v),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Returns whether to quote `field` according to the quoting policy, or an error
+    /// if it needs quoting under `QuoteStyle::Never`.
+    fn should_quote(&self, field: &str, numeric: bool) -> io::Result<bool> {
+        match self.quote_style {
+            QuoteStyle::Necessary => Ok(self.needs_quote(field)),
+            QuoteStyle::Always => Ok(true),
+            QuoteStyle::NonNumeric => Ok(!numeric),
+            QuoteStyle::Never => {
+                if self.needs_quote(field) {
+                    let msg = format!("field {:?} must be quoted", field);
+                    Err(io::Error::new(io::ErrorKind::InvalidInput, msg))
+                } else {
+                    Ok(false)
+                }
+            }
+        }
+    }
+
+    fn needs_quote(&self, field: &str) -> bool {
+        *field == self.null_value
+            || DEFAULT_NULL_VALUES.contains(&field)
+            || field
+                .chars()
+                .any(|c| c == self.delimiter || c == '"' || c == '\n' || c == '\r')
+    }
+
+    fn push_field(&self, record: &mut String, location: usize, field: &str, quote: bool) {
+        if location > 0 {
+            record.push(self.delimiter);
+        }
+        if quote {
+            record.push('"');
+            record.push_str(&field.replace('"', "\"\""));
+            record.push('"');
+        } else {
+            record.push_str(field);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io;
+
+    use super::{CsvWriter, QuoteStyle};
+    use frame::DataFrame;
+    use generic::Array;
+    use io::CsvReader;
+    use nullvec::NullVec;
+
+    fn sample() -> DataFrame {
+        DataFrame::new(vec![
+            (
+                "a".to_string(),
+                Array::Int64Array(NullVec::with_mask(vec![1, 0], Some(vec![false, true]))),
+            ),
+            (
+                "b".to_string(),
+                Array::new(vec!["say \"hi\"".to_string(), "".to_string()]),
+            ),
+            ("c".to_string(), Array::new(vec![0.5, 1.0 / 3.0])),
+        ])
+    }
+
+    fn written<F>(f: F) -> String
+    where
+        F: FnOnce(CsvWriter<Vec<u8>>) -> CsvWriter<Vec<u8>>,
+    {
+        let mut writer = f(CsvWriter::new(vec![]));
+        writer.write(&sample()).unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_default() {
+        let res = written(|w| w);
+        let exp = "a,b,c\n1,\"say \"\"hi\"\"\",0.5\n,\"\",0.3333333333333333\n";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_options() {
+        let res = written(|w| {
+            w.delimiter('\t')
+                .null_value("NA")
+                .float_precision(Some(2))
+                .has_header(false)
+        });
+        assert_eq!(res, "1\t\"say \"\"hi\"\"\"\t0.50\nNA\t\"\"\t0.33\n");
+    }
+
+    #[test]
+    fn test_quote_style() {
+        let res = written(|w| w.quote_style(QuoteStyle::Always));
+        let exp =
+            "\"a\",\"b\",\"c\"\n\"1\",\"say \"\"hi\"\"\",\"0.5\"\n,\"\",\"0.3333333333333333\"\n";
+        assert_eq!(res, exp);
+
+        let res = written(|w| {
+            w.quote_style(QuoteStyle::NonNumeric)
+                .float_precision(Some(1))
+        });
+        assert_eq!(
+            res,
+            "\"a\",\"b\",\"c\"\n1,\"say \"\"hi\"\"\",0.5\n,\"\",0.3\n"
+        );
+
+        let mut writer = CsvWriter::new(vec![]).quote_style(QuoteStyle::Never);
+        let a = Array::new(vec![1i64, 2]);
+        let b = Array::new(vec!["x", "y"]);
+        writer.write_columns(&[("a", &a), ("b", &b)]).unwrap();
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(res, "a,b\n1,x\n2,y\n");
+
+        let mut writer = CsvWriter::new(vec![]).quote_style(QuoteStyle::Never);
+        let err = writer.write(&sample()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "field \"say \\\"hi\\\"\" must be quoted");
+
+        // empty string is the same as the null representation
+        let mut writer = CsvWriter::new(vec![]).quote_style(QuoteStyle::Never);
+        let b = Array::new(vec![""]);
+        assert!(writer.write_columns(&[("b", &b)]).is_err());
+    }
+
+    #[test]
+    fn test_streaming() {
+        let df = sample();
+        let mut writer = CsvWriter::new(vec![]);
+        writer.write(&df.head(1)).unwrap();
+        writer.write(&df.tail(1)).unwrap();
+        assert!(writer.write(&df.select(&["a"])).is_err());
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(res, written(|w| w));
+    }
+
+    #[test]
+    fn test_write_columns() {
+        let a = Array::new(vec![1i64, 2]);
+        let b = Array::new(vec![true, false]);
+        let mut writer = CsvWriter::new(vec![]);
+        writer.write_columns(&[("a", &a), ("b", &b)]).unwrap();
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(res, "a,b\n1,true\n2,false\n");
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let res = written(|w| w);
+        let df = CsvReader::new(res.as_bytes()).read().unwrap();
+        // quoted empty string is distinguished from Null
+        assert_eq!(df, sample());
+
+        let b = Array::new(NullVec::with_mask(
+            vec!["NA", "", "NULL", ""]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            Some(vec![false, false, false, true]),
+        ));
+        let mut writer = CsvWriter::new(vec![]);
+        writer.write_columns(&[("b", &b)]).unwrap();
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(res, "b\n\"NA\"\n\"\"\n\"NULL\"\n\n");
+        let df = CsvReader::new(res.as_bytes()).read().unwrap();
+        assert_eq!(df.column("b"), Some(&b));
+    }
+}
@@ -0,0 +1,338 @@
+//! Minimal FlatBuffers encoding, used by Arrow IPC metadata.
+//!
+//! Only the subset which Arrow messages need is supported: tables with scalar,
+//! union, string and vector fields. The builder writes objects front to back,
+//! each table preceded by its vtable and followed by the objects it refers to,
+//! so that all offsets point forward as the format requires.
+
+use std::io;
+use std::str;
+
+use super::native::invalid_data;
+
+/// Fields of a table as pairs of field id and value. Absent fields are omitted.
+pub type Fields = Vec<(u16, Value)>;
+
+/// Value of a table field.
+pub enum Value {
+    Bool(bool),
+    UByte(u8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Str(String),
+    Table(Fields),
+    Tables(Vec<Fields>),
+    /// Vector of structs as raw bytes and the number of structs. Structs are
+    /// aligned to 8 bytes.
+    Structs(Vec<u8>, usize),
+}
+
+impl Value {
+    /// Size of the inline part in the table.
+    fn width(&self) -> usize {
+        match *self {
+            Value::Bool(_) | Value::UByte(_) => 1,
+            Value::Short(_) => 2,
+            Value::Long(_) => 8,
+            _ => 4,
+        }
+    }
+}
+
+/// Encode `root` table as a finished buffer.
+pub fn finish(root: &[(u16, Value)]) -> Vec<u8> {
+    let mut buf = vec![0u8; 4];
+    let pos = put_table(&mut buf, root);
+    patch(&mut buf, 0, pos);
+    buf
+}
+
+fn align(buf: &mut Vec<u8>, n: usize) {
+    let len = buf.len().div_ceil(n) * n;
+    buf.resize(len, 0);
+}
+
+/// Write offset to `target` at `at`.
+fn patch(buf: &mut [u8], at: usize, target: usize) {
+    let offset = (target - at) as u32;
+    buf[at..at + 4].copy_from_slice(&offset.to_le_bytes());
+}
+
+fn put_table(buf: &mut Vec<u8>, fields: &[(u16, Value)]) -> usize {
+    // inline fields follow the vtable offset, each aligned to its size
+    let mut size: usize = 4;
+    let mut slots = Vec::with_capacity(fields.len());
+    for (_, value) in fields.iter() {
+        let width = value.width();
+        size = size.div_ceil(width) * width;
+        slots.push(size);
+        size += width;
+    }
+    let nfields = fields.iter().map(|f| f.0 as usize + 1).max().unwrap_or(0);
+    let mut vtable = vec![0u16; nfields + 2];
+    vtable[0] = (vtable.len() * 2) as u16;
+    vtable[1] = size as u16;
+    for (&(id, _), &slot) in fields.iter().zip(slots.iter()) {
+        vtable[id as usize + 2] = slot as u16;
+    }
+
+    align(buf, 2);
+    let vtable_pos = buf.len();
+    for v in vtable.iter() {
+        buf.extend_from_slice(&v.to_le_bytes());
+    }
+    align(buf, 8);
+    let pos = buf.len();
+    buf.resize(pos + size, 0);
+    buf[pos..pos + 4].copy_from_slice(&((pos - vtable_pos) as i32).to_le_bytes());
+
+    for ((_, value), &slot) in fields.iter().zip(slots.iter()) {
+        let at = pos + slot;
+        match *value {
+            Value::Bool(v) => buf[at] = v as u8,
+            Value::UByte(v) => buf[at] = v,
+            Value::Short(v) => buf[at..at + 2].copy_from_slice(&v.to_le_bytes()),
+            Value::Int(v) => buf[at..at + 4].copy_from_slice(&v.to_le_bytes()),
+            Value::Long(v) => buf[at..at + 8].copy_from_slice(&v.to_le_bytes()),
+            _ => {}
+        }
+    }
+    for ((_, value), &slot) in fields.iter().zip(slots.iter()) {
+        let target = match *value {
+            Value::Str(ref s) => {
+                align(buf, 4);
+                let target = buf.len();
+                buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                buf.extend_from_slice(s.as_bytes());
+                buf.push(0);
+                target
+            }
+            Value::Table(ref table) => put_table(buf, table),
+            Value::Tables(ref tables) => {
+                align(buf, 4);
+                let target = buf.len();
+                buf.extend_from_slice(&(tables.len() as u32).to_le_bytes());
+                buf.resize(target + 4 + tables.len() * 4, 0);
+                for (i, table) in tables.iter().enumerate() {
+                    let t = put_table(buf, table);
+                    patch(buf, target + 4 + i * 4, t);
+                }
+                target
+            }
+            Value::Structs(ref bytes, count) => {
+                // structs start after the length, at 8-byte boundary
+                align(buf, 8);
+                buf.extend_from_slice(&[0; 4]);
+                let target = buf.len();
+                buf.extend_from_slice(&(count as u32).to_le_bytes());
+                buf.extend_from_slice(bytes);
+                target
+            }
+            _ => continue,
+        };
+        patch(buf, pos + slot, target);
+    }
+    pos
+}
+
+/// Read-only view of an encoded table. All accesses are bounds-checked.
+#[derive(Clone, Copy)]
+pub struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    vtable: usize,
+}
+
+fn bytes(buf: &[u8], pos: usize, len: usize) -> io::Result<&[u8]> {
+    match pos.checked_add(len) {
+        Some(end) if end <= buf.len() => Ok(&buf[pos..end]),
+        _ => Err(invalid_data("invalid flatbuffer")),
+    }
+}
+
+fn get_u16(buf: &[u8], pos: usize) -> io::Result<u16> {
+    let b = bytes(buf, pos, 2)?;
+    Ok(u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn get_u32(buf: &[u8], pos: usize) -> io::Result<u32> {
+    let b = bytes(buf, pos, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Follow the offset stored at `pos`.
+fn deref(buf: &[u8], pos: usize) -> io::Result<usize> {
+    let offset = get_u32(buf, pos)? as usize;
+    pos.checked_add(offset)
+        .ok_or_else(|| invalid_data("invalid flatbuffer"))
+}
+
+impl<'a> Table<'a> {
+    /// Returns the root table of `buf`.
+    pub fn root(buf: &'a [u8]) -> io::Result<Self> {
+        Table::at(buf, deref(buf, 0)?)
+    }
+
+    fn at(buf: &'a [u8], pos: usize) -> io::Result<Self> {
+        let soffset = i64::from(get_u32(buf, pos)? as i32);
+        let vtable = pos as i64 - soffset;
+        if vtable < 0 {
+            return Err(invalid_data("invalid flatbuffer"));
+        }
+        let vtable = vtable as usize;
+        let size = get_u16(buf, vtable)? as usize;
+        if size < 4 || !size.is_multiple_of(2) {
+            return Err(invalid_data("invalid flatbuffer"));
+        }
+        bytes(buf, vtable, size)?;
+        Ok(Table { buf, pos, vtable })
+    }
+
+    /// Returns the location of field `id`, `None` if absent.
+    fn field(&self, id: u16) -> io::Result<Option<usize>> {
+        let entry = 4 + 2 * id as usize;
+        if entry + 2 > get_u16(self.buf, self.vtable)? as usize {
+            return Ok(None);
+        }
+        match get_u16(self.buf, self.vtable + entry)? {
+            0 => Ok(None),
+            offset => Ok(Some(self.pos + offset as usize)),
+        }
+    }
+
+    fn scalar(&self, id: u16, width: usize) -> io::Result<Option<&'a [u8]>> {
+        match self.field(id)? {
+            Some(pos) => Ok(Some(bytes(self.buf, pos, width)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn bool(&self, id: u16, default: bool) -> io::Result<bool> {
+        Ok(self.scalar(id, 1)?.map_or(default, |b| b[0] != 0))
+    }
+
+    pub fn u8(&self, id: u16, default: u8) -> io::Result<u8> {
+        Ok(self.scalar(id, 1)?.map_or(default, |b| b[0]))
+    }
+
+    pub fn i16(&self, id: u16, default: i16) -> io::Result<i16> {
+        Ok(self
+            .scalar(id, 2)?
+            .map_or(default, |b| i16::from_le_bytes([b[0], b[1]])))
+    }
+
+    pub fn i32(&self, id: u16, default: i32) -> io::Result<i32> {
+        Ok(self
+            .scalar(id, 4)?
+            .map_or(default, |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+    }
+
+    pub fn i64(&self, id: u16, default: i64) -> io::Result<i64> {
+        Ok(self.scalar(id, 8)?.map_or(default, get_i64))
+    }
+
+    pub fn table(&self, id: u16) -> io::Result<Option<Table<'a>>> {
+        match self.field(id)? {
+            Some(pos) => Ok(Some(Table::at(self.buf, deref(self.buf, pos)?)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the location and the length of vector field `id`.
+    fn vector(&self, id: u16) -> io::Result<Option<(usize, usize)>> {
+        match self.field(id)? {
+            Some(pos) => {
+                let start = deref(self.buf, pos)?;
+                let len = get_u32(self.buf, start)? as usize;
+                Ok(Some((start + 4, len)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn str(&self, id: u16) -> io::Result<Option<&'a str>> {
+        match self.vector(id)? {
+            Some((start, len)) => {
+                let b = bytes(self.buf, start, len)?;
+                str::from_utf8(b)
+                    .map(Some)
+                    .map_err(|_| invalid_data("invalid UTF-8"))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns tables of vector field `id`, empty if absent.
+    pub fn tables(&self, id: u16) -> io::Result<Vec<Table<'a>>> {
+        match self.vector(id)? {
+            Some((start, len)) => {
+                bytes(self.buf, start, len.saturating_mul(4))?;
+                (0..len)
+                    .map(|i| Table::at(self.buf, deref(self.buf, start + i * 4)?))
+                    .collect()
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Returns structs of `size` bytes in vector field `id`, empty if absent.
+    pub fn structs(&self, id: u16, size: usize) -> io::Result<Vec<&'a [u8]>> {
+        match self.vector(id)? {
+            Some((start, len)) => {
+                let b = bytes(self.buf, start, len.saturating_mul(size))?;
+                Ok(b.chunks(size).collect())
+            }
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub fn get_i64(bytes: &[u8]) -> i64 {
+    let mut b = [0u8; 8];
+    b.copy_from_slice(&bytes[..8]);
+    i64::from_le_bytes(b)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{finish, Table, Value};
+
+    #[test]
+    fn test_roundtrip() {
+        let child = vec![(1, Value::Int(-3))];
+        let buf = finish(&[
+            (0, Value::Short(4)),
+            (1, Value::Bool(true)),
+            (3, Value::Long(1 << 40)),
+            (4, Value::Str("name".to_string())),
+            (5, Value::Table(child)),
+            (6, Value::Tables(vec![vec![], vec![(0, Value::UByte(7))]])),
+            (7, Value::Structs(vec![1, 0, 0, 0, 0, 0, 0, 0], 1)),
+        ]);
+        let root = Table::root(&buf).unwrap();
+        assert_eq!(root.i16(0, 0).unwrap(), 4);
+        assert!(root.bool(1, false).unwrap());
+        // absent fields have default values
+        assert_eq!(root.u8(2, 9).unwrap(), 9);
+        assert_eq!(root.i64(3, 0).unwrap(), 1 << 40);
+        assert_eq!(root.str(4).unwrap(), Some("name"));
+        assert_eq!(root.table(5).unwrap().unwrap().i32(1, 0).unwrap(), -3);
+        let tables = root.tables(6).unwrap();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[1].u8(0, 0).unwrap(), 7);
+        assert_eq!(root.structs(7, 8).unwrap(), vec![&[1, 0, 0, 0, 0, 0, 0, 0]]);
+        assert!(root.table(8).unwrap().is_none());
+        assert!(root.tables(9).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let buf = finish(&[(0, Value::Str("name".to_string()))]);
+        for len in 0..buf.len() - 1 {
+            let res = Table::root(&buf[..len]).and_then(|t| t.str(0));
+            assert!(res.is_err());
+        }
+    }
+}
@@ -0,0 +1,673 @@
+//! Minimal JSON tokenizer and encoder shared by JSON Lines reader and writer.
+//!
+//! JSON values are parsed into `Scalar`: integers into `i64` (`u64` if out of
+//! range), other numbers into `f64`, arrays into `List` and objects into `Struct`.
+
+use std::fmt::Write;
+
+use columnar::{ListColumn, StructColumn};
+use generic::{Array, Scalar};
+use nullvec::NullVec;
+use traits::Slicer;
+
+/// Maximum nesting depth of arrays and objects.
+const MAX_DEPTH: usize = 128;
+
+/// Parse a single JSON value which spans the whole `text`, except whitespaces.
+///
+/// Returns the error message with the byte position on failure, including when
+/// arrays and objects are nested deeper than 128 levels.
+pub fn parse(text: &str) -> Result<Scalar, String> {
+    let mut parser = Parser {
+        text,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &str) -> String {
+        format!("{} at position {}", msg, self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.text[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {}", token)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Scalar, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect("null").map(|_| Scalar::Null),
+            Some(b't') => self.expect("true").map(|_| Scalar::bool(true)),
+            Some(b'f') => self.expect("false").map(|_| Scalar::bool(false)),
+            Some(b'"') => self.parse_string().map(Scalar::String),
+            Some(b'[') | Some(b'{') => self.parse_nested(),
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn parse_nested(&mut self) -> Result<Scalar, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        self.depth += 1;
+        let value = if self.peek() == Some(b'[') {
+            self.parse_array()
+        } else {
+            self.parse_object()
+        };
+        self.depth -= 1;
+        value
+    }
+
+    fn parse_number(&mut self) -> Result<Scalar, String> {
+        let start = self.pos;
+        let digits = |p: &mut Parser| -> usize {
+            let from = p.pos;
+            while let Some(b'0'..=b'9') = p.peek() {
+                p.pos += 1;
+            }
+            p.pos - from
+        };
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let leading = self.peek();
+        let n = digits(self);
+        if n == 0 || (leading == Some(b'0') && n > 1) {
+            return Err(self.error("invalid number"));
+        }
+        let mut integer = true;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            integer = false;
+            if digits(self) == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            integer = false;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        let token = &self.text[start..self.pos];
+        if integer {
+            if let Ok(v) = token.parse::<i64>() {
+                return Ok(Scalar::i64(v));
+            }
+            if let Ok(v) = token.parse::<u64>() {
+                return Ok(Scalar::u64(v));
+            }
+        }
+        Ok(Scalar::f64(token.parse::<f64>().unwrap()))
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        // from_str_radix accepts a leading sign, which is not allowed in JSON
+        let hex = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()));
+        match hex.and_then(|h| u32::from_str_radix(h, 16).ok()) {
+            Some(v) => {
+                self.pos += 4;
+                Ok(v)
+            }
+            None => Err(self.error("invalid unicode escape")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut result = String::new();
+        loop {
+            let start = self.pos;
+            while let Some(b) = self.peek() {
+                if b == b'"' || b == b'\\' || b < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            result.push_str(&self.text[start..self.pos]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(result);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let mut code = self.parse_hex4()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                // high surrogate must be followed by low surrogate
+                                self.expect("\\u")?;
+                                let low = self.parse_hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("invalid surrogate pair"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            match ::std::char::from_u32(code) {
+                                Some(c) => result.push(c),
+                                None => return Err(self.error("invalid unicode escape")),
+                            }
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+                    result.push(escaped);
+                }
+                Some(_) => return Err(self.error("control character in string")),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Scalar, String> {
+        self.pos += 1;
+        let mut values: Vec<Scalar> = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+        } else {
+            loop {
+                values.push(self.parse_value()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(b']') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.error("expected , or ]")),
+                }
+            }
+        }
+        let kind = infer_kind(&values);
+        Ok(Scalar::List(build_array(&values, &kind)))
+    }
+
+    fn parse_object(&mut self) -> Result<Scalar, String> {
+        self.pos += 1;
+        let mut fields: Vec<(String, Scalar)> = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Scalar::Struct(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected string key"));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(":")?;
+            let value = self.parse_value()?;
+            // the last one wins if keys are duplicated
+            match fields.iter().position(|f| f.0 == key) {
+                Some(i) => fields[i].1 = value,
+                None => fields.push((key, value)),
+            }
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Scalar::Struct(fields));
+                }
+                _ => return Err(self.error("expected , or }")),
+            }
+        }
+    }
+}
+
+/// Type of JSON values, used to infer the supertype which can hold all values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kind {
+    Null,
+    Bool,
+    Int,
+    UInt,
+    Float,
+    Str,
+    List(Box<Kind>),
+    Struct(Vec<(String, Kind)>),
+}
+
+impl Kind {
+    /// Returns the type of a parsed value.
+    pub fn of(value: &Scalar) -> Kind {
+        match *value {
+            Scalar::Null => Kind::Null,
+            Scalar::bool(_) => Kind::Bool,
+            Scalar::i64(_) => Kind::Int,
+            Scalar::u64(_) => Kind::UInt,
+            Scalar::f64(_) => Kind::Float,
+            Scalar::List(ref vals) => Kind::List(Box::new(kind_of_array(vals))),
+            Scalar::Struct(ref fields) => Kind::Struct(
+                fields
+                    .iter()
+                    .map(|(n, v)| (n.clone(), Kind::of(v)))
+                    .collect(),
+            ),
+            _ => Kind::Str,
+        }
+    }
+
+    /// Returns the supertype of two types.
+    ///
+    /// Numbers of different types are widened to `Float`, lists and structs are
+    /// merged recursively, and other mismatches fall back to `Str`.
+    pub fn supertype(self, other: Kind) -> Kind {
+        match (self, other) {
+            (Kind::Null, k) | (k, Kind::Null) => k,
+            (Kind::List(l), Kind::List(r)) => Kind::List(Box::new(l.supertype(*r))),
+            (Kind::Struct(mut l), Kind::Struct(r)) => {
+                for (name, kind) in r.into_iter() {
+                    match l.iter().position(|f| f.0 == name) {
+                        Some(i) => {
+                            let current = ::std::mem::replace(&mut l[i].1, Kind::Null);
+                            l[i].1 = current.supertype(kind);
+                        }
+                        None => l.push((name, kind)),
+                    }
+                }
+                Kind::Struct(l)
+            }
+            (l, r) if l == r => l,
+            (Kind::Int, Kind::UInt)
+            | (Kind::UInt, Kind::Int)
+            | (Kind::Int, Kind::Float)
+            | (Kind::Float, Kind::Int)
+            | (Kind::UInt, Kind::Float)
+            | (Kind::Float, Kind::UInt) => Kind::Float,
+            _ => Kind::Str,
+        }
+    }
+}
+
+/// Infer the supertype of all values.
+pub fn infer_kind(values: &[Scalar]) -> Kind {
+    values
+        .iter()
+        .fold(Kind::Null, |k, v| k.supertype(Kind::of(v)))
+}
+
+/// Infer the supertype of all values in `Array`.
+///
+/// Nested lists and structs are inspected through their child `Array`s, so that
+/// each level is visited once instead of being converted to `Scalar`s.
+fn kind_of_array(values: &Array) -> Kind {
+    match *values {
+        Array::ListArray(ref c) => {
+            if c.len() == 0 || c.mask().is_some_and(|m| m.iter().all(|&x| x)) {
+                return Kind::Null;
+            }
+            let complete = !c.has_null() && c.offsets()[c.len()] as usize == c.values().len();
+            let inner = if complete {
+                kind_of_array(c.values())
+            } else {
+                kind_of_array(&c.explode().0)
+            };
+            Kind::List(Box::new(inner))
+        }
+        Array::StructArray(ref c) => {
+            let valid: Vec<bool> = c.is_null().iter().map(|&x| !x).collect();
+            if !valid.contains(&true) {
+                return Kind::Null;
+            }
+            let fields = c
+                .names()
+                .iter()
+                .zip(c.fields())
+                .map(|(name, field)| {
+                    let kind = if c.has_null() {
+                        kind_of_array(&field.blocs(&valid))
+                    } else {
+                        kind_of_array(field)
+                    };
+                    (name.clone(), kind)
+                })
+                .collect();
+            Kind::Struct(fields)
+        }
+        _ => (0..values.len()).fold(Kind::Null, |k, i| k.supertype(Kind::of(&values.iloc(&i)))),
+    }
+}
+
+/// Build `Array` of `kind` from values whose types are subtypes of `kind`.
+///
+/// Columns without non-null values are built as `str`.
+pub fn build_array(values: &[Scalar], kind: &Kind) -> Array {
+    match *kind {
+        Kind::Bool => values
+            .iter()
+            .map(|v| v.as_bool())
+            .collect::<NullVec<bool>>()
+            .into(),
+        Kind::Int => values
+            .iter()
+            .map(|v| v.as_i64())
+            .collect::<NullVec<i64>>()
+            .into(),
+        Kind::UInt => values
+            .iter()
+            .map(|v| v.as_u64())
+            .collect::<NullVec<u64>>()
+            .into(),
+        Kind::Float => values
+            .iter()
+            .map(|v| match *v {
+                Scalar::i64(x) => Scalar::f64(x as f64),
+                Scalar::u64(x) => Scalar::f64(x as f64),
+                ref x => x.clone(),
+            })
+            .map(|v| v.as_f64())
+            .collect::<NullVec<f64>>()
+            .into(),
+        Kind::List(ref inner) => {
+            let mut elements: Vec<Scalar> = vec![];
+            let mut offsets: Vec<u64> = vec![0];
+            let mut mask: Vec<bool> = Vec::with_capacity(values.len());
+            for value in values.iter() {
+                if let Scalar::List(ref vals) = *value {
+                    elements.extend((0..vals.len()).map(|i| vals.iloc(&i)));
+                }
+                offsets.push(elements.len() as u64);
+                mask.push(*value == Scalar::Null);
+            }
+            let child = build_array(&elements, inner);
+            ListColumn::new(child, offsets, Some(mask)).into()
+        }
+        Kind::Struct(ref fields) if !fields.is_empty() => {
+            let columns = fields
+                .iter()
+                .map(|(name, kind)| {
+                    let column: Vec<Scalar> = values
+                        .iter()
+                        .map(|v| match *v {
+                            Scalar::Struct(ref row) => row
+                                .iter()
+                                .find(|f| f.0 == *name)
+                                .map_or(Scalar::Null, |f| f.1.clone()),
+                            _ => Scalar::Null,
+                        })
+                        .collect();
+                    (name.clone(), build_array(&column, kind))
+                })
+                .collect();
+            let mask = values.iter().map(|v| *v == Scalar::Null).collect();
+            StructColumn::with_mask(columns, Some(mask)).into()
+        }
+        _ => values
+            .iter()
+            .map(|v| match *v {
+                Scalar::Null => Scalar::Null,
+                Scalar::String(ref s) => Scalar::String(s.clone()),
+                ref v => Scalar::String(encode(v)),
+            })
+            .map(|v| v.as_str())
+            .collect::<NullVec<String>>()
+            .into(),
+    }
+}
+
+/// Encode `Scalar` as JSON text.
+///
+/// Values which JSON has no counterpart, such as dates and decimals, are written
+/// as strings. Non-finite floats are written as `null`.
+pub fn encode(value: &Scalar) -> String {
+    let mut result = String::new();
+    encode_to(&mut result, value);
+    result
+}
+
+/// Append `Scalar` as JSON text to `result`.
+pub fn encode_to(result: &mut String, value: &Scalar) {
+    match *value {
+        Scalar::Null => result.push_str("null"),
+        Scalar::f64(v) if !v.is_finite() => result.push_str("null"),
+        Scalar::f32(v) if !v.is_finite() => result.push_str("null"),
+        // Debug keeps the decimal point, so that floats are read as floats
+        Scalar::f64(v) => write!(result, "{:?}", v).unwrap(),
+        Scalar::f32(v) => write!(result, "{:?}", v).unwrap(),
+        Scalar::i64(_)
+        | Scalar::i32(_)
+        | Scalar::i16(_)
+        | Scalar::i8(_)
+        | Scalar::isize(_)
+        | Scalar::u64(_)
+        | Scalar::u32(_)
+        | Scalar::u16(_)
+        | Scalar::u8(_)
+        | Scalar::usize(_)
+        | Scalar::i128(_)
+        | Scalar::u128(_)
+        | Scalar::bool(_) => write!(result, "{}", value).unwrap(),
+        Scalar::String(ref v) => encode_str(result, v),
+        Scalar::List(ref vals) => {
+            result.push('[');
+            for i in 0..vals.len() {
+                if i > 0 {
+                    result.push(',');
+                }
+                encode_to(result, &vals.iloc(&i));
+            }
+            result.push(']');
+        }
+        Scalar::Struct(ref fields) => {
+            result.push('{');
+            for (i, (name, v)) in fields.iter().enumerate() {
+                if i > 0 {
+                    result.push(',');
+                }
+                encode_str(result, name);
+                result.push(':');
+                encode_to(result, v);
+            }
+            result.push('}');
+        }
+        _ => encode_str(result, &value.to_string()),
+    }
+}
+
+/// Append quoted and escaped string to `result`.
+pub fn encode_str(result: &mut String, value: &str) {
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{encode, infer_kind, parse, Kind};
+    use generic::{Array, Scalar};
+
+    #[test]
+    fn test_parse_primitive() {
+        assert_eq!(parse(" null "), Ok(Scalar::Null));
+        assert_eq!(parse("true"), Ok(Scalar::bool(true)));
+        assert_eq!(parse("-12"), Ok(Scalar::i64(-12)));
+        assert_eq!(
+            parse("18446744073709551615"),
+            Ok(Scalar::u64(18_446_744_073_709_551_615))
+        );
+        assert_eq!(parse("1.5e2"), Ok(Scalar::f64(150.)));
+        assert_eq!(
+            parse("\"a\\\"b\\u00e9\\ud83d\\ude00\""),
+            Ok(Scalar::String("a\"b\u{e9}\u{1f600}".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        assert_eq!(
+            parse("[1, null, 2.5]"),
+            Ok(Scalar::List(Array::new(vec![
+                Scalar::f64(1.),
+                Scalar::Null,
+                Scalar::f64(2.5),
+            ])))
+        );
+        assert_eq!(
+            parse("{\"a\": 1, \"b\": {}}"),
+            Ok(Scalar::Struct(vec![
+                ("a".to_string(), Scalar::i64(1)),
+                ("b".to_string(), Scalar::Struct(vec![])),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for s in [
+            "",
+            "01",
+            "1.",
+            "tru",
+            "\"a",
+            "[1,]",
+            "{\"a\" 1}",
+            "1 2",
+            "\"\\x\"",
+            "\"\\u+041\"",
+            "\"\\u004\"",
+        ]
+        .iter()
+        {
+            assert!(parse(s).is_err(), "{}", s);
+        }
+        assert_eq!(
+            parse("[1,]"),
+            Err("unexpected character at position 3".to_string())
+        );
+        assert_eq!(parse("\"\\u0041\""), Ok(Scalar::String("A".to_string())));
+    }
+
+    #[test]
+    fn test_parse_deeply_nested() {
+        let text = format!("{}1{}", "[".repeat(128), "]".repeat(128));
+        assert!(parse(&text).is_ok());
+        let text = format!("{}1{}", "[".repeat(129), "]".repeat(129));
+        assert_eq!(
+            parse(&text),
+            Err("nesting too deep at position 128".to_string())
+        );
+        // must not overflow the stack
+        assert!(parse(&"[{\"a\":".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_kind_of_nested() {
+        let text = format!("{}1{}", "[".repeat(128), "]".repeat(128));
+        let mut exp = Kind::Int;
+        for _ in 0..128 {
+            exp = Kind::List(Box::new(exp));
+        }
+        assert_eq!(Kind::of(&parse(&text).unwrap()), exp);
+
+        let value = parse("[[null, {\"a\": [1]}], null, [{\"b\": true}, null]]").unwrap();
+        assert_eq!(
+            Kind::of(&value),
+            Kind::List(Box::new(Kind::List(Box::new(Kind::Struct(vec![
+                ("a".to_string(), Kind::List(Box::new(Kind::Int))),
+                ("b".to_string(), Kind::Bool),
+            ])))))
+        );
+        assert_eq!(
+            Kind::of(&parse("[[], [null]]").unwrap()),
+            Kind::List(Box::new(Kind::List(Box::new(Kind::Null))))
+        );
+    }
+
+    #[test]
+    fn test_supertype() {
+        let values = vec![Scalar::i64(1), Scalar::Null, Scalar::u64(2)];
+        assert_eq!(infer_kind(&values), Kind::Float);
+        let values = vec![Scalar::i64(1), Scalar::bool(true)];
+        assert_eq!(infer_kind(&values), Kind::Str);
+        let values = vec![
+            parse("{\"a\": 1}").unwrap(),
+            parse("{\"b\": [\"x\"]}").unwrap(),
+        ];
+        assert_eq!(
+            infer_kind(&values),
+            Kind::Struct(vec![
+                ("a".to_string(), Kind::Int),
+                ("b".to_string(), Kind::List(Box::new(Kind::Str))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        for s in [
+            "null",
+            "[1,null,3]",
+            "{\"a\":[1.0,2.5],\"b\":\"x\\ny\"}",
+            "[[],[true]]",
+        ]
+        .iter()
+        {
+            assert_eq!(encode(&parse(s).unwrap()), *s);
+        }
+        assert_eq!(encode(&Scalar::String("\u{1}".to_string())), "\"\\u0001\"");
+    }
+}
@@ -0,0 +1,176 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use frame::DataFrame;
+use generic::Scalar;
+
+use super::json;
+
+/// Reader which parses JSON Lines (newline-delimited JSON objects) into `DataFrame`.
+///
+/// Each key becomes a column in the order of first appearance. Missing keys and
+/// JSON `null` become `Null`. Each column's dtype is the supertype of its values:
+/// integers are read as `i64` (`u64` if out of range) and widened to `f64` if
+/// mixed with other numbers, arrays become `list` and objects become `struct`.
+/// Values of incompatible types are stored as `str`, where non-string values are
+/// kept as JSON text.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let data = "{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2.5}\n";
+/// let df = JsonLinesReader::new(data.as_bytes()).read().unwrap();
+/// assert_eq!(df.names(), &["a".to_string(), "b".to_string()]);
+/// assert_eq!(df.column("a"), Some(&Array::new(vec![1., 2.5])));
+/// assert_eq!(df.column("b").unwrap().dtype(), "str");
+/// ```
+pub struct JsonLinesReader<R: Read> {
+    reader: BufReader<R>,
+}
+
+impl<R: Read> JsonLinesReader<R> {
+    /// Create new `JsonLinesReader`.
+    pub fn new(reader: R) -> Self {
+        JsonLinesReader {
+            reader: BufReader::new(reader),
+        }
+    }
+
+    /// Read all lines into a single `DataFrame`, skipping empty lines.
+    pub fn read(mut self) -> io::Result<DataFrame> {
+        let mut names: Vec<String> = vec![];
+        let mut columns: Vec<Vec<Scalar>> = vec![];
+        let mut nrows = 0;
+        let mut lines = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            lines += 1;
+            let text = line.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let fields = match json::parse(text) {
+                Ok(Scalar::Struct(fields)) => fields,
+                Ok(_) => {
+                    let msg = format!("line {} is not a JSON object", lines);
+                    return Err(invalid_data(msg));
+                }
+                Err(e) => {
+                    let msg = format!("invalid JSON at line {}: {}", lines, e);
+                    return Err(invalid_data(msg));
+                }
+            };
+            for (name, value) in fields.into_iter() {
+                match names.iter().position(|n| *n == name) {
+                    Some(i) => columns[i].push(value),
+                    None => {
+                        let mut column = vec![Scalar::Null; nrows];
+                        column.push(value);
+                        names.push(name);
+                        columns.push(column);
+                    }
+                }
+            }
+            nrows += 1;
+            // fill missing keys
+            for column in columns.iter_mut() {
+                if column.len() < nrows {
+                    column.push(Scalar::Null);
+                }
+            }
+        }
+        let fields = names
+            .into_iter()
+            .zip(columns)
+            .map(|(name, column)| {
+                let kind = json::infer_kind(&column);
+                (name, json::build_array(&column, &kind))
+            })
+            .collect();
+        Ok(DataFrame::new(fields))
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::JsonLinesReader;
+    use generic::{Array, Scalar};
+    use nullvec::NullVec;
+    use traits::Slicer;
+
+    #[test]
+    fn test_read() {
+        let data = "{\"i\": 1, \"s\": \"x\", \"b\": true}\n\
+                    \n\
+                    {\"i\": null, \"u\": 18446744073709551615, \"m\": 1}\r\n\
+                    {\"s\": \"y\", \"m\": \"z\", \"b\": false}";
+        let df = JsonLinesReader::new(data.as_bytes()).read().unwrap();
+        assert_eq!(df.names(), &["i", "s", "b", "u", "m"]);
+        assert_eq!(df.dtypes(), vec!["i64", "str", "bool", "u64", "str"]);
+        let exp = NullVec::with_mask(vec![1, 0, 0], Some(vec![false, true, true]));
+        assert_eq!(df.column("i"), Some(&Array::Int64Array(exp)));
+        assert_eq!(df.column("s").unwrap().iloc(&1), Scalar::Null);
+        // incompatible values are kept as JSON text
+        assert_eq!(
+            df.column("m").unwrap().iloc(&1),
+            Scalar::String("1".to_string())
+        );
+        assert_eq!(
+            df.column("m").unwrap().iloc(&2),
+            Scalar::String("z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_nested() {
+        let data = "{\"l\": [1, 2], \"o\": {\"a\": 1}}\n\
+                    {\"l\": [], \"o\": {\"b\": \"x\"}}\n\
+                    {\"l\": null, \"o\": null}\n";
+        let df = JsonLinesReader::new(data.as_bytes()).read().unwrap();
+        assert_eq!(df.dtypes(), vec!["list", "struct"]);
+        let l = df.column("l").unwrap();
+        assert_eq!(l.iloc(&0), Scalar::List(Array::new(vec![1i64, 2])));
+        assert_eq!(l.iloc(&1), Scalar::List(Array::new(Vec::<i64>::new())));
+        assert_eq!(l.iloc(&2), Scalar::Null);
+        let o = df.column("o").unwrap();
+        assert_eq!(
+            o.iloc(&1),
+            Scalar::Struct(vec![
+                ("a".to_string(), Scalar::Null),
+                ("b".to_string(), Scalar::String("x".to_string())),
+            ])
+        );
+        assert_eq!(o.iloc(&2), Scalar::Null);
+    }
+
+    #[test]
+    fn test_read_empty() {
+        let df = JsonLinesReader::new("\n".as_bytes()).read().unwrap();
+        assert_eq!(df.len(), 0);
+        assert!(df.names().is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = "{\"a\": 1}\n[1]\n";
+        let err = JsonLinesReader::new(data.as_bytes()).read().unwrap_err();
+        assert_eq!(err.to_string(), "line 2 is not a JSON object");
+
+        let data = "{\"a\": 1}\n\n{\"a\": }\n";
+        let err = JsonLinesReader::new(data.as_bytes()).read().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid JSON at line 3: unexpected character at position 6"
+        );
+    }
+}
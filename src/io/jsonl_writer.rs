@@ -0,0 +1,158 @@
+use std::io::{self, Write};
+
+use frame::DataFrame;
+use generic::Array;
+use traits::Slicer;
+
+use super::json;
+
+/// Writer which writes `DataFrame` or named `Array`s as JSON Lines, one object per
+/// row.
+///
+/// `Null` is written as JSON `null`, lists as arrays and structs as objects.
+/// Values which JSON has no counterpart, such as dates and decimals, are written
+/// as strings. Calling `write` multiple times appends rows.
+///
+/// # Examples
+///
+/// ```
+/// use nullvec::prelude::*;
+///
+/// let df = DataFrame::new(vec![
+///     ("a".to_string(), Array::new(vec![1i64, 2])),
+///     ("b".to_string(), Array::new(vec!["x", "y\"z"])),
+/// ]);
+/// let mut writer = JsonLinesWriter::new(vec![]);
+/// writer.write(&df).unwrap();
+/// let written = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(written, "{\"a\":1,\"b\":\"x\"}\n{\"a\":2,\"b\":\"y\\\"z\"}\n");
+/// ```
+pub struct JsonLinesWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesWriter<W> {
+    /// Create new `JsonLinesWriter`.
+    pub fn new(writer: W) -> Self {
+        JsonLinesWriter { writer }
+    }
+
+    /// Write all rows of `DataFrame`.
+    pub fn write(&mut self, df: &DataFrame) -> io::Result<()> {
+        let columns: Vec<&Array> = df.columns().iter().collect();
+        self.write_rows(df.names(), &columns)
+    }
+
+    /// Write named `Array`s as columns.
+    ///
+    /// # Panics
+    ///
+    /// - if columns have different lengths
+    pub fn write_columns(&mut self, columns: &[(&str, &Array)]) -> io::Result<()> {
+        let names: Vec<String> = columns.iter().map(|c| c.0.to_string()).collect();
+        let arrays: Vec<&Array> = columns.iter().map(|c| c.1).collect();
+        if let Some(first) = arrays.first() {
+            assert!(
+                arrays.iter().all(|a| a.len() == first.len()),
+                "columns must have the same length"
+            );
+        }
+        self.write_rows(&names, &arrays)
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_rows(&mut self, names: &[String], columns: &[&Array]) -> io::Result<()> {
+        // keys are the same in all rows
+        let keys: Vec<String> = names
+            .iter()
+            .map(|n| {
+                let mut key = String::new();
+                json::encode_str(&mut key, n);
+                key
+            })
+            .collect();
+        let len = columns.first().map_or(0, |c| c.len());
+        let mut record = String::new();
+        for i in 0..len {
+            record.clear();
+            record.push('{');
+            for (j, (key, column)) in keys.iter().zip(columns.iter()).enumerate() {
+                if j > 0 {
+                    record.push(',');
+                }
+                record.push_str(key);
+                record.push(':');
+                json::encode_to(&mut record, &unsafe { column.iloc_unchecked(&i) });
+            }
+            record.push_str("}\n");
+            self.writer.write_all(record.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::JsonLinesWriter;
+    use columnar::ListColumn;
+    use frame::DataFrame;
+    use generic::Array;
+    use io::JsonLinesReader;
+    use nullvec::NullVec;
+    use temporal::Date32;
+
+    fn sample() -> DataFrame {
+        DataFrame::new(vec![
+            (
+                "a".to_string(),
+                Array::Int64Array(NullVec::with_mask(vec![1, 0], Some(vec![false, true]))),
+            ),
+            ("b".to_string(), Array::new(vec![0.5, 2.0])),
+            (
+                "l".to_string(),
+                Array::ListArray(ListColumn::from_rows(vec![
+                    Some(Array::new(vec!["x\ty".to_string()])),
+                    None,
+                ])),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_write() {
+        let mut writer = JsonLinesWriter::new(vec![]);
+        writer.write(&sample()).unwrap();
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        let exp = "{\"a\":1,\"b\":0.5,\"l\":[\"x\\ty\"]}\n{\"a\":null,\"b\":2.0,\"l\":null}\n";
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_write_columns() {
+        let d = Array::new(vec![Date32::from_ymd(2017, 3, 1)]);
+        let b = Array::new(vec![f64::NAN]);
+        let mut writer = JsonLinesWriter::new(vec![]);
+        writer.write_columns(&[("d", &d), ("b\"", &b)]).unwrap();
+        let res = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(res, "{\"d\":\"2017-03-01\",\"b\\\"\":null}\n");
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut writer = JsonLinesWriter::new(vec![]);
+        writer.write(&sample()).unwrap();
+        let written = writer.into_inner();
+        let df = JsonLinesReader::new(&written[..]).read().unwrap();
+        assert_eq!(df, sample());
+    }
+}
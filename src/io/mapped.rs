@@ -0,0 +1,725 @@
+//! Read-only columns backed by a memory-mapped column file.
+//!
+//! Columns of fixed-width primitive dtypes are accessed in place: values and the
+//! validity bitmap are decoded from the mapped region on each access, without
+//! reading the whole column into memory. Checksums of arrays are not verified,
+//! as it would require reading them entirely.
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{self, Cursor};
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::path::Path;
+use std::sync::Arc;
+
+use memmap2::Mmap;
+use num_traits::{ToPrimitive, Zero};
+
+use nullable::Nullable;
+use nullvec::NullVec;
+use traits::{BasicAggregation, ComparisonAggregation, NullStorable, NumericAggregation, Slicer};
+
+use super::column_file::read_index;
+use super::native::{self, invalid_data, FORMAT_VERSION};
+
+// magic, version and body length
+const HEADER_LEN: usize = 13;
+// dtype tag, length and validity flag
+const BODY_HEADER_LEN: usize = 10;
+
+/// Fixed-width type which can be read from a mapped column.
+pub trait MappedElement: Clone + NullStorable {
+    /// dtype of columns which store the type.
+    const DTYPE: &'static str;
+    /// Byte width of a value.
+    const WIDTH: usize;
+
+    /// Decode a value from `WIDTH` little-endian bytes.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Returns whether all values in `bytes` can be decoded, checked once when a
+    /// column is mapped.
+    fn is_valid(_bytes: &[u8]) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_mapped_element {
+    ($t:ty, $dtype:expr, $width:expr) => {
+        impl MappedElement for $t {
+            const DTYPE: &'static str = $dtype;
+            const WIDTH: usize = $width;
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut b = [0u8; $width];
+                b.copy_from_slice(bytes);
+                <$t>::from_le_bytes(b)
+            }
+        }
+    };
+}
+
+impl_mapped_element!(i64, "i64", 8);
+impl_mapped_element!(i32, "i32", 4);
+impl_mapped_element!(i16, "i16", 2);
+impl_mapped_element!(i8, "i8", 1);
+impl_mapped_element!(u64, "u64", 8);
+impl_mapped_element!(u32, "u32", 4);
+impl_mapped_element!(u16, "u16", 2);
+impl_mapped_element!(u8, "u8", 1);
+impl_mapped_element!(i128, "i128", 16);
+impl_mapped_element!(u128, "u128", 16);
+impl_mapped_element!(f64, "f64", 8);
+impl_mapped_element!(f32, "f32", 4);
+
+impl MappedElement for isize {
+    const DTYPE: &'static str = "isize";
+    const WIDTH: usize = 8;
+
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        // range is checked by is_valid
+        i64::from_le_slice(bytes) as isize
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes
+            .chunks(Self::WIDTH)
+            .all(|b| isize::try_from(i64::from_le_slice(b)).is_ok())
+    }
+}
+
+impl MappedElement for usize {
+    const DTYPE: &'static str = "usize";
+    const WIDTH: usize = 8;
+
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        // range is checked by is_valid
+        u64::from_le_slice(bytes) as usize
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes
+            .chunks(Self::WIDTH)
+            .all(|b| usize::try_from(u64::from_le_slice(b)).is_ok())
+    }
+}
+
+impl MappedElement for bool {
+    const DTYPE: &'static str = "bool";
+    const WIDTH: usize = 1;
+
+    fn from_le_slice(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.iter().all(|&b| b <= 1)
+    }
+}
+
+/// Column file written by `ColumnFileWriter`, mapped into memory.
+///
+/// The footer index is read on opening, and each column is mapped on demand as
+/// `MappedColumn`. Columns share the mapping, and can outlive the file.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs::File;
+/// use nullvec::prelude::*;
+///
+/// let path = std::env::temp_dir().join("nullvec_mapped_example.nvcf");
+/// let mut writer = ColumnFileWriter::new(File::create(&path).unwrap());
+/// let a = Array::Int64Array(NullVec::with_mask(vec![1, 0, 3], Some(vec![false, true, false])));
+/// writer.write_column("a", &a).unwrap();
+/// writer.finish().unwrap();
+///
+/// let file = unsafe { MappedColumnFile::open(&path).unwrap() };
+/// let column = file.column::<i64>("a").unwrap();
+/// assert_eq!(column.iloc(&1), Null);
+/// assert_eq!(column.sum(), Nullable::Value(4));
+/// assert_eq!(Array::Int64Array(column.to_nullvec()), a);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MappedColumnFile {
+    map: Arc<Mmap>,
+    names: Vec<String>,
+    // offset and byte length of each column
+    locations: Vec<(u64, u64)>,
+}
+
+impl MappedColumnFile {
+    /// Map the column file at `path`, reading the footer index.
+    ///
+    /// Returns an error of `InvalidData` kind if the file is not complete,
+    /// corrupted or written in unsupported version.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it or any of its
+    /// columns is alive, as the mapped region would change underneath.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let map = Mmap::map(&file)?;
+        let (names, locations) = read_index(&mut Cursor::new(&map[..]))?;
+        Ok(MappedColumnFile {
+            map: Arc::new(map),
+            names,
+            locations,
+        })
+    }
+
+    /// Returns column names in the written order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns dtype of the column specified with the name.
+    ///
+    /// Returns an error of `InvalidInput` kind if the column doesn't exist.
+    pub fn dtype(&self, name: &str) -> io::Result<&'static str> {
+        let body = self.body(name)?;
+        let tag = self.map[body] as usize;
+        native::DTYPES
+            .get(tag)
+            .cloned()
+            .ok_or_else(|| invalid_data(format!("unknown dtype tag {}", tag)))
+    }
+
+    /// Map the column specified with the name.
+    ///
+    /// Returns an error of `InvalidInput` kind if the column doesn't exist or
+    /// its dtype is not `T`.
+    pub fn column<T: MappedElement>(&self, name: &str) -> io::Result<MappedColumn<T>> {
+        let dtype = self.dtype(name)?;
+        if dtype != T::DTYPE {
+            let msg = format!("column {} has dtype {}, not {}", name, dtype, T::DTYPE);
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        let body = self.body(name)?;
+        let (_, size) = self.location(name)?;
+        let end = body + size as usize - HEADER_LEN - 4;
+        let mut len = [0u8; 8];
+        len.copy_from_slice(&self.map[body + 1..body + 9]);
+        let len = u64::from_le_bytes(len);
+
+        let mut data = body + BODY_HEADER_LEN;
+        let validity = match self.map[body + 9] {
+            0 => None,
+            1 => {
+                let validity = data;
+                data += len.div_ceil(8) as usize;
+                Some(validity)
+            }
+            _ => return Err(invalid_data("invalid validity flag")),
+        };
+        let size = len.checked_mul(T::WIDTH as u64);
+        if data > end || size.is_none_or(|s| s != (end - data) as u64) {
+            return Err(invalid_data("invalid length"));
+        }
+        if !T::is_valid(&self.map[data..end]) {
+            return Err(invalid_data(format!("invalid {} value", T::DTYPE)));
+        }
+        Ok(MappedColumn {
+            map: self.map.clone(),
+            validity,
+            data,
+            len: len as usize,
+            locations: None,
+            _type: PhantomData,
+        })
+    }
+
+    fn location(&self, name: &str) -> io::Result<(u64, u64)> {
+        match self.names.iter().position(|n| n == name) {
+            Some(i) => Ok(self.locations[i]),
+            None => {
+                let msg = format!("column {} not found", name);
+                Err(io::Error::new(io::ErrorKind::InvalidInput, msg))
+            }
+        }
+    }
+
+    /// Returns the start of the array body, validating its header.
+    fn body(&self, name: &str) -> io::Result<usize> {
+        let (offset, size) = self.location(name)?;
+        let (offset, size) = (offset as usize, size as usize);
+        if size < HEADER_LEN + BODY_HEADER_LEN + 4 {
+            return Err(invalid_data("column is truncated"));
+        }
+        let header = &self.map[offset..offset + HEADER_LEN];
+        if &header[..4] != native::MAGIC {
+            return Err(invalid_data("not a native array"));
+        }
+        if header[4] != FORMAT_VERSION {
+            return Err(invalid_data(format!("unsupported version {}", header[4])));
+        }
+        let mut len = [0u8; 8];
+        len.copy_from_slice(&header[5..]);
+        if u64::from_le_bytes(len) != (size - HEADER_LEN - 4) as u64 {
+            return Err(invalid_data("invalid array length"));
+        }
+        Ok(offset + HEADER_LEN)
+    }
+}
+
+/// Read-only nullable column backed by a memory-mapped column file.
+///
+/// Values are decoded on access, and `Slicer` methods return views which share
+/// the mapping. Use `to_nullvec` to materialize into `NullVec`.
+#[derive(Clone)]
+pub struct MappedColumn<T: MappedElement> {
+    map: Arc<Mmap>,
+    // start of the validity bitmap and the data
+    validity: Option<usize>,
+    data: usize,
+    len: usize,
+    // locations in the mapped column if this is a view, None for null
+    locations: Option<Vec<Option<usize>>>,
+    _type: PhantomData<T>,
+}
+
+impl<T: MappedElement> MappedColumn<T> {
+    /// Returns the value at the location in the mapped column.
+    fn get(&self, location: usize) -> Nullable<T> {
+        if let Some(validity) = self.validity {
+            if self.map[validity + location / 8] & (1 << (location % 8)) == 0 {
+                return Nullable::Null;
+            }
+        }
+        let start = self.data + location * T::WIDTH;
+        Nullable::new(T::from_le_slice(&self.map[start..start + T::WIDTH]))
+    }
+
+    /// Returns the location in the mapped column, `None` for null.
+    fn resolve(&self, location: usize) -> Option<usize> {
+        match self.locations {
+            Some(ref locations) => locations[location],
+            None => Some(location),
+        }
+    }
+
+    fn view(&self, locations: Vec<Option<usize>>) -> Self {
+        MappedColumn {
+            map: self.map.clone(),
+            validity: self.validity,
+            data: self.data,
+            len: self.len,
+            locations: Some(locations),
+            _type: PhantomData,
+        }
+    }
+
+    /// Returns Iterator which iterates values as `Nullable`.
+    pub fn iter(&self) -> MappedColumnIter<'_, T> {
+        MappedColumnIter {
+            column: self,
+            current: 0,
+        }
+    }
+
+    /// Returns values which are not `Null`.
+    fn not_null(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().filter_map(|v| match v {
+            Nullable::Value(v) => Some(v),
+            Nullable::Null => None,
+        })
+    }
+
+    /// Read all values into `NullVec`.
+    pub fn to_nullvec(&self) -> NullVec<T> {
+        self.iter().collect()
+    }
+}
+
+/// Iterator over values of `MappedColumn`.
+pub struct MappedColumnIter<'a, T: MappedElement + 'a> {
+    column: &'a MappedColumn<T>,
+    current: usize,
+}
+
+impl<'a, T: MappedElement> Iterator for MappedColumnIter<'a, T> {
+    type Item = Nullable<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.column.len() {
+            return None;
+        }
+        let value = unsafe { self.column.iloc_unchecked(&self.current) };
+        self.current += 1;
+        Some(value)
+    }
+}
+
+impl<'a, T: MappedElement> IntoIterator for &'a MappedColumn<T> {
+    type Item = Nullable<T>;
+    type IntoIter = MappedColumnIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: MappedElement> Slicer for MappedColumn<T> {
+    type Scalar = Nullable<T>;
+
+    fn len(&self) -> usize {
+        match self.locations {
+            Some(ref locations) => locations.len(),
+            None => self.len,
+        }
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Scalar {
+        assert!(*location < self.len(), "Index out of bounds");
+        unsafe { self.iloc_unchecked(location) }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Self::Scalar {
+        match self.resolve(*location) {
+            Some(location) => self.get(location),
+            None => Nullable::Null,
+        }
+    }
+
+    fn ilocs(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        assert!(locations.iter().all(|&i| i < len), "Index out of bounds");
+        unsafe { self.ilocs_unchecked(locations) }
+    }
+
+    unsafe fn ilocs_unchecked(&self, locations: &[usize]) -> Self {
+        self.view(locations.iter().map(|&i| self.resolve(i)).collect())
+    }
+
+    fn ilocs_forced(&self, locations: &[usize]) -> Self {
+        let len = self.len();
+        self.view(
+            locations
+                .iter()
+                .map(|&i| if i < len { self.resolve(i) } else { None })
+                .collect(),
+        )
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(
+            self.len() == flags.len(),
+            "flags must be the same length as values"
+        );
+        self.view(
+            flags
+                .iter()
+                .enumerate()
+                .filter(|&(_, &f)| f)
+                .map(|(i, _)| self.resolve(i))
+                .collect(),
+        )
+    }
+}
+
+impl<T> BasicAggregation for MappedColumn<T>
+where
+    T: MappedElement + Zero + Add,
+{
+    type Kept = Nullable<T>;
+    type Counted = usize;
+
+    fn sum(&self) -> Self::Kept {
+        if self.len() == 0 {
+            return Nullable::Value(T::zero());
+        }
+        let mut has_value = false;
+        let sum = self.not_null().fold(T::zero(), |a, b| {
+            has_value = true;
+            a + b
+        });
+        if has_value {
+            Nullable::Value(sum)
+        } else {
+            Nullable::Null
+        }
+    }
+
+    fn count(&self) -> Self::Counted {
+        self.not_null().count()
+    }
+}
+
+impl<T> MappedColumn<T>
+where
+    T: MappedElement + ToPrimitive,
+{
+    /// Returns the number of not-null values and the sum of squared deviations,
+    /// computed in two passes over the mapped values.
+    fn sum_sq(&self) -> (usize, f64) {
+        let (count, sum) = self
+            .not_null()
+            .fold((0, 0.), |(n, s), v| (n + 1, s + v.to_f64().unwrap()));
+        let mean = sum / count as f64;
+        let sq = self.not_null().fold(0., |a, v| {
+            let d = v.to_f64().unwrap() - mean;
+            a + d * d
+        });
+        (count, sq)
+    }
+}
+
+impl<T> NumericAggregation for MappedColumn<T>
+where
+    T: MappedElement + Zero + Add + ToPrimitive,
+{
+    type Coerced = Nullable<f64>;
+
+    fn mean(&self) -> Self::Coerced {
+        if self.len() == 0 {
+            return Nullable::Null;
+        }
+        match self.sum() {
+            Nullable::Value(ref val) => {
+                Nullable::Value(val.to_f64().unwrap() / self.count() as f64)
+            }
+            Nullable::Null => Nullable::Null,
+        }
+    }
+
+    fn var(&self) -> Self::Coerced {
+        match self.sum_sq() {
+            (0, _) => Nullable::Null,
+            (n, sq) => Nullable::Value(sq / n as f64),
+        }
+    }
+
+    fn unbiased_var(&self) -> Self::Coerced {
+        match self.sum_sq() {
+            (0, _) => Nullable::Null,
+            (n, sq) => Nullable::Value(sq / (n - 1) as f64),
+        }
+    }
+
+    fn std(&self) -> Self::Coerced {
+        match self.var() {
+            Nullable::Value(val) => Nullable::Value(val.sqrt()),
+            Nullable::Null => Nullable::Null,
+        }
+    }
+
+    fn unbiased_std(&self) -> Self::Coerced {
+        match self.unbiased_var() {
+            Nullable::Value(val) => Nullable::Value(val.sqrt()),
+            Nullable::Null => Nullable::Null,
+        }
+    }
+}
+
+impl<T> ComparisonAggregation for MappedColumn<T>
+where
+    T: MappedElement + PartialOrd,
+{
+    type Kept = Nullable<T>;
+
+    fn min(&self) -> Self::Kept {
+        let mut it = self.not_null();
+        match it.next() {
+            Some(first) => Nullable::Value(it.fold(first, |a, b| if b < a { b } else { a })),
+            None => Nullable::Null,
+        }
+    }
+
+    fn max(&self) -> Self::Kept {
+        let mut it = self.not_null();
+        match it.next() {
+            Some(first) => Nullable::Value(it.fold(first, |a, b| if b > a { b } else { a })),
+            None => Nullable::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::env;
+    use std::fs::{self, File};
+    use std::io;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::{MappedColumnFile, BODY_HEADER_LEN};
+    use generic::Array;
+    use io::ColumnFileWriter;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{BasicAggregation, ComparisonAggregation, NumericAggregation, Slicer};
+
+    /// Write columns to a temporary file, removed on drop.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, columns: &[(&str, &Array)]) -> Self {
+            let path = env::temp_dir().join(format!("nullvec_{}_{}.nvcf", name, process::id()));
+            let mut writer = ColumnFileWriter::new(File::create(&path).unwrap());
+            for &(name, array) in columns.iter() {
+                writer.write_column(name, array).unwrap();
+            }
+            writer.finish().unwrap();
+            TempFile(path)
+        }
+
+        fn open(&self) -> MappedColumnFile {
+            unsafe { MappedColumnFile::open(&self.0).unwrap() }
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn values() -> NullVec<f64> {
+        vec![
+            Nullable::Value(1.5),
+            Nullable::Null,
+            Nullable::Value(-2.),
+            Nullable::Value(4.),
+            Nullable::Null,
+            Nullable::Value(0.5),
+            Nullable::Value(3.),
+            Nullable::Value(8.),
+            Nullable::Value(1.),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_column() {
+        let f = Array::Float64Array(values());
+        let bools: Vec<bool> = (0..9).map(|i| i % 3 == 0).collect();
+        let b = Array::new(bools.clone());
+        let u = Array::new((0..9usize).collect::<Vec<usize>>());
+        let file = TempFile::new("column", &[("f", &f), ("b", &b), ("u", &u)]);
+        let file = file.open();
+        assert_eq!(file.names(), &["f", "b", "u"]);
+        assert_eq!(file.dtype("b").unwrap(), "bool");
+
+        let column = file.column::<f64>("f").unwrap();
+        assert_eq!(column.len(), 9);
+        assert_eq!(column.iloc(&0), Nullable::Value(1.5));
+        assert_eq!(column.iloc(&1), Nullable::Null);
+        assert_eq!(column.to_nullvec(), values());
+        let collected: Vec<Nullable<f64>> = column.iter().collect();
+        assert_eq!(collected, values().into_iter().collect::<Vec<_>>());
+
+        let column = file.column::<bool>("b").unwrap();
+        assert_eq!(column.to_nullvec(), NullVec::new(bools));
+        let column = file.column::<usize>("u").unwrap();
+        assert_eq!(column.iloc(&8), Nullable::Value(8));
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let vals = values();
+        let f = Array::Float64Array(vals.clone());
+        let file = TempFile::new("aggregation", &[("f", &f)]);
+        let column = file.open().column::<f64>("f").unwrap();
+        assert_eq!(column.sum(), vals.sum());
+        assert_eq!(column.count(), vals.count());
+        assert_eq!(column.mean(), vals.mean());
+        assert_eq!(column.var(), vals.var());
+        assert_eq!(column.unbiased_var(), vals.unbiased_var());
+        assert_eq!(column.std(), vals.std());
+        assert_eq!(column.unbiased_std(), vals.unbiased_std());
+        assert_eq!(column.min(), Nullable::Value(-2.));
+        assert_eq!(column.max(), Nullable::Value(8.));
+
+        // all null
+        let view = column.ilocs(&[1, 4]);
+        assert_eq!(view.sum(), Nullable::Null);
+        assert_eq!(view.count(), 0);
+        assert_eq!(view.mean(), Nullable::Null);
+        assert_eq!(view.min(), Nullable::Null);
+        assert_eq!(column.ilocs(&[]).sum(), Nullable::Value(0.));
+    }
+
+    #[test]
+    fn test_slicer() {
+        let i = Array::new(vec![10i32, 11, 12, 13]);
+        let file = TempFile::new("slicer", &[("i", &i)]);
+        let column = file.open().column::<i32>("i").unwrap();
+
+        let view = column.ilocs(&[3, 1, 1]);
+        assert_eq!(view.to_nullvec(), NullVec::new(vec![13, 11, 11]));
+        // views of views
+        let view = view.blocs(&[true, false, true]);
+        assert_eq!(view.to_nullvec(), NullVec::new(vec![13, 11]));
+        let view = column.ilocs_forced(&[0, 5]);
+        let exp: NullVec<i32> = vec![Nullable::Value(10), Nullable::Null]
+            .into_iter()
+            .collect();
+        assert_eq!(view.to_nullvec(), exp);
+        assert_eq!(view.sum(), Nullable::Value(10));
+        assert_eq!(column.reindex(&[2]).to_nullvec(), NullVec::new(vec![12]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_iloc_out_of_bounds() {
+        let i = Array::new(vec![1i8]);
+        let file = TempFile::new("bounds", &[("i", &i)]);
+        let column = file.open().column::<i8>("i").unwrap();
+        column.iloc(&1);
+    }
+
+    #[test]
+    fn test_errors() {
+        let i = Array::new(vec![1i64]);
+        let s = Array::new(vec!["x"]);
+        let file = TempFile::new("errors", &[("i", &i), ("s", &s)]);
+        let file = file.open();
+        let err = file.column::<i32>("i").err().unwrap();
+        assert_eq!(err.to_string(), "column i has dtype i64, not i32");
+        let err = file.column::<i64>("s").err().unwrap();
+        assert_eq!(err.to_string(), "column s has dtype str, not i64");
+        let err = file.column::<i64>("x").err().unwrap();
+        assert_eq!(err.to_string(), "column x not found");
+    }
+
+    #[test]
+    fn test_invalid_bool() {
+        let b = Array::new(vec![true, false]);
+        let temp = TempFile::new("invalid_bool", &[("b", &b)]);
+        let offset = temp.open().body("b").unwrap() + BODY_HEADER_LEN + 1;
+
+        let mut bytes = fs::read(&temp.0).unwrap();
+        assert_eq!(bytes[offset], 0);
+        bytes[offset] = 2;
+        fs::write(&temp.0, &bytes).unwrap();
+
+        let err = temp.open().column::<bool>("b").err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "invalid bool value");
+    }
+
+    #[test]
+    fn test_usize_range() {
+        let u = Array::new(vec![1usize, usize::MAX]);
+        let temp = TempFile::new("usize_range", &[("u", &u)]);
+        let column = temp.open().column::<usize>("u").unwrap();
+        assert_eq!(column.iloc(&1), Nullable::Value(usize::MAX));
+
+        // values out of the range are only possible on 32-bit targets
+        if cfg!(target_pointer_width = "32") {
+            let offset = temp.open().body("u").unwrap() + BODY_HEADER_LEN;
+            let mut bytes = fs::read(&temp.0).unwrap();
+            for b in &mut bytes[offset..offset + 8] {
+                *b = 0xFF;
+            }
+            fs::write(&temp.0, &bytes).unwrap();
+
+            let err = temp.open().column::<usize>("u").err().unwrap();
+            assert_eq!(err.to_string(), "invalid usize value");
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Readers and writers which convert external formats from / to `DataFrame`.
+
+#[cfg(feature = "arrow-ipc")]
+mod arrow;
+mod column_file;
+mod csv;
+mod csv_writer;
+#[cfg(feature = "arrow-ipc")]
+mod flatbuf;
+mod json;
+mod jsonl;
+mod jsonl_writer;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod native;
+
+#[cfg(feature = "arrow-ipc")]
+pub use self::arrow::{ArrowFileReader, ArrowFileWriter, ArrowStreamReader, ArrowStreamWriter};
+pub use self::column_file::{ColumnFileReader, ColumnFileWriter};
+pub use self::csv::{CsvChunks, CsvReader};
+pub use self::csv_writer::{CsvWriter, QuoteStyle};
+pub use self::jsonl::JsonLinesReader;
+pub use self::jsonl_writer::JsonLinesWriter;
+#[cfg(feature = "mmap")]
+pub use self::mapped::{MappedColumn, MappedColumnFile, MappedColumnIter, MappedElement};
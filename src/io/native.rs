@@ -0,0 +1,828 @@
+//! Native binary format of `Array`.
+//!
+//! An encoded `Array` consists of a header and a checksummed body:
+//!
+//! ```text
+//! magic "NVAR" | version: u8 | body length: u64 | body | CRC-32 of body: u32
+//! ```
+//!
+//! The body is `dtype tag: u8 | length: u64 | validity | data`, where validity is
+//! a flag byte followed by a bitmap (bit set means valid, LSB first) if the flag
+//! is 1. All integers are little-endian. Data depends on dtype:
+//!
+//! - fixed-width types: values in order, including those under `Null`. `isize` and
+//!   `usize` are stored in 8 bytes, `bool` in 1 byte and `char` as `u32`.
+//! - `date32`: days as `i32`, `timestamp`: `i64` value, `u8` unit, `u8` offset flag
+//!   and `i32` offset, `duration`: `i64` value and `u8` unit, `decimal`: `i128`
+//!   mantissa, `u8` precision and `u8` scale
+//! - `str`, `utf8` and `binary`: `length + 1` offsets as `u64`, followed by the
+//!   byte length and the bytes
+//! - `category`: `u8` ordered flag, the number of categories as `u64`, categories
+//!   as `str` data without validity, then codes as `u32` with their own validity
+//!   (the outer validity flag is always 0)
+//! - `list`: `length + 1` offsets as `u64`, followed by the child body
+//! - `struct`: the number of fields as `u64`, then name as `str` data and child
+//!   body of each field
+
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+use std::str;
+
+use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+use decimal::{Decimal, MAX_PRECISION};
+use generic::Array;
+use nullvec::NullVec;
+use temporal::{Date32, Duration, TimeUnit, Timestamp};
+use traits::{NullStorable, Slicer};
+
+pub const MAGIC: &[u8; 4] = b"NVAR";
+
+/// Current version of the native format.
+pub const FORMAT_VERSION: u8 = 1;
+
+// tag is the location in this list
+pub const DTYPES: [&str; 26] = [
+    "i64",
+    "i32",
+    "i16",
+    "i8",
+    "isize",
+    "u64",
+    "u32",
+    "u16",
+    "u8",
+    "usize",
+    "i128",
+    "u128",
+    "f64",
+    "f32",
+    "bool",
+    "char",
+    "str",
+    "utf8",
+    "binary",
+    "category",
+    "date32",
+    "timestamp",
+    "duration",
+    "decimal",
+    "list",
+    "struct",
+];
+
+const UNITS: [TimeUnit; 4] = [
+    TimeUnit::Second,
+    TimeUnit::Millisecond,
+    TimeUnit::Microsecond,
+    TimeUnit::Nanosecond,
+];
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+/// Returns CRC-32 (IEEE) checksum of `bytes`.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc = CRC_TABLE[((crc ^ u32::from(b)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+pub fn invalid_data<S: Into<String>>(msg: S) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+impl Array {
+    /// Write `Array` in the native binary format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    ///
+    /// let a = Array::Int64Array(NullVec::with_mask(vec![1, 0], Some(vec![false, true])));
+    /// let mut buf: Vec<u8> = vec![];
+    /// a.write_to(&mut buf).unwrap();
+    /// assert_eq!(Array::read_from(&mut &buf[..]).unwrap(), a);
+    /// ```
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut body: Vec<u8> = vec![];
+        encode(self, &mut body);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[FORMAT_VERSION])?;
+        writer.write_all(&(body.len() as u64).to_le_bytes())?;
+        writer.write_all(&body)?;
+        writer.write_all(&crc32(&body).to_le_bytes())
+    }
+
+    /// Read `Array` written by `write_to`.
+    ///
+    /// Returns an error of `InvalidData` kind if input is corrupted or written in
+    /// unsupported version.
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Array> {
+        let mut header = [0u8; 13];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(invalid_data("not a native array"));
+        }
+        if header[4] != FORMAT_VERSION {
+            return Err(invalid_data(format!("unsupported version {}", header[4])));
+        }
+        let mut len = [0u8; 8];
+        len.copy_from_slice(&header[5..]);
+        let len = u64::from_le_bytes(len);
+        let mut body: Vec<u8> = vec![];
+        reader.by_ref().take(len).read_to_end(&mut body)?;
+        if (body.len() as u64) < len {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "truncated array",
+            ));
+        }
+        let mut checksum = [0u8; 4];
+        reader.read_exact(&mut checksum)?;
+        if u32::from_le_bytes(checksum) != crc32(&body) {
+            return Err(invalid_data("checksum mismatch"));
+        }
+        let mut decoder = Decoder { buf: &body, pos: 0 };
+        let array = decoder.array()?;
+        if decoder.pos != body.len() {
+            return Err(invalid_data("trailing bytes in array"));
+        }
+        Ok(array)
+    }
+}
+
+// Encoding
+
+/// Fixed-width element of the data buffer.
+pub trait Element: Sized {
+    const WIDTH: usize;
+
+    fn put(&self, buf: &mut Vec<u8>);
+
+    /// Decode from `WIDTH` bytes, `None` if they don't represent a valid value.
+    fn get(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_element {
+    ($t:ty, $width:expr) => {
+        impl Element for $t {
+            const WIDTH: usize = $width;
+
+            fn put(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn get(bytes: &[u8]) -> Option<Self> {
+                let mut b = [0u8; $width];
+                b.copy_from_slice(bytes);
+                Some(<$t>::from_le_bytes(b))
+            }
+        }
+    };
+}
+
+impl_element!(i64, 8);
+impl_element!(i32, 4);
+impl_element!(i16, 2);
+impl_element!(i8, 1);
+impl_element!(u64, 8);
+impl_element!(u32, 4);
+impl_element!(u16, 2);
+impl_element!(u8, 1);
+impl_element!(i128, 16);
+impl_element!(u128, 16);
+impl_element!(f64, 8);
+impl_element!(f32, 4);
+
+impl Element for isize {
+    const WIDTH: usize = 8;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        (*self as i64).put(buf);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        i64::get(bytes).and_then(|v| isize::try_from(v).ok())
+    }
+}
+
+impl Element for usize {
+    const WIDTH: usize = 8;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        (*self as u64).put(buf);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        u64::get(bytes).and_then(|v| usize::try_from(v).ok())
+    }
+}
+
+impl Element for bool {
+    const WIDTH: usize = 1;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        match bytes[0] {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Element for char {
+    const WIDTH: usize = 4;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        (*self as u32).put(buf);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        u32::get(bytes).and_then(::std::char::from_u32)
+    }
+}
+
+impl Element for Date32 {
+    const WIDTH: usize = 4;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        self.days().put(buf);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        i32::get(bytes).map(Date32::from_days)
+    }
+}
+
+fn unit_tag(unit: TimeUnit) -> u8 {
+    UNITS.iter().position(|&u| u == unit).unwrap() as u8
+}
+
+impl Element for Timestamp {
+    const WIDTH: usize = 14;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        self.value().put(buf);
+        buf.push(unit_tag(self.unit()));
+        buf.push(self.offset().is_some() as u8);
+        self.offset().unwrap_or(0).put(buf);
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        let unit = *UNITS.get(bytes[8] as usize)?;
+        let t = Timestamp::new(i64::get(&bytes[..8])?, unit);
+        let offset = i32::get(&bytes[10..])?;
+        match bytes[9] {
+            0 => Some(t),
+            1 if i64::from(offset).abs() < 86_400 => Some(t.with_offset(offset)),
+            _ => None,
+        }
+    }
+}
+
+impl Element for Duration {
+    const WIDTH: usize = 9;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        self.value().put(buf);
+        buf.push(unit_tag(self.unit()));
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        let unit = *UNITS.get(bytes[8] as usize)?;
+        Some(Duration::new(i64::get(&bytes[..8])?, unit))
+    }
+}
+
+impl Element for Decimal {
+    const WIDTH: usize = 18;
+
+    fn put(&self, buf: &mut Vec<u8>) {
+        self.mantissa().put(buf);
+        buf.push(self.precision());
+        buf.push(self.scale());
+    }
+
+    fn get(bytes: &[u8]) -> Option<Self> {
+        let mantissa = i128::get(&bytes[..16])?;
+        let (precision, scale) = (bytes[16], bytes[17]);
+        let digits = mantissa
+            .unsigned_abs()
+            .checked_ilog10()
+            .map_or(1, |d| d + 1);
+        if precision == 0 || precision > MAX_PRECISION || scale > precision {
+            return None;
+        }
+        if digits > u32::from(precision) {
+            return None;
+        }
+        Some(Decimal::new(mantissa, precision, scale))
+    }
+}
+
+fn put_u64(buf: &mut Vec<u8>, value: usize) {
+    (value as u64).put(buf);
+}
+
+fn put_validity(buf: &mut Vec<u8>, mask: Option<&[bool]>) {
+    match mask {
+        Some(mask) => {
+            buf.push(1);
+            let mut bitmap = vec![0u8; mask.len().div_ceil(8)];
+            for (i, &null) in mask.iter().enumerate() {
+                if !null {
+                    bitmap[i / 8] |= 1 << (i % 8);
+                }
+            }
+            buf.extend_from_slice(&bitmap);
+        }
+        None => buf.push(0),
+    }
+}
+
+fn put_nullvec<T: Element + Clone + NullStorable>(buf: &mut Vec<u8>, values: &NullVec<T>) {
+    if values.has_null() {
+        put_validity(buf, Some(&values.is_null()));
+    } else {
+        put_validity(buf, None);
+    }
+    for (_, v) in values.iter_raw() {
+        v.put(buf);
+    }
+}
+
+fn put_buffers(buf: &mut Vec<u8>, offsets: &[u64], values: &[u8]) {
+    for o in offsets.iter() {
+        o.put(buf);
+    }
+    put_u64(buf, values.len());
+    buf.extend_from_slice(values);
+}
+
+fn put_strings<'a, I: Iterator<Item = &'a str>>(buf: &mut Vec<u8>, values: I) {
+    let mut offsets: Vec<u64> = vec![0];
+    let mut bytes: Vec<u8> = vec![];
+    for v in values {
+        bytes.extend_from_slice(v.as_bytes());
+        offsets.push(bytes.len() as u64);
+    }
+    put_buffers(buf, &offsets, &bytes);
+}
+
+/// Encode `Array` as body.
+fn encode(array: &Array, buf: &mut Vec<u8>) {
+    let dtype = array.dtype();
+    buf.push(DTYPES.iter().position(|&d| d == dtype).unwrap() as u8);
+    put_u64(buf, array.len());
+    match *array {
+        Array::Int64Array(ref vals) => put_nullvec(buf, vals),
+        Array::Int32Array(ref vals) => put_nullvec(buf, vals),
+        Array::Int16Array(ref vals) => put_nullvec(buf, vals),
+        Array::Int8Array(ref vals) => put_nullvec(buf, vals),
+        Array::IsizeArray(ref vals) => put_nullvec(buf, vals),
+        Array::UInt64Array(ref vals) => put_nullvec(buf, vals),
+        Array::UInt32Array(ref vals) => put_nullvec(buf, vals),
+        Array::UInt16Array(ref vals) => put_nullvec(buf, vals),
+        Array::UInt8Array(ref vals) => put_nullvec(buf, vals),
+        Array::UsizeArray(ref vals) => put_nullvec(buf, vals),
+        Array::Int128Array(ref vals) => put_nullvec(buf, vals),
+        Array::UInt128Array(ref vals) => put_nullvec(buf, vals),
+        Array::Float64Array(ref vals) => put_nullvec(buf, vals),
+        Array::Float32Array(ref vals) => put_nullvec(buf, vals),
+        Array::BoolArray(ref vals) => put_nullvec(buf, vals),
+        Array::CharArray(ref vals) => put_nullvec(buf, vals),
+        Array::StringArray(ref vals) => {
+            if vals.has_null() {
+                put_validity(buf, Some(&vals.is_null()));
+            } else {
+                put_validity(buf, None);
+            }
+            put_strings(buf, vals.iter_raw().map(|(_, v)| v.as_str()));
+        }
+        Array::Utf8Array(ref vals) => {
+            put_validity(buf, vals.mask());
+            put_buffers(buf, vals.offsets(), vals.values_buffer());
+        }
+        Array::BinaryArray(ref vals) => {
+            put_validity(buf, vals.mask());
+            put_buffers(buf, vals.offsets(), vals.values_buffer());
+        }
+        Array::CategoricalArray(ref vals) => {
+            put_validity(buf, None);
+            buf.push(vals.is_ordered() as u8);
+            put_u64(buf, vals.categories().len());
+            put_strings(buf, vals.categories().iter().map(|c| c.as_str()));
+            put_nullvec(buf, vals.codes());
+        }
+        Array::Date32Array(ref vals) => put_nullvec(buf, vals),
+        Array::TimestampArray(ref vals) => put_nullvec(buf, vals),
+        Array::DurationArray(ref vals) => put_nullvec(buf, vals),
+        Array::DecimalArray(ref vals) => put_nullvec(buf, vals),
+        Array::ListArray(ref vals) => {
+            put_validity(buf, vals.mask());
+            for o in vals.offsets().iter() {
+                o.put(buf);
+            }
+            encode(vals.values(), buf);
+        }
+        Array::StructArray(ref vals) => {
+            put_validity(buf, vals.mask());
+            put_u64(buf, vals.names().len());
+            for (name, field) in vals.names().iter().zip(vals.fields().iter()) {
+                put_strings(buf, Some(name.as_str()).into_iter());
+                encode(field, buf);
+            }
+        }
+    }
+}
+
+// Decoding
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn bytes(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.buf.len() - self.pos < len {
+            return Err(invalid_data("unexpected end of array"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::get(self.bytes(8)?).unwrap())
+    }
+
+    /// Read length, which must not exceed the remaining bytes when multiplied
+    /// by `width`, to avoid allocating for corrupted length.
+    fn len(&mut self, width: usize) -> io::Result<usize> {
+        let len = self.u64()?;
+        let remaining = (self.buf.len() - self.pos) as u64;
+        match len.checked_mul(width as u64) {
+            Some(size) if size <= remaining => Ok(len as usize),
+            _ => Err(invalid_data("invalid length")),
+        }
+    }
+
+    fn validity(&mut self, len: usize) -> io::Result<Option<Vec<bool>>> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => {
+                let bitmap = self.bytes(len.div_ceil(8))?;
+                Ok(Some(
+                    (0..len)
+                        .map(|i| bitmap[i / 8] & (1 << (i % 8)) == 0)
+                        .collect(),
+                ))
+            }
+            _ => Err(invalid_data("invalid validity flag")),
+        }
+    }
+
+    fn values<T: Element>(&mut self, len: usize) -> io::Result<Vec<T>> {
+        let bytes = self.bytes(len.saturating_mul(T::WIDTH))?;
+        bytes
+            .chunks(T::WIDTH)
+            .map(|b| T::get(b).ok_or_else(|| invalid_data("invalid value")))
+            .collect()
+    }
+
+    fn nullvec<T: Element + NullStorable>(&mut self, len: usize) -> io::Result<NullVec<T>> {
+        let mask = self.validity(len)?;
+        let values = self.values(len)?;
+        Ok(NullVec::with_mask(values, mask))
+    }
+
+    /// Read offsets and bytes, validating offsets.
+    fn buffers(&mut self, len: usize) -> io::Result<(Vec<u64>, Vec<u8>)> {
+        let offsets: Vec<u64> = self.values(len + 1)?;
+        let size = self.len(1)?;
+        let values = self.bytes(size)?.to_vec();
+        check_offsets(&offsets, size)?;
+        Ok((offsets, values))
+    }
+
+    fn strings(&mut self, len: usize) -> io::Result<Vec<String>> {
+        let (offsets, values) = self.buffers(len)?;
+        offsets
+            .windows(2)
+            .map(|w| {
+                str::from_utf8(&values[w[0] as usize..w[1] as usize])
+                    .map(|s| s.to_string())
+                    .map_err(|_| invalid_data("invalid UTF-8"))
+            })
+            .collect()
+    }
+
+    fn array(&mut self) -> io::Result<Array> {
+        let tag = self.u8()?;
+        let dtype = *DTYPES
+            .get(tag as usize)
+            .ok_or_else(|| invalid_data(format!("unknown dtype tag {}", tag)))?;
+        // every value takes at least a byte except struct
+        let len = match dtype {
+            "struct" => self.len(0)?,
+            _ => self.len(1)?,
+        };
+        let array = match dtype {
+            "i64" => Array::Int64Array(self.nullvec(len)?),
+            "i32" => Array::Int32Array(self.nullvec(len)?),
+            "i16" => Array::Int16Array(self.nullvec(len)?),
+            "i8" => Array::Int8Array(self.nullvec(len)?),
+            "isize" => Array::IsizeArray(self.nullvec(len)?),
+            "u64" => Array::UInt64Array(self.nullvec(len)?),
+            "u32" => Array::UInt32Array(self.nullvec(len)?),
+            "u16" => Array::UInt16Array(self.nullvec(len)?),
+            "u8" => Array::UInt8Array(self.nullvec(len)?),
+            "usize" => Array::UsizeArray(self.nullvec(len)?),
+            "i128" => Array::Int128Array(self.nullvec(len)?),
+            "u128" => Array::UInt128Array(self.nullvec(len)?),
+            "f64" => Array::Float64Array(self.nullvec(len)?),
+            "f32" => Array::Float32Array(self.nullvec(len)?),
+            "bool" => Array::BoolArray(self.nullvec(len)?),
+            "char" => Array::CharArray(self.nullvec(len)?),
+            "str" => {
+                let mask = self.validity(len)?;
+                Array::StringArray(NullVec::with_mask(self.strings(len)?, mask))
+            }
+            "utf8" => {
+                let mask = self.validity(len)?;
+                let (offsets, values) = self.buffers(len)?;
+                let valid = str::from_utf8(&values)
+                    .is_ok_and(|s| offsets.iter().all(|&o| s.is_char_boundary(o as usize)));
+                if !valid {
+                    return Err(invalid_data("invalid UTF-8"));
+                }
+                let values = Utf8Column::from_buffers(values, offsets, mask);
+                Array::Utf8Array(values)
+            }
+            "binary" => {
+                let mask = self.validity(len)?;
+                let (offsets, values) = self.buffers(len)?;
+                Array::BinaryArray(BinaryColumn::from_buffers(values, offsets, mask))
+            }
+            "category" => {
+                self.validity(len)?;
+                let ordered = self.u8()? == 1;
+                let n = self.len(8)?;
+                let categories = self.strings(n)?;
+                let codes: NullVec<u32> = self.nullvec(len)?;
+                let mut sorted = categories.clone();
+                sorted.sort();
+                sorted.dedup();
+                let valid = sorted.len() == categories.len()
+                    && codes.iter_raw().all(|(m, &c)| m || (c as usize) < n);
+                if !valid {
+                    return Err(invalid_data("invalid categories or codes"));
+                }
+                Array::CategoricalArray(Categorical::from_codes(codes, categories, ordered))
+            }
+            "date32" => Array::Date32Array(self.nullvec(len)?),
+            "timestamp" => Array::TimestampArray(self.nullvec(len)?),
+            "duration" => Array::DurationArray(self.nullvec(len)?),
+            "decimal" => Array::DecimalArray(self.nullvec(len)?),
+            "list" => {
+                let mask = self.validity(len)?;
+                let offsets: Vec<u64> = self.values(len + 1)?;
+                let child = self.array()?;
+                check_offsets(&offsets, child.len())?;
+                Array::ListArray(ListColumn::new(child, offsets, mask))
+            }
+            _ => {
+                let mask = self.validity(len)?;
+                let n = self.len(1)?;
+                let mut fields: Vec<(String, Array)> = Vec::with_capacity(n);
+                for _ in 0..n {
+                    let name = self.strings(1)?.pop().unwrap();
+                    let field = self.array()?;
+                    if field.len() != len || fields.iter().any(|f| f.0 == name) {
+                        return Err(invalid_data("invalid struct field"));
+                    }
+                    fields.push((name, field));
+                }
+                if fields.is_empty() {
+                    return Err(invalid_data("struct must have at least one field"));
+                }
+                Array::StructArray(StructColumn::with_mask(fields, mask))
+            }
+        };
+        Ok(array)
+    }
+}
+
+pub fn check_offsets(offsets: &[u64], size: usize) -> io::Result<()> {
+    let valid = offsets.first() == Some(&0)
+        && offsets.windows(2).all(|w| w[0] <= w[1])
+        && offsets.last().is_some_and(|&o| o as usize <= size);
+    if valid {
+        Ok(())
+    } else {
+        Err(invalid_data("invalid offsets"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::crc32;
+    use columnar::{BinaryColumn, Categorical, ListColumn, StructColumn, Utf8Column};
+    use decimal::Decimal;
+    use generic::Array;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use temporal::{Date32, Duration, TimeUnit, Timestamp};
+    use traits::NullStorable;
+
+    // the second element is Null
+    fn masked<T: NullStorable>(values: Vec<T>) -> NullVec<T> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i == 1 {
+                    Nullable::Null
+                } else {
+                    Nullable::new(v)
+                }
+            })
+            .collect()
+    }
+
+    fn roundtrip(array: Array) {
+        let mut buf: Vec<u8> = vec![];
+        array.write_to(&mut buf).unwrap();
+        let res = Array::read_from(&mut &buf[..]).unwrap();
+        assert_eq!(res, array);
+        assert_eq!(res.dtype(), array.dtype());
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_roundtrip_primitive() {
+        roundtrip(Array::Int64Array(masked(vec![1, 2, i64::MIN])));
+        roundtrip(Array::Int32Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int16Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int8Array(masked(vec![1, 2, -3])));
+        roundtrip(Array::IsizeArray(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt64Array(NullVec::new(vec![1, 2, u64::MAX])));
+        roundtrip(Array::UInt32Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt16Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UInt8Array(masked(vec![1, 2, 3])));
+        roundtrip(Array::UsizeArray(masked(vec![1, 2, 3])));
+        roundtrip(Array::Int128Array(masked(vec![1, 2, i128::MIN])));
+        roundtrip(Array::UInt128Array(masked(vec![1, 2, u128::MAX])));
+        roundtrip(Array::Float64Array(masked(vec![1.5, 2., -0.1])));
+        roundtrip(Array::Float32Array(masked(vec![1.5, 2., 0.1])));
+        roundtrip(Array::BoolArray(masked(
+            (0..17).map(|i| i % 3 == 0).collect(),
+        )));
+        roundtrip(Array::CharArray(masked(vec!['a', 'b', '\u{1f600}'])));
+        roundtrip(Array::Int64Array(NullVec::new(vec![])));
+    }
+
+    #[test]
+    fn test_roundtrip_string() {
+        let values = vec!["a".to_string(), "bc".to_string(), "".to_string()];
+        roundtrip(Array::StringArray(masked(values.clone())));
+        roundtrip(Array::Utf8Array(Utf8Column::with_mask(
+            values.clone(),
+            Some(vec![false, true, false]),
+        )));
+        roundtrip(Array::BinaryArray(BinaryColumn::new(vec![
+            vec![0, 255],
+            vec![],
+        ])));
+        let c = Categorical::with_categories(
+            &masked(values),
+            vec!["bc".to_string(), "a".to_string()],
+            true,
+        );
+        roundtrip(Array::CategoricalArray(c));
+    }
+
+    #[test]
+    fn test_roundtrip_temporal() {
+        roundtrip(Array::Date32Array(masked(vec![
+            Date32::from_ymd(2017, 3, 1),
+            Date32::from_ymd(1960, 1, 1),
+        ])));
+        let t = Timestamp::new(-1, TimeUnit::Microsecond).with_offset(-3_600);
+        let ts = vec![Timestamp::new(1_500, TimeUnit::Millisecond), t, t];
+        roundtrip(Array::TimestampArray(NullVec::new(ts.clone())));
+        let res = {
+            let mut buf: Vec<u8> = vec![];
+            Array::TimestampArray(NullVec::new(ts))
+                .write_to(&mut buf)
+                .unwrap();
+            Array::read_from(&mut &buf[..]).unwrap()
+        };
+        match res {
+            Array::TimestampArray(ref vals) => {
+                let (_, v) = vals.iter_raw().nth(1).unwrap();
+                assert_eq!(
+                    (v.unit(), v.offset()),
+                    (TimeUnit::Microsecond, Some(-3_600))
+                );
+            }
+            _ => panic!("unexpected dtype"),
+        }
+        roundtrip(Array::DurationArray(masked(vec![
+            Duration::new(90, TimeUnit::Second),
+            Duration::new(0, TimeUnit::Nanosecond),
+        ])));
+        roundtrip(Array::DecimalArray(masked(vec![
+            Decimal::new(-12345, 5, 2),
+            Decimal::new(0, 1, 0),
+        ])));
+    }
+
+    #[test]
+    fn test_roundtrip_nested() {
+        let list = ListColumn::from_rows(vec![
+            Some(Array::new(vec![1i64, 2])),
+            None,
+            Some(Array::new(Vec::<i64>::new())),
+        ]);
+        roundtrip(Array::ListArray(list.clone()));
+        let s = StructColumn::with_mask(
+            vec![
+                ("b".to_string(), Array::ListArray(list)),
+                ("a".to_string(), Array::new(vec![1.5, 2., 3.])),
+            ],
+            Some(vec![true, false, false]),
+        );
+        roundtrip(Array::StructArray(s));
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let a = Array::new(vec!["x".to_string(), "yz".to_string()]);
+        let mut buf: Vec<u8> = vec![];
+        a.write_to(&mut buf).unwrap();
+
+        let mut corrupted = buf.clone();
+        corrupted[20] ^= 1;
+        let err = Array::read_from(&mut &corrupted[..]).unwrap_err();
+        assert_eq!(err.to_string(), "checksum mismatch");
+
+        let mut corrupted = buf.clone();
+        corrupted[4] = 2;
+        let err = Array::read_from(&mut &corrupted[..]).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported version 2");
+
+        assert!(Array::read_from(&mut &buf[..buf.len() - 1]).is_err());
+        assert!(Array::read_from(&mut &b"NVCF"[..]).is_err());
+    }
+
+    #[test]
+    fn test_consecutive() {
+        let a = Array::new(vec![1i64, 2]);
+        let b = Array::new(vec![true]);
+        let mut buf: Vec<u8> = vec![];
+        a.write_to(&mut buf).unwrap();
+        b.write_to(&mut buf).unwrap();
+        let mut reader = &buf[..];
+        assert_eq!(Array::read_from(&mut reader).unwrap(), a);
+        assert_eq!(Array::read_from(&mut reader).unwrap(), b);
+        assert!(reader.is_empty());
+    }
+}
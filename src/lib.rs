@@ -1,4 +1,12 @@
 extern crate num_traits;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 
 // macro must be defined first to be usable in other modules
 #[macro_use]
@@ -10,6 +18,16 @@ mod nullvec;
 mod nullable;
 // Generic types
 mod generic;
+// Columnar storages
+mod columnar;
+// Date and time types
+mod temporal;
+// Fixed-point decimal type
+mod decimal;
+// Two-dimensional table
+mod frame;
+// Readers and writers
+mod io;
 
 // common
 mod algos;
@@ -13,6 +13,8 @@ use std::f32;
 
 mod nullable_ops_nullable;
 mod nullable_ops_primitive;
+#[cfg(feature = "serde")]
+mod nullable_impl_serde;
 use traits::NullStorable;
 
 /// Nullable Scalar
@@ -34,8 +36,12 @@ impl NullStorable for u32 {}
 impl NullStorable for u16 {}
 impl NullStorable for u8 {}
 impl NullStorable for usize {}
+impl NullStorable for i128 {}
+impl NullStorable for u128 {}
 impl NullStorable for bool {}
+impl NullStorable for char {}
 impl NullStorable for String {}
+impl NullStorable for Vec<u8> {}
 impl NullStorable for f64 {
     fn has_primitive_null() -> bool {
         true
@@ -122,7 +128,10 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     bool,
+    char,
     String
 );
 
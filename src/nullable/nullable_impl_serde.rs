@@ -0,0 +1,40 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Nullable;
+use traits::NullStorable;
+
+/// Serialized as an optional value, `Null` corresponds to `None` (JSON `null`).
+impl<T: NullStorable + Serialize> Serialize for Nullable<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Nullable::Value(ref v) => serializer.serialize_some(v),
+            Nullable::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Float `NAN` is deserialized as `Null`, the same as `Nullable::new`.
+impl<'de, T: NullStorable + Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Option::<T>::deserialize(deserializer)?;
+        Ok(value.map_or(Nullable::Null, Nullable::new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullable::Nullable;
+    use serde_json;
+
+    #[test]
+    fn test_nullable_roundtrip() {
+        let v = Nullable::new(3i64);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "3");
+        assert_eq!(serde_json::from_str::<Nullable<i64>>("3").unwrap(), v);
+
+        let n: Nullable<String> = Nullable::Null;
+        assert_eq!(serde_json::to_string(&n).unwrap(), "null");
+        assert_eq!(serde_json::from_str::<Nullable<String>>("null").unwrap(), n);
+    }
+}
@@ -68,6 +68,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     f64,
     f32
 );
@@ -91,6 +93,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     bool
 );
 
@@ -17,7 +17,16 @@
 mod nullvec_convert;
 mod nullvec_impl;
 mod nullvec_impl_aggregation;
+mod nullvec_impl_cum;
+mod nullvec_impl_decimal;
+mod nullvec_impl_dt;
 mod nullvec_impl_iter;
+mod nullvec_impl_str;
+#[cfg(feature = "regex")]
+mod nullvec_impl_regex;
+#[cfg(feature = "serde")]
+mod nullvec_impl_serde;
+mod nullvec_impl_window;
 
 // broadcast op
 mod nullvec_ops_primitive;
@@ -26,6 +35,7 @@ mod nullvec_ops_nullable;
 // elemwise op
 mod nullvec_ops_vec;
 mod nullvec_ops_nullvec;
+mod nullvec_ops_temporal;
 
 // scalar compat
 mod nullvec_scalar;
@@ -33,6 +43,11 @@ mod nullvec_scalar;
 use algos::vec_ops::Elemwise;
 use traits::NullStorable;
 
+pub use self::nullvec_impl::{Interpolation, NullCondition, Branch};
+pub use self::nullvec_impl_dt::DatetimeMethods;
+pub use self::nullvec_impl_str::{StringMethods, PadSide};
+pub use self::nullvec_impl_window::{Expanding, Ewm, EwmDecay};
+
 /// Nullable Vector
 #[derive(Clone, Debug, PartialEq)]
 pub struct NullVec<T: NullStorable> {
@@ -1,5 +1,7 @@
 use super::NullVec;
+use decimal::Decimal;
 use nullable::Nullable;
+use temporal::{Date32, Duration, Timestamp};
 use traits::NullStorable;
 
 impl<T: NullStorable> From<Vec<T>> for NullVec<T> {
@@ -96,10 +98,17 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     f64,
     f32,
     bool,
-    String
+    char,
+    String,
+    Date32,
+    Timestamp,
+    Duration,
+    Decimal
 );
 
 
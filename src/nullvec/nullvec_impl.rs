@@ -1,4 +1,6 @@
 
+use num_traits::ToPrimitive;
+
 use algos::indexing::Indexing;
 
 use super::NullVec;
@@ -141,6 +143,429 @@ impl<T: Clone + NullStorable> NullVec<T> {
             &None => self.clone(),
         }
     }
+
+    /// Returns `NullVec<T>` filling `Null` with the last non-null value.
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - Maximum number of consecutive `Null` to fill. If `None`, no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![false, true, true, false]));
+    /// assert_eq!(v.ffill(None), NullVec::new(vec![1, 1, 1, 4]));
+    /// assert_eq!(v.ffill(Some(1)),
+    ///            NullVec::with_mask(vec![1, 1, 0, 4], Some(vec![false, false, true, false])));
+    /// ```
+    pub fn ffill(&self, limit: Option<usize>) -> Self {
+        let (new_data, new_mask) = self.fill_directional(limit, self.iter_raw());
+        Self::from_filled(new_data, new_mask)
+    }
+
+    /// Returns `NullVec<T>` filling `Null` with the next non-null value.
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - Maximum number of consecutive `Null` to fill. If `None`, no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![false, true, true, false]));
+    /// assert_eq!(v.bfill(None), NullVec::new(vec![1, 4, 4, 4]));
+    /// ```
+    pub fn bfill(&self, limit: Option<usize>) -> Self {
+        let raw: Vec<(bool, &T)> = self.iter_raw().collect();
+        let (mut new_data, mut new_mask) = self.fill_directional(limit, raw.into_iter().rev());
+        new_data.reverse();
+        new_mask.reverse();
+        Self::from_filled(new_data, new_mask)
+    }
+
+    /// Fill `Null` propagating the last non-null value in the order of `iter`
+    fn fill_directional<'a, I>(&'a self, limit: Option<usize>, iter: I) -> (Vec<T>, Vec<bool>)
+    where
+        I: Iterator<Item = (bool, &'a T)>,
+    {
+        let mut new_data: Vec<T> = Vec::with_capacity(self.len());
+        let mut new_mask: Vec<bool> = Vec::with_capacity(self.len());
+        let mut last: Option<&T> = None;
+        let mut consecutive = 0usize;
+
+        for (m, v) in iter {
+            if !m {
+                last = Some(v);
+                consecutive = 0;
+                new_data.push(v.clone());
+                new_mask.push(false);
+                continue;
+            }
+            consecutive += 1;
+            let within_limit = match limit {
+                Some(l) => consecutive <= l,
+                None => true,
+            };
+            match last {
+                Some(l) if within_limit => {
+                    new_data.push(l.clone());
+                    new_mask.push(false);
+                }
+                _ => {
+                    new_data.push(T::default());
+                    new_mask.push(true);
+                }
+            }
+        }
+        (new_data, new_mask)
+    }
+
+    /// Create `NullVec<T>` from filled values, removing mask if all values are filled
+    fn from_filled(data: Vec<T>, mask: Vec<bool>) -> Self {
+        if mask.iter().any(|&m| m) {
+            NullVec {
+                data,
+                mask: Some(mask),
+            }
+        } else {
+            NullVec { data, mask: None }
+        }
+    }
+
+    /// Returns `NullVec<T>` filling `Null` with the corresponding value of `other`.
+    ///
+    /// The result is `Null` only if both values are `Null`.
+    ///
+    /// # Panics
+    ///
+    /// - if `other` has different length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, true]));
+    /// let other = NullVec::with_mask(vec![4, 5, 6], Some(vec![false, false, true]));
+    /// assert_eq!(v.fill_null_with(&other),
+    ///            NullVec::with_mask(vec![1, 5, 0], Some(vec![false, false, true])));
+    /// ```
+    pub fn fill_null_with(&self, other: &NullVec<T>) -> Self {
+        assert!(
+            self.len() == other.len(),
+            "lhs and rhs must be the same length"
+        );
+        let (new_data, new_mask): (Vec<T>, Vec<bool>) = self.iter_raw()
+            .zip(other.iter_raw())
+            .map(|((lm, lv), (rm, rv))| if !lm {
+                (lv.clone(), false)
+            } else if !rm {
+                (rv.clone(), false)
+            } else {
+                (T::default(), true)
+            })
+            .unzip();
+        Self::from_filled(new_data, new_mask)
+    }
+
+    /// Returns `NullVec<T>` shifted by `periods`.
+    ///
+    /// Positive `periods` shifts values toward the end (lag), and negative `periods`
+    /// toward the beginning (lead). Vacated locations are filled with `fill_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1, 2, 3]);
+    /// assert_eq!(v.shift(1, Null),
+    ///            NullVec::with_mask(vec![0, 1, 2], Some(vec![true, false, false])));
+    /// assert_eq!(v.shift(-1, Nullable::new(9)), NullVec::new(vec![2, 3, 9]));
+    /// ```
+    pub fn shift(&self, periods: isize, fill_value: Nullable<T>) -> Self {
+        let len = self.len();
+        let n = ::std::cmp::min(periods.unsigned_abs(), len);
+
+        let (fill, fill_mask) = match fill_value {
+            Nullable::Value(v) => (v, false),
+            Nullable::Null => (T::default(), true),
+        };
+        let mut new_data: Vec<T> = Vec::with_capacity(len);
+        let mut new_mask: Vec<bool> = Vec::with_capacity(len);
+        let is_null = self.is_null();
+
+        if periods >= 0 {
+            new_data.extend(::std::iter::repeat_n(fill, n));
+            new_data.extend_from_slice(&self.data[..len - n]);
+            new_mask.extend(::std::iter::repeat_n(fill_mask, n));
+            new_mask.extend_from_slice(&is_null[..len - n]);
+        } else {
+            new_data.extend_from_slice(&self.data[n..]);
+            new_data.extend(::std::iter::repeat_n(fill, n));
+            new_mask.extend_from_slice(&is_null[n..]);
+            new_mask.extend(::std::iter::repeat_n(fill_mask, n));
+        }
+        Self::from_filled(new_data, new_mask)
+    }
+}
+
+/// Rule to handle `Null` in condition of selection kernels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullCondition {
+    /// Regard `Null` condition as `false`
+    AsFalse,
+    /// Regard `Null` condition as `true`
+    AsTrue,
+    /// Result is `Null` where condition is `Null`
+    Propagate,
+}
+
+impl NullCondition {
+    /// Resolve raw condition value, `None` means the result should be `Null`
+    fn resolve(&self, is_null: bool, value: bool) -> Option<bool> {
+        if !is_null {
+            return Some(value);
+        }
+        match *self {
+            NullCondition::AsFalse => Some(false),
+            NullCondition::AsTrue => Some(true),
+            NullCondition::Propagate => None,
+        }
+    }
+}
+
+/// Branch of selection kernels, which is a broadcasted scalar or a `NullVec`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Branch<'a, T: 'a + NullStorable> {
+    /// Scalar value broadcasted to all locations
+    Scalar(Nullable<T>),
+    /// Vector which must be the same length as the condition
+    Vector(&'a NullVec<T>),
+}
+
+impl<'a, T: Clone + NullStorable> Branch<'a, T> {
+    fn assert_length(&self, len: usize) {
+        if let Branch::Vector(vals) = *self {
+            assert!(vals.len() == len, "branch must be the same length as condition");
+        }
+    }
+
+    unsafe fn iloc_unchecked(&self, location: &usize) -> Nullable<T> {
+        match *self {
+            Branch::Scalar(ref val) => val.clone(),
+            Branch::Vector(vals) => vals.iloc_unchecked(location),
+        }
+    }
+}
+
+impl<'a, T: NullStorable> From<Nullable<T>> for Branch<'a, T> {
+    fn from(value: Nullable<T>) -> Self {
+        Branch::Scalar(value)
+    }
+}
+
+impl<'a, T: NullStorable> From<&'a NullVec<T>> for Branch<'a, T> {
+    fn from(values: &'a NullVec<T>) -> Self {
+        Branch::Vector(values)
+    }
+}
+
+impl<T: Clone + NullStorable> NullVec<T> {
+    /// Returns `NullVec<T>` selecting values from `a` where `cond` is `true`,
+    /// otherwise from `b`.
+    ///
+    /// # Parameters
+    ///
+    /// * `cond` - Condition to select values.
+    /// * `a` - Values used where `cond` is `true`. Either `&NullVec<T>` or `Nullable<T>`.
+    /// * `b` - Values used where `cond` is `false`. Either `&NullVec<T>` or `Nullable<T>`.
+    /// * `null_cond` - Rule to handle `Null` in `cond`.
+    ///
+    /// # Panics
+    ///
+    /// - if `a` or `b` has different length from `cond`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let cond = NullVec::with_mask(vec![true, false, true], Some(vec![false, false, true]));
+    /// let a = NullVec::new(vec![1, 2, 3]);
+    /// let res = NullVec::if_then_else(&cond, &a, Nullable::new(0), NullCondition::AsFalse);
+    /// assert_eq!(res, NullVec::new(vec![1, 0, 0]));
+    ///
+    /// let res = NullVec::if_then_else(&cond, &a, Nullable::new(0), NullCondition::Propagate);
+    /// assert_eq!(res, NullVec::with_mask(vec![1, 0, 0], Some(vec![false, false, true])));
+    /// ```
+    pub fn if_then_else<'a, 'b, A, B>(
+        cond: &NullVec<bool>,
+        a: A,
+        b: B,
+        null_cond: NullCondition,
+    ) -> Self
+    where
+        T: 'a + 'b,
+        A: Into<Branch<'a, T>>,
+        B: Into<Branch<'b, T>>,
+    {
+        let a: Branch<T> = a.into();
+        let b: Branch<T> = b.into();
+        a.assert_length(cond.len());
+        b.assert_length(cond.len());
+
+        let result: Vec<Nullable<T>> = cond
+            .iter_raw()
+            .enumerate()
+            .map(|(i, (m, &c))| match null_cond.resolve(m, c) {
+                Some(true) => unsafe { a.iloc_unchecked(&i) },
+                Some(false) => unsafe { b.iloc_unchecked(&i) },
+                None => Nullable::Null,
+            })
+            .collect();
+        result.into()
+    }
+
+    /// Returns `NullVec<T>` keeping values where `cond` is `true`,
+    /// and replacing with the corresponding value of `other` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1, 2, 3]);
+    /// let cond = NullVec::new(vec![true, false, true]);
+    /// let other = NullVec::new(vec![4, 5, 6]);
+    /// assert_eq!(v.where_(&cond, &other, NullCondition::AsFalse), NullVec::new(vec![1, 5, 3]));
+    /// ```
+    pub fn where_(
+        &self,
+        cond: &NullVec<bool>,
+        other: &NullVec<T>,
+        null_cond: NullCondition,
+    ) -> Self {
+        NullVec::if_then_else(cond, self, other, null_cond)
+    }
+
+    /// Returns `NullVec<T>` replacing values with `value` where `cond` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1, 2, 3]);
+    /// let cond = NullVec::new(vec![true, false, true]);
+    /// assert_eq!(v.mask(&cond, Null, NullCondition::AsFalse),
+    ///            NullVec::with_mask(vec![0, 2, 0], Some(vec![true, false, true])));
+    /// ```
+    pub fn mask(&self, cond: &NullVec<bool>, value: Nullable<T>, null_cond: NullCondition) -> Self {
+        NullVec::if_then_else(cond, value, self, null_cond)
+    }
+
+    /// Returns `NullVec<T>` which has the first non-null value of `values` in each location.
+    ///
+    /// Returns an empty `NullVec<T>` if `values` is empty.
+    ///
+    /// # Panics
+    ///
+    /// - if `values` have different lengths
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v1 = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, true]));
+    /// let v2 = NullVec::with_mask(vec![4, 5, 6], Some(vec![true, true, false]));
+    /// let v3 = NullVec::new(vec![7, 8, 9]);
+    /// assert_eq!(NullVec::coalesce(&[&v1, &v2, &v3]), NullVec::new(vec![1, 8, 6]));
+    /// ```
+    pub fn coalesce(values: &[&NullVec<T>]) -> Self {
+        match values.split_first() {
+            Some((first, rest)) => rest
+                .iter()
+                .fold((*first).clone(), |acc, v| acc.fill_null_with(v)),
+            None => NullVec::new(vec![]),
+        }
+    }
+}
+
+/// Method to interpolate `Null`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    /// Linear interpolation between the previous and the next non-null values
+    Linear,
+    /// Use the nearest non-null value, the previous value is used if equidistant
+    Nearest,
+}
+
+impl<T: Clone + ToPrimitive + NullStorable> NullVec<T> {
+    /// Returns `NullVec<f64>` interpolating `Null` between non-null neighbours
+    /// based on locations.
+    ///
+    /// Leading and trailing `Null` which have no non-null neighbour on either side
+    /// are kept as `Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 0, 0, 4, 0], Some(vec![false, true, true, false, true]));
+    /// assert_eq!(v.interpolate(Interpolation::Linear),
+    ///            NullVec::with_mask(vec![1., 2., 3., 4., 0.],
+    ///                               Some(vec![false, false, false, false, true])));
+    /// assert_eq!(v.interpolate(Interpolation::Nearest),
+    ///            NullVec::with_mask(vec![1., 1., 4., 4., 0.],
+    ///                               Some(vec![false, false, false, false, true])));
+    /// ```
+    pub fn interpolate(&self, method: Interpolation) -> NullVec<f64> {
+        let values: Vec<Option<f64>> = self.iter_raw()
+            .map(|(m, v)| if m {
+                None
+            } else {
+                ToPrimitive::to_f64(v)
+            })
+            .collect();
+
+        // location of the next non-null value
+        let mut next: Vec<Option<usize>> = vec![None; values.len()];
+        let mut current: Option<usize> = None;
+        for (i, v) in values.iter().enumerate().rev() {
+            if v.is_some() {
+                current = Some(i);
+            }
+            next[i] = current;
+        }
+
+        let mut new_data: Vec<f64> = Vec::with_capacity(values.len());
+        let mut new_mask: Vec<bool> = Vec::with_capacity(values.len());
+        let mut prev: Option<usize> = None;
+        for (i, v) in values.iter().enumerate() {
+            if let Some(val) = *v {
+                prev = Some(i);
+                new_data.push(val);
+                new_mask.push(false);
+                continue;
+            }
+            match (prev, next[i]) {
+                (Some(p), Some(n)) => {
+                    let (pv, nv) = (values[p].unwrap(), values[n].unwrap());
+                    let val = match method {
+                        Interpolation::Linear => {
+                            pv + (nv - pv) * (i - p) as f64 / (n - p) as f64
+                        }
+                        Interpolation::Nearest => if i - p <= n - i { pv } else { nv },
+                    };
+                    new_data.push(val);
+                    new_mask.push(false);
+                }
+                _ => {
+                    new_data.push(0.);
+                    new_mask.push(true);
+                }
+            }
+        }
+        NullVec::from_filled(new_data, new_mask)
+    }
 }
 
 impl<T: Clone + NullStorable> Slicer for NullVec<T> {
@@ -268,7 +693,7 @@ impl<T: Clone + NullStorable> Append for NullVec<T> {
                 Some(new_mask)
             }
             (&None, &Some(ref rmask)) => {
-                let new_mask: Vec<bool> = other
+                let new_mask: Vec<bool> = self
                     .is_null()
                     .into_iter()
                     .chain(rmask.iter().cloned())
@@ -315,7 +740,8 @@ mod tests {
 
     use nullable::Nullable;
     use nullvec::NullVec;
-    use traits::{Slicer, Stringify};
+    use traits::{Slicer, Stringify, Append};
+    use super::{Interpolation, NullCondition};
 
     #[test]
     fn test_int_isnull() {
@@ -411,6 +837,181 @@ mod tests {
         assert_eq!(res.mask, None);
     }
 
+    #[test]
+    fn test_ffill_bfill() {
+        let values: Vec<usize> = vec![0, 2, 0, 0, 5, 0];
+        let nvec = NullVec::with_mask(values, Some(vec![true, false, true, true, false, true]));
+
+        let res = nvec.ffill(None);
+        assert_eq!(res.data, vec![0, 2, 2, 2, 5, 5]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false, false, false]));
+
+        let res = nvec.ffill(Some(1));
+        assert_eq!(res.data, vec![0, 2, 2, 0, 5, 5]);
+        assert_eq!(res.mask, Some(vec![true, false, false, true, false, false]));
+
+        let res = nvec.bfill(None);
+        assert_eq!(res.data, vec![2, 2, 5, 5, 5, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, false, false, false, true]));
+
+        let res = nvec.bfill(Some(1));
+        assert_eq!(res.data, vec![2, 2, 0, 5, 5, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, true, false, false, true]));
+
+        let res = nvec.ffill(None).bfill(None);
+        assert_eq!(res.data, vec![2, 2, 2, 2, 5, 5]);
+        assert_eq!(res.mask, None);
+    }
+
+    #[test]
+    fn test_fill_null_with() {
+        let nvec1 = NullVec::new(vec![1.1, f64::NAN, f64::NAN]);
+        let nvec2 = NullVec::new(vec![f64::NAN, 2.2, f64::NAN]);
+        let res = nvec1.fill_null_with(&nvec2);
+        assert_eq!(res.data, vec![1.1, 2.2, 0.]);
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+
+        let nvec3 = NullVec::new(vec![3.3, 3.3, 3.3]);
+        let res = nvec1.fill_null_with(&nvec3);
+        assert_eq!(res.data, vec![1.1, 3.3, 3.3]);
+        assert_eq!(res.mask, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_null_with_length_mismatch() {
+        let nvec1 = NullVec::new(vec![1, 2]);
+        let nvec2 = NullVec::new(vec![1, 2, 3]);
+        nvec1.fill_null_with(&nvec2);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let values: Vec<i64> = vec![0, 1, 0, 0, 0, 5, 0];
+        let nvec = NullVec::with_mask(values, Some(vec![true, false, true, true, true, false, true]));
+
+        let res = nvec.interpolate(Interpolation::Linear);
+        assert_eq!(res.data, vec![0., 1., 2., 3., 4., 5., 0.]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false, false, false, true]));
+
+        let res = nvec.interpolate(Interpolation::Nearest);
+        assert_eq!(res.data, vec![0., 1., 1., 1., 5., 5., 0.]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false, false, false, true]));
+
+        let nvec = NullVec::new(vec![1., f64::NAN, 2.]);
+        let res = nvec.interpolate(Interpolation::Linear);
+        assert_eq!(res.data, vec![1., 1.5, 2.]);
+        assert_eq!(res.mask, None);
+    }
+
+    #[test]
+    fn test_if_then_else() {
+        let cond = NullVec::with_mask(
+            vec![true, false, true, false],
+            Some(vec![false, false, true, true]),
+        );
+        let a = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![true, false, false, false]));
+        let b = NullVec::new(vec![5, 6, 7, 8]);
+
+        let res = NullVec::if_then_else(&cond, &a, &b, NullCondition::AsFalse);
+        assert_eq!(res.data, vec![0, 6, 7, 8]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false]));
+
+        let res = NullVec::if_then_else(&cond, &a, &b, NullCondition::AsTrue);
+        assert_eq!(res.data, vec![0, 6, 3, 4]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false]));
+
+        let res = NullVec::if_then_else(&cond, &a, &b, NullCondition::Propagate);
+        assert_eq!(res.data, vec![0, 6, 0, 0]);
+        assert_eq!(res.mask, Some(vec![true, false, true, true]));
+
+        let res = NullVec::if_then_else(
+            &cond,
+            Nullable::Value(9),
+            Nullable::Value(0),
+            NullCondition::AsFalse,
+        );
+        assert_eq!(res.data, vec![9, 0, 0, 0]);
+        assert_eq!(res.mask, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_if_then_else_length_mismatch() {
+        let cond = NullVec::new(vec![true, false]);
+        let a = NullVec::new(vec![1, 2, 3]);
+        NullVec::if_then_else(&cond, &a, Nullable::Null, NullCondition::AsFalse);
+    }
+
+    #[test]
+    fn test_where_mask() {
+        let nvec = NullVec::new(vec![1.1, 1.2, 1.3]);
+        let cond = NullVec::with_mask(vec![true, false, false], Some(vec![false, false, true]));
+        let other = NullVec::new(vec![2.1, f64::NAN, 2.3]);
+
+        let res = nvec.where_(&cond, &other, NullCondition::AsFalse);
+        assert_eq!(res.data, vec![1.1, 0., 2.3]);
+        assert_eq!(res.mask, Some(vec![false, true, false]));
+
+        let res = nvec.mask(&cond, Nullable::Value(0.5), NullCondition::AsTrue);
+        assert_eq!(res.data, vec![0.5, 1.2, 0.5]);
+        assert_eq!(res.mask, None);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let nvec1 = NullVec::new(vec![1.1, f64::NAN, f64::NAN]);
+        let nvec2 = NullVec::new(vec![2.1, f64::NAN, 2.3]);
+        let res = NullVec::coalesce(&[&nvec1, &nvec2]);
+        assert_eq!(res.data, vec![1.1, 0., 2.3]);
+        assert_eq!(res.mask, Some(vec![false, true, false]));
+
+        let res = NullVec::coalesce(&[&nvec1]);
+        assert_eq!(res, nvec1);
+
+        let res: NullVec<f64> = NullVec::coalesce(&[]);
+        assert_eq!(res, NullVec::new(vec![]));
+    }
+
+    #[test]
+    fn test_shift() {
+        let values: Vec<usize> = vec![1, 2, 3, 4];
+        let nvec = NullVec::new(values);
+
+        let res = nvec.shift(1, Nullable::Null);
+        assert_eq!(res.data, vec![0, 1, 2, 3]);
+        assert_eq!(res.mask, Some(vec![true, false, false, false]));
+
+        let res = nvec.shift(-2, Nullable::Null);
+        assert_eq!(res.data, vec![3, 4, 0, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, true, true]));
+
+        let res = nvec.shift(2, Nullable::Value(10));
+        assert_eq!(res.data, vec![10, 10, 1, 2]);
+        assert_eq!(res.mask, None);
+
+        let res = nvec.shift(0, Nullable::Null);
+        assert_eq!(res, nvec);
+
+        let res = nvec.shift(10, Nullable::Null);
+        assert_eq!(res.data, vec![0, 0, 0, 0]);
+        assert_eq!(res.mask, Some(vec![true, true, true, true]));
+    }
+
+    #[test]
+    fn test_shift_with_null() {
+        let values: Vec<f64> = vec![1.1, f64::NAN, 1.3];
+        let nvec = NullVec::new(values);
+
+        let res = nvec.shift(1, Nullable::Value(0.5));
+        assert_eq!(res.data, vec![0.5, 1.1, 0.]);
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+
+        let res = nvec.shift(-2, Nullable::Value(0.5));
+        assert_eq!(res.data, vec![1.3, 0.5, 0.5]);
+        assert_eq!(res.mask, None);
+    }
+
     #[test]
     fn test_iloc() {
         let values: Vec<usize> = vec![1, 2, 3];
@@ -575,4 +1176,30 @@ mod tests {
         let exp = vec!["a".to_string(), "bb".to_string(), "Null".to_string()];
         assert_eq!(nvec.into_string_vec(), exp);
     }
+
+    #[test]
+    fn test_append() {
+        let nvec1 = NullVec::new(vec![1, 2]);
+        let nvec2 = NullVec::with_mask(vec![3, 4, 5], Some(vec![true, false, false]));
+        let res = nvec1.append(&nvec2);
+        assert_eq!(res.data, vec![1, 2, 3, 4, 5]);
+        assert_eq!(res.mask, Some(vec![false, false, true, false, false]));
+
+        let res = nvec2.append(&nvec1);
+        assert_eq!(res.mask, Some(vec![true, false, false, false, false]));
+    }
+
+    #[test]
+    fn test_append_without_left_mask() {
+        // the mask of the left side must be built from its own length
+        let nvec1 = NullVec::new(vec![1]);
+        let nvec2 = NullVec::with_mask(vec![2, 3, 4], Some(vec![false, true, false]));
+        let res = nvec1.append(&nvec2);
+        assert_eq!(res.data, vec![1, 2, 3, 4]);
+        assert_eq!(res.mask, Some(vec![false, false, true, false]));
+
+        let empty: NullVec<i32> = NullVec::new(vec![]);
+        let res = empty.append(&nvec2);
+        assert_eq!(res, nvec2);
+    }
 }
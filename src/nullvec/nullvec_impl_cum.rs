@@ -0,0 +1,386 @@
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, ToPrimitive};
+
+use super::NullVec;
+use nullable::Nullable;
+use traits::{NullStorable, Slicer};
+
+impl<T: Clone + NullStorable> NullVec<T> {
+    /// Scan values applying `func` to the running value and the current value.
+    ///
+    /// If `skip_nulls` is `true`, `Null` is kept as `Null` and the running value is
+    /// carried over to the next non-null value. Otherwise, all values after the first
+    /// `Null` are `Null`.
+    ///
+    /// If `func` returns `None` because of overflow, the value and all values after
+    /// it are `Null`.
+    pub(crate) fn cum_apply<F>(&self, skip_nulls: bool, func: F) -> NullVec<T>
+    where
+        F: Fn(T, &T) -> Option<T>,
+    {
+        let mut current: Option<T> = None;
+        let mut exhausted = false;
+        let mut result: Vec<Nullable<T>> = Vec::with_capacity(self.len());
+        for (m, v) in self.iter_raw() {
+            if m || exhausted {
+                if !skip_nulls {
+                    exhausted = true;
+                }
+                result.push(Nullable::Null);
+            } else {
+                let new_value = match current.take() {
+                    Some(c) => func(c, v),
+                    None => Some(v.clone()),
+                };
+                match new_value {
+                    Some(new_value) => {
+                        result.push(Nullable::Value(new_value.clone()));
+                        current = Some(new_value);
+                    }
+                    None => {
+                        exhausted = true;
+                        result.push(Nullable::Null);
+                    }
+                }
+            }
+        }
+        result.into()
+    }
+
+    /// Returns running count of non-null values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false]));
+    /// assert_eq!(v.cumcount(true),
+    ///            NullVec::with_mask(vec![1, 0, 2], Some(vec![false, true, false])));
+    /// ```
+    pub fn cumcount(&self, skip_nulls: bool) -> NullVec<usize> {
+        let mut count = 0usize;
+        let mut exhausted = false;
+        let mut result: Vec<Nullable<usize>> = Vec::with_capacity(self.len());
+        for (m, _) in self.iter_raw() {
+            if m || exhausted {
+                if !skip_nulls {
+                    exhausted = true;
+                }
+                result.push(Nullable::Null);
+            } else {
+                count += 1;
+                result.push(Nullable::Value(count));
+            }
+        }
+        result.into()
+    }
+
+    /// Returns the pairs of locations `(current, current - periods)` which are
+    /// both in bounds, otherwise `None`.
+    fn lagged_locations(&self, periods: isize) -> Vec<Option<(usize, usize)>> {
+        let len = self.len() as isize;
+        (0..len)
+            .map(|i| match i.checked_sub(periods) {
+                Some(j) if j >= 0 && j < len => Some((i as usize, j as usize)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Apply `func` to the element and the element `periods` before.
+    ///
+    /// The result is `Null` if either value is `Null` or out of bounds, or if
+    /// `func` returns `None`.
+    pub(crate) fn diff_with<F>(&self, periods: isize, func: F) -> NullVec<T>
+    where
+        F: Fn(&T, &T) -> Option<T>,
+    {
+        let result: Vec<Nullable<T>> = self
+            .lagged_locations(periods)
+            .into_iter()
+            .map(|locs| match locs {
+                Some((i, j)) => match (self.iloc(&i), self.iloc(&j)) {
+                    (Nullable::Value(x), Nullable::Value(y)) => match func(&x, &y) {
+                        Some(d) => Nullable::Value(d),
+                        None => Nullable::Null,
+                    },
+                    _ => Nullable::Null,
+                },
+                None => Nullable::Null,
+            })
+            .collect();
+        result.into()
+    }
+}
+
+impl<T> NullVec<T>
+where
+    T: Clone + CheckedAdd + NullStorable,
+{
+    /// Returns cumulative sum.
+    ///
+    /// # Parameters
+    ///
+    /// * `skip_nulls` - If `true`, `Null` is kept as `Null` and doesn't reset the running value.
+    ///   If `false`, all values after the first `Null` are `Null`.
+    ///
+    /// If the sum overflows, the value and all values after it are `Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false]));
+    /// assert_eq!(v.cumsum(true),
+    ///            NullVec::with_mask(vec![1, 0, 4], Some(vec![false, true, false])));
+    /// assert_eq!(v.cumsum(false),
+    ///            NullVec::with_mask(vec![1, 0, 0], Some(vec![false, true, true])));
+    /// ```
+    pub fn cumsum(&self, skip_nulls: bool) -> Self {
+        self.cum_apply(skip_nulls, |c, v| c.checked_add(v))
+    }
+}
+
+impl<T> NullVec<T>
+where
+    T: Clone + CheckedMul + NullStorable,
+{
+    /// Returns cumulative product.
+    ///
+    /// If the product overflows, the value and all values after it are `Null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1, 2, 3]);
+    /// assert_eq!(v.cumprod(true), NullVec::new(vec![1, 2, 6]));
+    /// ```
+    pub fn cumprod(&self, skip_nulls: bool) -> Self {
+        self.cum_apply(skip_nulls, |c, v| c.checked_mul(v))
+    }
+}
+
+impl<T> NullVec<T>
+where
+    T: Clone + PartialOrd + NullStorable,
+{
+    /// Returns cumulative minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![2, 3, 1]);
+    /// assert_eq!(v.cummin(true), NullVec::new(vec![2, 2, 1]));
+    /// ```
+    pub fn cummin(&self, skip_nulls: bool) -> Self {
+        self.cum_apply(skip_nulls, |c, v| Some(if *v < c { v.clone() } else { c }))
+    }
+
+    /// Returns cumulative maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![2, 1, 3]);
+    /// assert_eq!(v.cummax(true), NullVec::new(vec![2, 2, 3]));
+    /// ```
+    pub fn cummax(&self, skip_nulls: bool) -> Self {
+        self.cum_apply(skip_nulls, |c, v| Some(if *v > c { v.clone() } else { c }))
+    }
+}
+
+impl<T> NullVec<T>
+where
+    T: Clone + CheckedSub + NullStorable,
+{
+    /// Returns difference between the element and the element `periods` before.
+    ///
+    /// Negative `periods` compares with the element after. The result is `Null` if
+    /// either value is `Null` or out of bounds, or if the difference overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1, 3, 6]);
+    /// assert_eq!(v.diff(1), NullVec::with_mask(vec![0, 2, 3], Some(vec![true, false, false])));
+    /// ```
+    pub fn diff(&self, periods: isize) -> Self {
+        self.diff_with(periods, |x, y| x.checked_sub(y))
+    }
+}
+
+impl<T> NullVec<T>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    /// Returns percentage change between the element and the element `periods` before.
+    ///
+    /// The result is `Null` if either value is `Null` or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![2, 3, 6]);
+    /// assert_eq!(v.pct_change(1),
+    ///            NullVec::with_mask(vec![0., 0.5, 1.], Some(vec![true, false, false])));
+    /// ```
+    pub fn pct_change(&self, periods: isize) -> NullVec<f64> {
+        let result: Vec<Nullable<f64>> = self
+            .lagged_locations(periods)
+            .into_iter()
+            .map(|locs| match locs {
+                Some((i, j)) => match (self.iloc(&i), self.iloc(&j)) {
+                    (Nullable::Value(x), Nullable::Value(y)) => {
+                        let x = ToPrimitive::to_f64(&x).unwrap();
+                        let y = ToPrimitive::to_f64(&y).unwrap();
+                        Nullable::new(x / y - 1.)
+                    }
+                    _ => Nullable::Null,
+                },
+                None => Nullable::Null,
+            })
+            .collect();
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use nullvec::NullVec;
+
+    #[test]
+    fn test_cumsum() {
+        let nvec = NullVec::new(vec![1, 2, 3]);
+        assert_eq!(nvec.cumsum(true), NullVec::new(vec![1, 3, 6]));
+        assert_eq!(nvec.cumsum(false), NullVec::new(vec![1, 3, 6]));
+
+        let nvec = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![true, false, true, false]));
+        let res = nvec.cumsum(true);
+        assert_eq!(res.data, vec![0, 2, 0, 6]);
+        assert_eq!(res.mask, Some(vec![true, false, true, false]));
+
+        let res = nvec.cumsum(false);
+        assert_eq!(res.mask, Some(vec![true, true, true, true]));
+
+        let nvec: NullVec<i64> = NullVec::new(vec![]);
+        assert_eq!(nvec.cumsum(true), NullVec::new(vec![]));
+    }
+
+    #[test]
+    fn test_cumsum_overflow() {
+        let nvec = NullVec::new(vec![200u8, 50, 10, 1]);
+        let res = nvec.cumsum(true);
+        assert_eq!(res.data, vec![200, 250, 0, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, true, true]));
+
+        let nvec = NullVec::new(vec![i64::MIN, -1]);
+        assert_eq!(nvec.cumsum(true).mask, Some(vec![false, true]));
+    }
+
+    #[test]
+    fn test_cumprod() {
+        let nvec = NullVec::with_mask(vec![1, 2, 0, 3], Some(vec![false, false, true, false]));
+        let res = nvec.cumprod(true);
+        assert_eq!(res.data, vec![1, 2, 0, 6]);
+        assert_eq!(res.mask, Some(vec![false, false, true, false]));
+
+        let res = nvec.cumprod(false);
+        assert_eq!(res.data, vec![1, 2, 0, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, true, true]));
+
+        let res = NullVec::new(vec![16u8, 16, 1]).cumprod(true);
+        assert_eq!(res.mask, Some(vec![false, true, true]));
+    }
+
+    #[test]
+    fn test_cummin_cummax() {
+        let nvec = NullVec::with_mask(
+            vec![3, 0, 2, 4, 1],
+            Some(vec![false, true, false, false, false]),
+        );
+        let res = nvec.cummin(true);
+        assert_eq!(res.data, vec![3, 0, 2, 2, 1]);
+        assert_eq!(res.mask, Some(vec![false, true, false, false, false]));
+
+        let res = nvec.cummax(true);
+        assert_eq!(res.data, vec![3, 0, 3, 4, 4]);
+        assert_eq!(res.mask, Some(vec![false, true, false, false, false]));
+
+        let nvec = NullVec::new(vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+        assert_eq!(
+            nvec.cummin(true),
+            NullVec::new(vec!["b".to_string(), "a".to_string(), "a".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_cumcount() {
+        let nvec = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![false, true, false, false]));
+        let res = nvec.cumcount(true);
+        assert_eq!(res.data, vec![1, 0, 2, 3]);
+        assert_eq!(res.mask, Some(vec![false, true, false, false]));
+
+        let res = nvec.cumcount(false);
+        assert_eq!(res.data, vec![1, 0, 0, 0]);
+        assert_eq!(res.mask, Some(vec![false, true, true, true]));
+    }
+
+    #[test]
+    fn test_diff() {
+        let nvec = NullVec::with_mask(
+            vec![1, 3, 6, 10, 15],
+            Some(vec![false, false, true, false, false]),
+        );
+        let res = nvec.diff(1);
+        assert_eq!(res.data, vec![0, 2, 0, 0, 5]);
+        assert_eq!(res.mask, Some(vec![true, false, true, true, false]));
+
+        let res = nvec.diff(2);
+        assert_eq!(res.data, vec![0, 0, 0, 7, 0]);
+        assert_eq!(res.mask, Some(vec![true, true, true, false, true]));
+
+        let res = nvec.diff(-1);
+        assert_eq!(res.data, vec![-2, 0, 0, -5, 0]);
+        assert_eq!(res.mask, Some(vec![false, true, true, false, true]));
+
+        let res = nvec.diff(10);
+        assert_eq!(res.mask, Some(vec![true, true, true, true, true]));
+    }
+
+    #[test]
+    fn test_diff_overflow() {
+        let nvec = NullVec::new(vec![3u8, 1, 4]);
+        let res = nvec.diff(1);
+        assert_eq!(res.data, vec![0, 0, 3]);
+        assert_eq!(res.mask, Some(vec![true, true, false]));
+
+        let res = NullVec::new(vec![1u8, 2]).diff(-1);
+        assert_eq!(res.mask, Some(vec![true, true]));
+
+        let res = NullVec::new(vec![1, 2]).diff(isize::MIN);
+        assert_eq!(res.mask, Some(vec![true, true]));
+        let res = NullVec::new(vec![1, 2]).diff(isize::MAX);
+        assert_eq!(res.mask, Some(vec![true, true]));
+        let res = NullVec::new(vec![1, 2]).pct_change(isize::MIN);
+        assert_eq!(res.mask, Some(vec![true, true]));
+    }
+
+    #[test]
+    fn test_pct_change() {
+        let nvec = NullVec::new(vec![1., 2., f64::NAN, 4., 2.]);
+        let res = nvec.pct_change(1);
+        assert_eq!(res.data, vec![0., 1., 0., 0., -0.5]);
+        assert_eq!(res.mask, Some(vec![true, false, true, true, false]));
+
+        let res = nvec.pct_change(-2);
+        assert_eq!(res.data, vec![0., -0.5, 0., 0., 0.]);
+        assert_eq!(res.mask, Some(vec![true, false, true, true, true]));
+    }
+}
@@ -0,0 +1,89 @@
+use super::NullVec;
+use decimal::Decimal;
+
+impl NullVec<String> {
+    /// Parse values to `Decimal`. Values which can't be parsed become `Null`.
+    /// See `Decimal::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["1.10".to_string(), "x".to_string()]);
+    /// let res = v.parse_decimal();
+    /// assert_eq!(res.is_null(), vec![false, true]);
+    /// assert_eq!(res.sum(), Nullable::Value(Decimal::new(110, 3, 2)));
+    /// ```
+    pub fn parse_decimal(&self) -> NullVec<Decimal> {
+        self.parse(Decimal::parse)
+    }
+}
+
+impl NullVec<Decimal> {
+    /// Returns values which have the specified scale. See `Decimal::rescale`.
+    pub fn rescale(&self, scale: u8) -> NullVec<Decimal> {
+        let new_data: Vec<Decimal> = self
+            .iter_raw()
+            .map(|(m, v)| {
+                if m {
+                    Decimal::default()
+                } else {
+                    v.rescale(scale)
+                }
+            })
+            .collect();
+        NullVec {
+            data: new_data,
+            mask: self.mask.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use decimal::Decimal;
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use traits::{BasicAggregation, ComparisonAggregation, Stringify};
+
+    #[test]
+    fn test_parse_decimal() {
+        let values = NullVec::with_mask(
+            vec![
+                "0.1".to_string(),
+                "-2.25".to_string(),
+                "".to_string(),
+                "1e2".to_string(),
+                "0.2".to_string(),
+            ],
+            Some(vec![false, false, true, false, false]),
+        );
+        let res = values.parse_decimal();
+        assert_eq!(res.is_null(), vec![false, false, true, true, false]);
+        assert_eq!(
+            res.into_string_vec(),
+            vec!["0.1", "-2.25", "Null", "Null", "0.2"]
+        );
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let values = NullVec::new(vec!["0.1".to_string(); 10]).parse_decimal();
+        assert_eq!(values.sum(), Nullable::Value(Decimal::new(1, 1, 0)));
+        assert_eq!(values.count(), 10);
+
+        let values = NullVec::new(vec![
+            "0.3".to_string(),
+            "-1.25".to_string(),
+            "2".to_string(),
+        ])
+        .parse_decimal();
+        assert_eq!(values.min(), Nullable::Value(Decimal::new(-125, 3, 2)));
+        assert_eq!(values.max(), Nullable::Value(Decimal::new(2, 1, 0)));
+        assert_eq!(
+            values.rescale(1).into_string_vec(),
+            vec!["0.3", "-1.3", "2.0"]
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use super::NullVec;
+use temporal::{
+    self, Date32, DateLike, FormatError, Frequency, ParseDatetimeError, TimeUnit, Timestamp,
+    TryParseError,
+};
+use traits::NullStorable;
+
+/// Vectorized datetime methods of `NullVec<Date32>` and `NullVec<Timestamp>`,
+/// created by `NullVec::dt`.
+///
+/// Components are extracted from the local date and time in the proleptic Gregorian
+/// calendar. All methods propagate `Null`.
+#[derive(Clone, Debug)]
+pub struct DatetimeMethods<'a, T: 'a + NullStorable> {
+    data: &'a NullVec<T>,
+}
+
+impl<T: DateLike + NullStorable> NullVec<T> {
+    /// Returns vectorized datetime methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![Date32::from_ymd(2017, 3, 1), Date32::default()],
+    ///                            Some(vec![false, true]));
+    /// assert_eq!(v.dt().month(), NullVec::with_mask(vec![3, 0], Some(vec![false, true])));
+    /// ```
+    pub fn dt(&self) -> DatetimeMethods<'_, T> {
+        DatetimeMethods { data: self }
+    }
+}
+
+impl<'a, T: DateLike + NullStorable> DatetimeMethods<'a, T> {
+    /// Apply `func` to non-null values, keeping the mask
+    fn apply<R, F>(&self, func: F) -> NullVec<R>
+    where
+        R: NullStorable,
+        F: Fn(&T) -> R,
+    {
+        let new_data: Vec<R> = self
+            .data
+            .iter_raw()
+            .map(|(m, v)| if m { R::default() } else { func(v) })
+            .collect();
+        NullVec {
+            data: new_data,
+            mask: self.data.mask.clone(),
+        }
+    }
+
+    /// Apply `func` to non-null values, results of `None` become `Null`
+    fn apply_checked<R, F>(&self, func: F) -> NullVec<R>
+    where
+        R: NullStorable,
+        F: Fn(&T) -> Option<R>,
+    {
+        let new_values: Vec<Option<R>> = self
+            .data
+            .iter_raw()
+            .map(|(m, v)| if m { None } else { func(v) })
+            .collect();
+        let new_mask: Vec<bool> = new_values.iter().map(|x| x.is_none()).collect();
+        let new_data: Vec<R> = new_values
+            .into_iter()
+            .map(|x| x.unwrap_or_default())
+            .collect();
+        if new_mask.iter().any(|&m| m) {
+            NullVec::with_mask(new_data, Some(new_mask))
+        } else {
+            NullVec::new(new_data)
+        }
+    }
+
+    fn apply_ymd<F>(&self, func: F) -> NullVec<i32>
+    where
+        F: Fn(i64, u32, u32) -> i32,
+    {
+        self.apply(|v| {
+            let (y, m, d) = temporal::civil_from_days(v.local_days());
+            func(y, m, d)
+        })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> NullVec<i32> {
+        self.apply_ymd(|y, _, _| y as i32)
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> NullVec<i32> {
+        self.apply_ymd(|_, m, _| m as i32)
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    pub fn day(&self) -> NullVec<i32> {
+        self.apply_ymd(|_, _, d| d as i32)
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> NullVec<i32> {
+        self.apply(|v| (v.local_seconds() / 3_600) as i32)
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> NullVec<i32> {
+        self.apply(|v| (v.local_seconds() % 3_600 / 60) as i32)
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> NullVec<i32> {
+        self.apply(|v| (v.local_seconds() % 60) as i32)
+    }
+
+    /// Returns the day of the week, Monday is 0 and Sunday is 6.
+    pub fn weekday(&self) -> NullVec<i32> {
+        // 1970-01-01 is Thursday
+        self.apply(|v| (v.local_days() + 3).rem_euclid(7) as i32)
+    }
+
+    /// Returns the day of the year, from 1 to 366.
+    pub fn day_of_year(&self) -> NullVec<i32> {
+        self.apply(|v| {
+            let days = v.local_days();
+            let (y, _, _) = temporal::civil_from_days(days);
+            (days - temporal::days_from_civil(y, 1, 1) + 1) as i32
+        })
+    }
+
+    /// Returns the quarter, from 1 to 4.
+    pub fn quarter(&self) -> NullVec<i32> {
+        self.apply_ymd(|_, m, _| ((m - 1) / 3 + 1) as i32)
+    }
+
+    /// Returns whether the year is a leap year.
+    pub fn is_leap_year(&self) -> NullVec<bool> {
+        self.apply(|v| {
+            let (y, _, _) = temporal::civil_from_days(v.local_days());
+            temporal::is_leap_year(y)
+        })
+    }
+
+    /// Returns values formatted with strftime-like `format`. Supported directives are
+    /// `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%f` (9 digits), `%z` and `%%`.
+    ///
+    /// Returns an error if `format` contains unsupported directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![Date32::from_ymd(2017, 3, 1)]);
+    /// assert_eq!(v.dt().strftime("%d/%m/%Y").unwrap(),
+    ///            NullVec::new(vec!["01/03/2017".to_string()]));
+    /// assert_eq!(v.dt().strftime("%y").unwrap_err().directive(), "%y");
+    /// ```
+    pub fn strftime(&self, format: &str) -> Result<NullVec<String>, FormatError> {
+        let items = temporal::items(format)?;
+        Ok(self.apply(|v| temporal::strftime(v, &items)))
+    }
+}
+
+impl<'a> DatetimeMethods<'a, Timestamp> {
+    /// Returns values truncated to the boundary of `freq`, `Null` if the result
+    /// overflows. See `Timestamp::truncate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![Timestamp::new(5_430, TimeUnit::Second)]);
+    /// assert_eq!(v.dt().truncate(Frequency::Hour),
+    ///            NullVec::new(vec![Timestamp::new(3_600, TimeUnit::Second)]));
+    /// ```
+    pub fn truncate(&self, freq: Frequency) -> NullVec<Timestamp> {
+        self.apply_checked(|v| v.checked_truncate(freq))
+    }
+
+    /// Returns values rounded to the nearest boundary of `freq`, `Null` if the
+    /// result overflows. See `Timestamp::round`.
+    pub fn round(&self, freq: Frequency) -> NullVec<Timestamp> {
+        self.apply_checked(|v| v.checked_round(freq))
+    }
+}
+
+impl NullVec<i64> {
+    /// Returns `NullVec<Timestamp>` regarding values as the number of `unit` since
+    /// 1970-01-01T00:00:00 UTC, so that epoch values can use the methods of `dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1_488_369_600, 0], Some(vec![false, true]));
+    /// let ts = v.to_timestamp(TimeUnit::Second);
+    /// assert_eq!(ts.dt().hour(), NullVec::with_mask(vec![12, 0], Some(vec![false, true])));
+    /// assert_eq!(ts.dt().truncate(Frequency::Day).to_epoch(TimeUnit::Second),
+    ///            NullVec::with_mask(vec![1_488_326_400, 0], Some(vec![false, true])));
+    /// ```
+    pub fn to_timestamp(&self, unit: TimeUnit) -> NullVec<Timestamp> {
+        NullVec {
+            data: self.data.iter().map(|&v| Timestamp::new(v, unit)).collect(),
+            mask: self.mask.clone(),
+        }
+    }
+}
+
+impl NullVec<Timestamp> {
+    /// Returns values as the number of `unit` since 1970-01-01T00:00:00 UTC, the
+    /// inverse of `NullVec::to_timestamp`.
+    ///
+    /// Converting to coarser unit rounds toward the past.
+    ///
+    /// # Panics
+    ///
+    /// - if a value overflows when converted to `unit`
+    pub fn to_epoch(&self, unit: TimeUnit) -> NullVec<i64> {
+        let data = self
+            .iter_raw()
+            .map(|(m, v)| if m { 0 } else { v.to_unit(unit).value() })
+            .collect();
+        NullVec {
+            data,
+            mask: self.mask.clone(),
+        }
+    }
+}
+
+impl NullVec<String> {
+    /// Parse values in strict mode, returning an error at the first value
+    /// which can't be parsed
+    fn try_parse<R, F>(&self, func: F) -> Result<NullVec<R>, ParseDatetimeError>
+    where
+        R: NullStorable,
+        F: Fn(&str) -> Option<R>,
+    {
+        let mut new_data: Vec<R> = Vec::with_capacity(self.data.len());
+        for (i, (m, v)) in self.iter_raw().enumerate() {
+            if m {
+                new_data.push(R::default());
+                continue;
+            }
+            match func(v) {
+                Some(parsed) => new_data.push(parsed),
+                None => return Err(ParseDatetimeError::new(i, v.clone())),
+            }
+        }
+        Ok(NullVec {
+            data: new_data,
+            mask: self.mask.clone(),
+        })
+    }
+
+    /// Parse values in lenient mode, values which can't be parsed become `Null`
+    pub(super) fn parse<R, F>(&self, func: F) -> NullVec<R>
+    where
+        R: NullStorable,
+        F: Fn(&str) -> Option<R>,
+    {
+        let mut new_data: Vec<R> = Vec::with_capacity(self.data.len());
+        let mut new_mask: Vec<bool> = Vec::with_capacity(self.data.len());
+        for (m, v) in self.iter_raw() {
+            match if m { None } else { func(v) } {
+                Some(parsed) => {
+                    new_data.push(parsed);
+                    new_mask.push(false);
+                }
+                None => {
+                    new_data.push(R::default());
+                    new_mask.push(true);
+                }
+            }
+        }
+        if new_mask.contains(&true) {
+            NullVec::with_mask(new_data, Some(new_mask))
+        } else {
+            NullVec::new(new_data)
+        }
+    }
+
+    /// Parse values to `Date32` with strftime-like `format`, or ISO 8601 if `format`
+    /// is `None`. Values which can't be parsed become `Null`.
+    /// Returns an error if `format` contains unsupported directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["2017-03-01".to_string(), "x".to_string()]);
+    /// assert_eq!(v.parse_date(None).unwrap(),
+    ///            NullVec::with_mask(vec![Date32::from_ymd(2017, 3, 1), Date32::default()],
+    ///                               Some(vec![false, true])));
+    /// assert!(v.parse_date(Some("%y")).is_err());
+    /// ```
+    pub fn parse_date(&self, format: Option<&str>) -> Result<NullVec<Date32>, FormatError> {
+        let items = temporal::format_items(format)?;
+        let items = items.as_ref().map(|v| &v[..]);
+        Ok(self.parse(|v| temporal::parse_date(v, items)))
+    }
+
+    /// Parse values to `Date32` like `parse_date`, but returns an error with the
+    /// location of the first value which can't be parsed. `Null` is kept as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["2017-03-01".to_string(), "x".to_string()]);
+    /// match v.try_parse_date(None).unwrap_err() {
+    ///     TryParseError::Value(err) => {
+    ///         assert_eq!(err.row(), 1);
+    ///         assert_eq!(err.value(), "x");
+    ///     }
+    ///     TryParseError::Format(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn try_parse_date(&self, format: Option<&str>) -> Result<NullVec<Date32>, TryParseError> {
+        let items = temporal::format_items(format)?;
+        let items = items.as_ref().map(|v| &v[..]);
+        Ok(self.try_parse(|v| temporal::parse_date(v, items))?)
+    }
+
+    /// Parse values to `Timestamp` in `unit` with strftime-like `format`, or ISO 8601
+    /// if `format` is `None`. Values which can't be parsed become `Null`.
+    /// Returns an error if `format` contains unsupported directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["01/03/2017 12:00".to_string()]);
+    /// let res = v.parse_datetime(Some("%d/%m/%Y %H:%M"), TimeUnit::Second).unwrap();
+    /// assert_eq!(res.dt().strftime("%Y-%m-%dT%H:%M:%S").unwrap(),
+    ///            NullVec::new(vec!["2017-03-01T12:00:00".to_string()]));
+    /// ```
+    pub fn parse_datetime(
+        &self,
+        format: Option<&str>,
+        unit: TimeUnit,
+    ) -> Result<NullVec<Timestamp>, FormatError> {
+        let items = temporal::format_items(format)?;
+        let items = items.as_ref().map(|v| &v[..]);
+        Ok(self.parse(|v| temporal::parse_timestamp(v, items, unit)))
+    }
+
+    /// Parse values to `Timestamp` like `parse_datetime`, but returns an error with
+    /// the location of the first value which can't be parsed. `Null` is kept as it is.
+    pub fn try_parse_datetime(
+        &self,
+        format: Option<&str>,
+        unit: TimeUnit,
+    ) -> Result<NullVec<Timestamp>, TryParseError> {
+        let items = temporal::format_items(format)?;
+        let items = items.as_ref().map(|v| &v[..]);
+        Ok(self.try_parse(|v| temporal::parse_timestamp(v, items, unit))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::NullVec;
+    use temporal::{Date32, Frequency, ParseDatetimeError, TimeUnit, Timestamp, TryParseError};
+
+    #[test]
+    fn test_epoch_conversion() {
+        let values = NullVec::with_mask(vec![90_061_500, 0, -1], Some(vec![false, true, false]));
+        let ts = values.to_timestamp(TimeUnit::Millisecond);
+        assert_eq!(ts.dt().day().data, vec![2, 0, 31]);
+        assert_eq!(ts.dt().hour().data, vec![1, 0, 23]);
+        assert_eq!(ts.to_epoch(TimeUnit::Millisecond), values);
+
+        let res = ts.to_epoch(TimeUnit::Second);
+        assert_eq!(res.data, vec![90_061, 0, -1]);
+        assert_eq!(res.mask, Some(vec![false, true, false]));
+    }
+
+    #[test]
+    fn test_date_components() {
+        let values = NullVec::with_mask(
+            vec![
+                Date32::from_ymd(2016, 12, 31),
+                Date32::from_ymd(2017, 3, 1),
+                Date32::from_ymd(1900, 2, 28),
+                Date32::default(),
+            ],
+            Some(vec![false, false, false, true]),
+        );
+        let mask = Some(vec![false, false, false, true]);
+        let dt = values.dt();
+        assert_eq!(
+            dt.year(),
+            NullVec::with_mask(vec![2016, 2017, 1900, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.month(),
+            NullVec::with_mask(vec![12, 3, 2, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.day(),
+            NullVec::with_mask(vec![31, 1, 28, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.hour(),
+            NullVec::with_mask(vec![0, 0, 0, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.weekday(),
+            NullVec::with_mask(vec![5, 2, 2, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.day_of_year(),
+            NullVec::with_mask(vec![366, 60, 59, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.quarter(),
+            NullVec::with_mask(vec![4, 1, 1, 0], mask.clone())
+        );
+        assert_eq!(
+            dt.is_leap_year(),
+            NullVec::with_mask(vec![true, false, false, false], mask)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_components() {
+        // 2017-07-14T02:40:05.250Z
+        let t = Timestamp::new(1_500_000_005_250, TimeUnit::Millisecond);
+        let values = NullVec::new(vec![t, t.with_offset(-3 * 3_600)]);
+        let dt = values.dt();
+        assert_eq!(dt.year(), NullVec::new(vec![2017, 2017]));
+        assert_eq!(dt.day(), NullVec::new(vec![14, 13]));
+        assert_eq!(dt.hour(), NullVec::new(vec![2, 23]));
+        assert_eq!(dt.minute(), NullVec::new(vec![40, 40]));
+        assert_eq!(dt.second(), NullVec::new(vec![5, 5]));
+        assert_eq!(dt.weekday(), NullVec::new(vec![4, 3]));
+
+        let values = NullVec::new(vec![Timestamp::new(-1, TimeUnit::Nanosecond)]);
+        let dt = values.dt();
+        assert_eq!(dt.year(), NullVec::new(vec![1969]));
+        assert_eq!(dt.second(), NullVec::new(vec![59]));
+    }
+
+    #[test]
+    fn test_truncate_round() {
+        let values = NullVec::with_mask(
+            vec![
+                Timestamp::new(5_430, TimeUnit::Second),
+                Timestamp::new(0, TimeUnit::Second),
+            ],
+            Some(vec![false, true]),
+        );
+        let res = values.dt().truncate(Frequency::Hour);
+        assert_eq!(res.is_null(), vec![false, true]);
+        assert_eq!(
+            res.not_null_values(),
+            vec![Timestamp::new(3_600, TimeUnit::Second)]
+        );
+
+        let res = values.dt().round(Frequency::Minute);
+        assert_eq!(
+            res.not_null_values(),
+            vec![Timestamp::new(5_460, TimeUnit::Second)]
+        );
+
+        // overflow results in Null
+        let values = NullVec::new(vec![
+            Timestamp::new(i64::MAX, TimeUnit::Nanosecond),
+            Timestamp::new(0, TimeUnit::Nanosecond),
+        ]);
+        assert_eq!(
+            values.dt().round(Frequency::Hour).is_null(),
+            vec![true, false]
+        );
+        assert_eq!(
+            values.dt().truncate(Frequency::Hour).is_null(),
+            vec![false, false]
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let values = NullVec::with_mask(
+            vec![
+                "2017-03-01".to_string(),
+                "2017-02-30".to_string(),
+                "".to_string(),
+                "1999-12-31T23:59:59Z".to_string(),
+            ],
+            Some(vec![false, false, true, false]),
+        );
+        let res = values.parse_date(None).unwrap();
+        assert_eq!(res.is_null(), vec![false, true, true, false]);
+        assert_eq!(
+            res.not_null_values(),
+            vec![Date32::from_ymd(2017, 3, 1), Date32::from_ymd(1999, 12, 31)]
+        );
+
+        let err = values.try_parse_date(None).unwrap_err();
+        assert_eq!(
+            err,
+            TryParseError::Value(ParseDatetimeError::new(1, "2017-02-30".to_string()))
+        );
+        assert_eq!(err.to_string(), "unable to parse \"2017-02-30\" at row 1");
+
+        // Null is not an error
+        let values = NullVec::with_mask(
+            vec!["03/01/2017".to_string(), "x".to_string()],
+            Some(vec![false, true]),
+        );
+        let res = values.try_parse_date(Some("%m/%d/%Y")).unwrap();
+        assert_eq!(res.is_null(), vec![false, true]);
+        assert_eq!(res.not_null_values(), vec![Date32::from_ymd(2017, 3, 1)]);
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let values = NullVec::new(vec![
+            "2017-03-01 12:00:00.5".to_string(),
+            "2017-03-01T12:00:00+09:00".to_string(),
+            "12:00".to_string(),
+        ]);
+        let res = values.parse_datetime(None, TimeUnit::Millisecond).unwrap();
+        assert_eq!(res.is_null(), vec![false, false, true]);
+        assert_eq!(
+            res.dt().strftime("%Y-%m-%d %H:%M:%S.%f%z").unwrap(),
+            NullVec::with_mask(
+                vec![
+                    "2017-03-01 12:00:00.500000000".to_string(),
+                    "2017-03-01 12:00:00.000000000+0900".to_string(),
+                    "".to_string(),
+                ],
+                Some(vec![false, false, true])
+            )
+        );
+
+        let err = values
+            .try_parse_datetime(None, TimeUnit::Millisecond)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TryParseError::Value(ParseDatetimeError::new(2, "12:00".to_string()))
+        );
+
+        let err = values
+            .try_parse_datetime(Some("%H:%M"), TimeUnit::Second)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to parse \"2017-03-01 12:00:00.5\" at row 0"
+        );
+
+        // unsupported directive is reported before parsing values
+        let err = values
+            .parse_datetime(Some("%y"), TimeUnit::Second)
+            .unwrap_err();
+        assert_eq!(err.directive(), "%y");
+        let err = values
+            .try_parse_datetime(Some("%Y-%"), TimeUnit::Second)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unsupported directive: %");
+        assert!(NullVec::<String>::new(vec![])
+            .parse_date(Some("%y"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_strftime() {
+        let values = NullVec::with_mask(
+            vec![
+                Timestamp::new(1_500_000_005, TimeUnit::Second),
+                Timestamp::default(),
+            ],
+            Some(vec![false, true]),
+        );
+        let res = values.dt().strftime("%Y/%m/%d %H:%M:%S").unwrap();
+        assert_eq!(res.is_null(), vec![false, true]);
+        assert_eq!(
+            res.not_null_values(),
+            vec!["2017/07/14 02:40:05".to_string()]
+        );
+
+        // round trip
+        let parsed = res
+            .parse_datetime(Some("%Y/%m/%d %H:%M:%S"), TimeUnit::Second)
+            .unwrap();
+        assert_eq!(parsed, values);
+
+        let err = values.dt().strftime("%Y %").unwrap_err();
+        assert_eq!(err.directive(), "%");
+    }
+}
@@ -152,8 +152,8 @@ where
             // ToDo: consider perf
             match self.data.mask {
                 Some(ref mask) => {
-                    while (unsafe { *mask.get_unchecked(self.current) } == true) &
-                        (self.current < self.data.len())
+                    while (self.current < self.data.len()) &&
+                        (unsafe { *mask.get_unchecked(self.current) } == true)
                     {
                         self.current += 1;
                     }
@@ -273,6 +273,19 @@ mod tests {
         assert_eq!(it.size_hint(), (0, Some(0)));
     }
 
+    #[test]
+    fn test_iter_not_null_trailing_nulls() {
+        // the mask must not be read past the end, which Miri can detect
+        let nvec = NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, true]));
+        assert_eq!(nvec.iter_not_null().collect::<Vec<&i32>>(), vec![&1]);
+
+        let nvec = NullVec::with_mask(vec![1, 2], Some(vec![true, true]));
+        let mut it = nvec.iter_not_null();
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, Some(0)));
+    }
+
     #[test]
     fn test_iter_not_null_long() {
         let values: Vec<usize> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
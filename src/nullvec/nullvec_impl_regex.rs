@@ -0,0 +1,207 @@
+use regex::{Error, Regex};
+
+use super::nullvec_impl_str::StringMethods;
+use super::NullVec;
+use nullable::Nullable;
+
+/// Regular expression methods, enabled by `regex` feature.
+///
+/// `pattern` is compiled once per method call. All methods return `regex::Error`
+/// if `pattern` is not a valid regular expression.
+impl<'a> StringMethods<'a> {
+    /// Returns whether values match `pattern` at any location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a1".to_string(), "b".to_string()]);
+    /// assert_eq!(v.str().matches(r"\d").unwrap(), NullVec::new(vec![true, false]));
+    /// ```
+    pub fn matches(&self, pattern: &str) -> Result<NullVec<bool>, Error> {
+        let re = Regex::new(pattern)?;
+        Ok(self.apply(|s| re.is_match(s)))
+    }
+
+    /// Returns the capture `group` of the first match of `pattern`.
+    ///
+    /// Group `0` is the whole match. The result is `Null` if a value doesn't match
+    /// or the group doesn't participate in the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a-1".to_string(), "b".to_string()]);
+    /// assert_eq!(v.str().extract(r"(\w)-(\d)", 2).unwrap(),
+    ///            NullVec::with_mask(vec!["1".to_string(), "".to_string()],
+    ///                               Some(vec![false, true])));
+    /// ```
+    pub fn extract(&self, pattern: &str, group: usize) -> Result<NullVec<String>, Error> {
+        let re = Regex::new(pattern)?;
+        Ok(self
+            .data
+            .iter_raw()
+            .map(|(m, v)| {
+                if m {
+                    return Nullable::Null;
+                }
+                match re.captures(v).and_then(|c| c.get(group)) {
+                    Some(g) => Nullable::Value(g.as_str().to_string()),
+                    None => Nullable::Null,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the capture `group` of all matches of `pattern`.
+    ///
+    /// Returns a tuple of 2 elements:
+    ///
+    /// - first element is flattened captured values. If the group doesn't participate
+    ///   in a match, corresponding element is `Null`.
+    /// - second element is the location of the value which each match belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a1b2".to_string(), "c".to_string(), "d3".to_string()]);
+    /// let (values, locations) = v.str().extract_all(r"\d", 0).unwrap();
+    /// assert_eq!(values, NullVec::new(vec!["1".to_string(), "2".to_string(), "3".to_string()]));
+    /// assert_eq!(locations, vec![0, 0, 2]);
+    /// ```
+    pub fn extract_all(
+        &self,
+        pattern: &str,
+        group: usize,
+    ) -> Result<(NullVec<String>, Vec<usize>), Error> {
+        let re = Regex::new(pattern)?;
+        let mut values: Vec<Nullable<String>> = vec![];
+        let mut locations: Vec<usize> = vec![];
+        for (i, (m, v)) in self.data.iter_raw().enumerate() {
+            if m {
+                continue;
+            }
+            for c in re.captures_iter(v) {
+                match c.get(group) {
+                    Some(g) => values.push(Nullable::Value(g.as_str().to_string())),
+                    None => values.push(Nullable::Null),
+                }
+                locations.push(i);
+            }
+        }
+        Ok((values.into(), locations))
+    }
+
+    /// Returns values replacing all matches of `pattern` with `rep`.
+    ///
+    /// `rep` can refer capture groups, such as `$1` or `${name}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a-1".to_string(), "b".to_string()]);
+    /// assert_eq!(v.str().replace_regex(r"(\w)-(\d)", "$2$1").unwrap(),
+    ///            NullVec::new(vec!["1a".to_string(), "b".to_string()]));
+    /// ```
+    pub fn replace_regex(&self, pattern: &str, rep: &str) -> Result<NullVec<String>, Error> {
+        let re = Regex::new(pattern)?;
+        Ok(self.apply(|s| re.replace_all(s, rep).into_owned()))
+    }
+
+    /// Returns the number of non-overlapping matches of `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a1b2".to_string(), "c".to_string()]);
+    /// assert_eq!(v.str().count_matches(r"\d").unwrap(), NullVec::new(vec![2, 0]));
+    /// ```
+    pub fn count_matches(&self, pattern: &str) -> Result<NullVec<usize>, Error> {
+        let re = Regex::new(pattern)?;
+        Ok(self.apply(|s| re.find_iter(s).count()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::NullVec;
+
+    fn strings(values: Vec<&str>) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_matches() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["abc", "xyz", "abc"]),
+            Some(vec![false, false, true]),
+        );
+        let res = nvec.str().matches("^a.c$").unwrap();
+        assert_eq!(res.data, vec![true, false, false]);
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let nvec = NullVec::new(strings(vec!["abc"]));
+        assert!(nvec.str().matches("(").is_err());
+        assert!(nvec.str().extract("(", 0).is_err());
+        assert!(nvec.str().extract_all("(", 0).is_err());
+        assert!(nvec.str().replace_regex("(", "").is_err());
+        assert!(nvec.str().count_matches("(").is_err());
+    }
+
+    #[test]
+    fn test_extract() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["k1=v1", "k2", "k3=v3", "k4=v4"]),
+            Some(vec![false, false, false, true]),
+        );
+        let res = nvec.str().extract(r"(\w+)=(\w+)", 2).unwrap();
+        assert_eq!(res.data, strings(vec!["v1", "", "v3", ""]));
+        assert_eq!(res.mask, Some(vec![false, true, false, true]));
+
+        // optional group which doesn't participate
+        let res = nvec.str().extract(r"(\w+)(=x)?", 2).unwrap();
+        assert_eq!(res.mask, Some(vec![true, true, true, true]));
+    }
+
+    #[test]
+    fn test_extract_all() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["a1b22", "c", "d3", "e4"]),
+            Some(vec![false, false, false, true]),
+        );
+        let (values, locations) = nvec.str().extract_all(r"[a-z](\d+)", 1).unwrap();
+        assert_eq!(values.data, strings(vec!["1", "22", "3"]));
+        assert_eq!(values.mask, None);
+        assert_eq!(locations, vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn test_replace_regex() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["a  b", "c d", "e"]),
+            Some(vec![false, false, true]),
+        );
+        let res = nvec.str().replace_regex(r"\s+", "_").unwrap();
+        assert_eq!(res.data, strings(vec!["a_b", "c_d", ""]));
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+    }
+
+    #[test]
+    fn test_count_matches() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["aaa", "bab", "a"]),
+            Some(vec![false, false, true]),
+        );
+        let res = nvec.str().count_matches("a").unwrap();
+        assert_eq!(res.data, vec![3, 1, 0]);
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+    }
+}
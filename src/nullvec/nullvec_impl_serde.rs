@@ -0,0 +1,57 @@
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::NullVec;
+use nullable::Nullable;
+use traits::NullStorable;
+
+/// Serialized as a sequence of nullable values, `Null` corresponds to `None`
+/// (JSON `null`).
+impl<T: Clone + NullStorable + Serialize> Serialize for NullVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.data.len()))?;
+        for (null, v) in self.iter_raw() {
+            if null {
+                seq.serialize_element(&None::<&T>)?;
+            } else {
+                seq.serialize_element(&Some(v))?;
+            }
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: NullStorable + Deserialize<'de>> Deserialize<'de> for NullVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Nullable<T>>::deserialize(deserializer)?;
+        Ok(values.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::NullVec;
+    use serde_json;
+
+    #[test]
+    fn test_nullvec_roundtrip() {
+        let v = NullVec::with_mask(vec![1i64, 0, 3], Some(vec![false, true, false]));
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, "[1,null,3]");
+        assert_eq!(serde_json::from_str::<NullVec<i64>>(&s).unwrap(), v);
+
+        let v = NullVec::new(vec!["a".to_string(), "b".to_string()]);
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, "[\"a\",\"b\"]");
+        assert_eq!(serde_json::from_str::<NullVec<String>>(&s).unwrap(), v);
+    }
+
+    #[test]
+    fn test_nullvec_float() {
+        let v = NullVec::new(vec![1.5, f64::NAN]);
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, "[1.5,null]");
+        assert_eq!(serde_json::from_str::<NullVec<f64>>(&s).unwrap(), v);
+    }
+}
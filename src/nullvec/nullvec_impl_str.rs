@@ -0,0 +1,384 @@
+use super::NullVec;
+use traits::{NullStorable, Slicer};
+
+/// Side to pad strings, used in `StringMethods::pad`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PadSide {
+    /// Pad on the left, values are right-aligned
+    Left,
+    /// Pad on the right, values are left-aligned
+    Right,
+    /// Pad on both sides, values are centered
+    Both,
+}
+
+/// Vectorized string methods of `NullVec<String>`, created by `NullVec::str`.
+///
+/// All methods propagate `Null`.
+#[derive(Clone, Debug)]
+pub struct StringMethods<'a> {
+    pub(super) data: &'a NullVec<String>,
+}
+
+impl NullVec<String> {
+    /// Returns vectorized string methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec!["Aa".to_string(), "b".to_string()],
+    ///                            Some(vec![false, true]));
+    /// assert_eq!(v.str().to_lowercase(),
+    ///            NullVec::with_mask(vec!["aa".to_string(), "".to_string()],
+    ///                               Some(vec![false, true])));
+    /// assert_eq!(v.str().len(), NullVec::with_mask(vec![2, 0], Some(vec![false, true])));
+    /// ```
+    pub fn str(&self) -> StringMethods<'_> {
+        StringMethods { data: self }
+    }
+}
+
+impl<'a> StringMethods<'a> {
+    /// Apply `func` to non-null values, keeping the mask
+    pub(super) fn apply<R, F>(&self, func: F) -> NullVec<R>
+    where
+        R: NullStorable,
+        F: Fn(&str) -> R,
+    {
+        let new_data: Vec<R> = self
+            .data
+            .iter_raw()
+            .map(|(m, v)| if m { R::default() } else { func(v) })
+            .collect();
+        NullVec {
+            data: new_data,
+            mask: self.data.mask.clone(),
+        }
+    }
+
+    /// Returns the number of characters.
+    pub fn len(&self) -> NullVec<usize> {
+        self.apply(|s| s.chars().count())
+    }
+
+    /// Returns whether values are empty strings.
+    pub fn is_empty(&self) -> NullVec<bool> {
+        self.apply(|s| s.is_empty())
+    }
+
+    /// Returns values converted to lowercase.
+    pub fn to_lowercase(&self) -> NullVec<String> {
+        self.apply(|s| s.to_lowercase())
+    }
+
+    /// Returns values converted to uppercase.
+    pub fn to_uppercase(&self) -> NullVec<String> {
+        self.apply(|s| s.to_uppercase())
+    }
+
+    /// Returns values removing leading and trailing whitespaces.
+    pub fn trim(&self) -> NullVec<String> {
+        self.apply(|s| s.trim().to_string())
+    }
+
+    /// Returns whether values contain `pat`.
+    pub fn contains(&self, pat: &str) -> NullVec<bool> {
+        self.apply(|s| s.contains(pat))
+    }
+
+    /// Returns whether values start with `pat`.
+    pub fn starts_with(&self, pat: &str) -> NullVec<bool> {
+        self.apply(|s| s.starts_with(pat))
+    }
+
+    /// Returns whether values end with `pat`.
+    pub fn ends_with(&self, pat: &str) -> NullVec<bool> {
+        self.apply(|s| s.ends_with(pat))
+    }
+
+    /// Returns values replacing all matches of `from` with `to`.
+    pub fn replace(&self, from: &str, to: &str) -> NullVec<String> {
+        self.apply(|s| s.replace(from, to))
+    }
+
+    /// Returns the `n`-th (0-based) element of values split by `pat`.
+    ///
+    /// The result is `Null` if a value has less than `n + 1` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["a-b".to_string(), "c".to_string()]);
+    /// assert_eq!(v.str().split("-", 1),
+    ///            NullVec::with_mask(vec!["b".to_string(), "".to_string()],
+    ///                               Some(vec![false, true])));
+    /// ```
+    pub fn split(&self, pat: &str, n: usize) -> NullVec<String> {
+        let new_values: Vec<Option<String>> = self
+            .data
+            .iter_raw()
+            .map(|(m, v)| {
+                if m {
+                    None
+                } else {
+                    v.split(pat).nth(n).map(|x| x.to_string())
+                }
+            })
+            .collect();
+        let new_mask: Vec<bool> = new_values.iter().map(|x| x.is_none()).collect();
+        let new_data: Vec<String> = new_values
+            .into_iter()
+            .map(|x| x.unwrap_or_default())
+            .collect();
+        if new_mask.iter().any(|&m| m) {
+            NullVec::with_mask(new_data, Some(new_mask))
+        } else {
+            NullVec::new(new_data)
+        }
+    }
+
+    /// Returns substrings from `start` to `end` (exclusive) based on characters.
+    ///
+    /// If `end` is `None`, substrings are taken to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["abcd".to_string(), "e".to_string()]);
+    /// assert_eq!(v.str().slice(1, Some(3)),
+    ///            NullVec::new(vec!["bc".to_string(), "".to_string()]));
+    /// ```
+    pub fn slice(&self, start: usize, end: Option<usize>) -> NullVec<String> {
+        self.apply(|s| match end {
+            Some(e) => s
+                .chars()
+                .skip(start)
+                .take(e.saturating_sub(start))
+                .collect(),
+            None => s.chars().skip(start).collect(),
+        })
+    }
+
+    /// Returns values padded with `fillchar` to `width` characters.
+    ///
+    /// Values longer than `width` are not truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec!["ab".to_string()]);
+    /// assert_eq!(v.str().pad(5, PadSide::Both, '*'), NullVec::new(vec!["*ab**".to_string()]));
+    /// ```
+    pub fn pad(&self, width: usize, side: PadSide, fillchar: char) -> NullVec<String> {
+        self.apply(|s| {
+            let len = s.chars().count();
+            if len >= width {
+                return s.to_string();
+            }
+            let n = width - len;
+            let (left, right) = match side {
+                PadSide::Left => (n, 0),
+                PadSide::Right => (0, n),
+                PadSide::Both => (n / 2, n - n / 2),
+            };
+            let mut padded = String::with_capacity(s.len() + n * fillchar.len_utf8());
+            padded.extend(::std::iter::repeat_n(fillchar, left));
+            padded.push_str(s);
+            padded.extend(::std::iter::repeat_n(fillchar, right));
+            padded
+        })
+    }
+
+    /// Returns values repeated `n` times.
+    pub fn repeat(&self, n: usize) -> NullVec<String> {
+        self.apply(|s| s.repeat(n))
+    }
+
+    /// Returns values concatenated with the corresponding values of `other`.
+    ///
+    /// # Panics
+    ///
+    /// - if `other` has different length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v1 = NullVec::new(vec!["a".to_string(), "b".to_string()]);
+    /// let v2 = NullVec::with_mask(vec!["c".to_string(), "d".to_string()],
+    ///                             Some(vec![false, true]));
+    /// assert_eq!(v1.str().concat(&v2),
+    ///            NullVec::with_mask(vec!["ac".to_string(), "".to_string()],
+    ///                               Some(vec![false, true])));
+    /// ```
+    pub fn concat(&self, other: &NullVec<String>) -> NullVec<String> {
+        assert!(
+            self.data.len() == other.len(),
+            "lhs and rhs must be the same length"
+        );
+        let (new_data, new_mask): (Vec<String>, Vec<bool>) = self
+            .data
+            .iter_raw()
+            .zip(other.iter_raw())
+            .map(|((lm, lv), (rm, rv))| {
+                if lm || rm {
+                    (String::new(), true)
+                } else {
+                    (format!("{}{}", lv, rv), false)
+                }
+            })
+            .unzip();
+        if new_mask.iter().any(|&m| m) {
+            NullVec::with_mask(new_data, Some(new_mask))
+        } else {
+            NullVec::new(new_data)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::PadSide;
+    use nullvec::NullVec;
+
+    fn strings(values: Vec<&str>) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_case_and_trim() {
+        let nvec = NullVec::with_mask(
+            strings(vec![" aB ", "Cd", "x"]),
+            Some(vec![false, false, true]),
+        );
+
+        let res = nvec.str().to_lowercase();
+        assert_eq!(res.data, strings(vec![" ab ", "cd", ""]));
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+
+        let res = nvec.str().to_uppercase();
+        assert_eq!(res.data, strings(vec![" AB ", "CD", ""]));
+
+        let res = nvec.str().trim();
+        assert_eq!(res.data, strings(vec!["aB", "Cd", ""]));
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+    }
+
+    #[test]
+    fn test_len() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["abc", "", "あい"]),
+            Some(vec![false, false, false]),
+        );
+        let res = nvec.str().len();
+        assert_eq!(res.data, vec![3, 0, 2]);
+
+        let res = nvec.str().is_empty();
+        assert_eq!(res.data, vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_match() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["abc", "bcd", "abc"]),
+            Some(vec![false, false, true]),
+        );
+
+        let res = nvec.str().contains("bc");
+        assert_eq!(res.data, vec![true, true, false]);
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+
+        let res = nvec.str().starts_with("a");
+        assert_eq!(res.data, vec![true, false, false]);
+
+        let res = nvec.str().ends_with("d");
+        assert_eq!(res.data, vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_replace_repeat() {
+        let nvec = NullVec::new(strings(vec!["aba", "c"]));
+        assert_eq!(
+            nvec.str().replace("a", "x"),
+            NullVec::new(strings(vec!["xbx", "c"]))
+        );
+        assert_eq!(
+            nvec.str().repeat(2),
+            NullVec::new(strings(vec!["abaaba", "cc"]))
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let nvec = NullVec::with_mask(
+            strings(vec!["a,b,c", "d", "e,f"]),
+            Some(vec![false, false, true]),
+        );
+
+        let res = nvec.str().split(",", 0);
+        assert_eq!(res.data, strings(vec!["a", "d", ""]));
+        assert_eq!(res.mask, Some(vec![false, false, true]));
+
+        let res = nvec.str().split(",", 2);
+        assert_eq!(res.data, strings(vec!["c", "", ""]));
+        assert_eq!(res.mask, Some(vec![false, true, true]));
+    }
+
+    #[test]
+    fn test_slice() {
+        let nvec = NullVec::new(strings(vec!["abcde", "あいう", ""]));
+        assert_eq!(
+            nvec.str().slice(1, Some(3)),
+            NullVec::new(strings(vec!["bc", "いう", ""]))
+        );
+        assert_eq!(
+            nvec.str().slice(2, None),
+            NullVec::new(strings(vec!["cde", "う", ""]))
+        );
+        assert_eq!(
+            nvec.str().slice(3, Some(1)),
+            NullVec::new(strings(vec!["", "", ""]))
+        );
+    }
+
+    #[test]
+    fn test_pad() {
+        let nvec = NullVec::new(strings(vec!["ab", "abcd"]));
+        assert_eq!(
+            nvec.str().pad(3, PadSide::Left, ' '),
+            NullVec::new(strings(vec![" ab", "abcd"]))
+        );
+        assert_eq!(
+            nvec.str().pad(3, PadSide::Right, '-'),
+            NullVec::new(strings(vec!["ab-", "abcd"]))
+        );
+        assert_eq!(
+            nvec.str().pad(6, PadSide::Both, '*'),
+            NullVec::new(strings(vec!["**ab**", "*abcd*"]))
+        );
+    }
+
+    #[test]
+    fn test_concat() {
+        let nvec1 =
+            NullVec::with_mask(strings(vec!["a", "b", "c"]), Some(vec![false, true, false]));
+        let nvec2 =
+            NullVec::with_mask(strings(vec!["x", "y", "z"]), Some(vec![false, false, true]));
+        let res = nvec1.str().concat(&nvec2);
+        assert_eq!(res.data, strings(vec!["ax", "", ""]));
+        assert_eq!(res.mask, Some(vec![false, true, true]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_length_mismatch() {
+        let nvec1 = NullVec::new(strings(vec!["a", "b"]));
+        let nvec2 = NullVec::new(strings(vec!["x"]));
+        nvec1.str().concat(&nvec2);
+    }
+}
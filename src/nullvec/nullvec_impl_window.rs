@@ -0,0 +1,499 @@
+use num_traits::ToPrimitive;
+
+use super::NullVec;
+use nullable::Nullable;
+use traits::{NullStorable, Slicer, NumericAggregation, ComparisonAggregation};
+
+/// Decay of exponentially weighted window, which is converted to smoothing factor `alpha`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EwmDecay {
+    /// Smoothing factor directly, `0 < alpha <= 1`
+    Alpha(f64),
+    /// Span, `alpha = 2 / (span + 1)` where `span >= 1`
+    Span(f64),
+    /// Half-life, `alpha = 1 - exp(-ln(2) / halflife)` where `halflife > 0`
+    HalfLife(f64),
+    /// Center of mass, `alpha = 1 / (1 + com)` where `com >= 0`
+    Com(f64),
+}
+
+impl EwmDecay {
+    /// Return smoothing factor `alpha`.
+    ///
+    /// # Panics
+    ///
+    /// - if the value is out of its valid range
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            EwmDecay::Alpha(alpha) => {
+                assert!(alpha > 0. && alpha <= 1., "alpha must satisfy 0 < alpha <= 1");
+                alpha
+            }
+            EwmDecay::Span(span) => {
+                assert!(span >= 1., "span must satisfy span >= 1");
+                2. / (span + 1.)
+            }
+            EwmDecay::HalfLife(halflife) => {
+                assert!(halflife > 0., "halflife must satisfy halflife > 0");
+                1. - (-(2f64.ln()) / halflife).exp()
+            }
+            EwmDecay::Com(com) => {
+                assert!(com >= 0., "com must satisfy com >= 0");
+                1. / (1. + com)
+            }
+        }
+    }
+}
+
+/// Expanding window over `NullVec`, created by `NullVec::expanding`.
+///
+/// Each aggregation returns `NullVec` which has the same length as the caller.
+/// The i-th element is the aggregation of non-null values from the beginning to the
+/// i-th location, and is `Null` if the number of non-null values is less than `min_periods`.
+#[derive(Clone, Debug)]
+pub struct Expanding<'a, T: 'a + NullStorable> {
+    data: &'a NullVec<T>,
+    min_periods: usize,
+}
+
+/// Exponentially weighted window over `NullVec`, created by `NullVec::ewm`.
+///
+/// Each aggregation returns `NullVec<f64>` which has the same length as the caller,
+/// following pandas' definitions of `adjust` and `ignore_na`.
+#[derive(Clone, Debug)]
+pub struct Ewm<'a, T: 'a + NullStorable> {
+    data: &'a NullVec<T>,
+    alpha: f64,
+    adjust: bool,
+    ignore_nulls: bool,
+}
+
+impl<T: Clone + NullStorable> NullVec<T> {
+    /// Returns expanding window which aggregates values from the beginning.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_periods` - Minimum number of non-null values required to have a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![false, true, false, false]));
+    /// assert_eq!(v.expanding(1).mean(), NullVec::new(vec![1., 1., 2., 8. / 3.]));
+    /// assert_eq!(v.expanding(1).max(), NullVec::new(vec![1, 1, 3, 4]));
+    /// ```
+    pub fn expanding(&self, min_periods: usize) -> Expanding<'_, T> {
+        Expanding {
+            data: self,
+            min_periods,
+        }
+    }
+
+    /// Returns exponentially weighted window.
+    ///
+    /// # Parameters
+    ///
+    /// * `decay` - Decay to compute smoothing factor.
+    /// * `adjust` - Whether to divide by decaying adjustment factor in beginning periods.
+    /// * `ignore_nulls` - Whether to ignore `Null` when computing weights. If `false`,
+    ///   weights are based on absolute positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let v = NullVec::new(vec![1., 2., 3.]);
+    /// let res = v.ewm(EwmDecay::Alpha(0.5), false, false).mean();
+    /// assert_eq!(res, NullVec::new(vec![1., 1.5, 2.25]));
+    /// ```
+    pub fn ewm(&self, decay: EwmDecay, adjust: bool, ignore_nulls: bool) -> Ewm<'_, T> {
+        Ewm {
+            data: self,
+            alpha: decay.alpha(),
+            adjust,
+            ignore_nulls,
+        }
+    }
+}
+
+/// Convert values to `f64`, `Null` is represented as `NAN`
+fn to_f64_values<T>(data: &NullVec<T>) -> Vec<f64>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    data.iter_raw()
+        .map(|(m, v)| if m {
+            f64::NAN
+        } else {
+            ToPrimitive::to_f64(v).unwrap()
+        })
+        .collect()
+}
+
+impl<'a, T> Expanding<'a, T>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    /// Compute expanding variance using Welford's algorithm
+    fn var_impl(&self, ddof: usize) -> NullVec<f64> {
+        let mut count = 0usize;
+        let mut mean = 0.;
+        let mut m2 = 0.;
+        let mut result: Vec<f64> = Vec::with_capacity(self.data.len());
+        for v in to_f64_values(self.data) {
+            if !v.is_nan() {
+                count += 1;
+                let delta = v - mean;
+                mean += delta / count as f64;
+                m2 += delta * (v - mean);
+            }
+            if count >= self.min_periods && count > ddof {
+                result.push(m2 / (count - ddof) as f64);
+            } else {
+                result.push(f64::NAN);
+            }
+        }
+        NullVec::new(result)
+    }
+}
+
+fn sqrt_nullvec(values: NullVec<f64>) -> NullVec<f64> {
+    let mask = values.mask;
+    let new_values: Vec<f64> = values.data.into_iter().map(|x| x.sqrt()).collect();
+    NullVec::with_mask(new_values, mask)
+}
+
+impl<'a, T> NumericAggregation for Expanding<'a, T>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    type Coerced = NullVec<f64>;
+
+    fn mean(&self) -> Self::Coerced {
+        let mut count = 0usize;
+        let mut sum = 0.;
+        let mut result: Vec<f64> = Vec::with_capacity(self.data.len());
+        for v in to_f64_values(self.data) {
+            if !v.is_nan() {
+                count += 1;
+                sum += v;
+            }
+            if count >= self.min_periods && count > 0 {
+                result.push(sum / count as f64);
+            } else {
+                result.push(f64::NAN);
+            }
+        }
+        NullVec::new(result)
+    }
+
+    fn var(&self) -> Self::Coerced {
+        self.var_impl(0)
+    }
+
+    fn unbiased_var(&self) -> Self::Coerced {
+        self.var_impl(1)
+    }
+
+    fn std(&self) -> Self::Coerced {
+        sqrt_nullvec(self.var())
+    }
+
+    fn unbiased_std(&self) -> Self::Coerced {
+        sqrt_nullvec(self.unbiased_var())
+    }
+}
+
+impl<'a, T> Expanding<'a, T>
+where
+    T: Clone + PartialOrd + NullStorable,
+{
+    /// Compute expanding min or max, `replace` returns whether the current value
+    /// should be replaced by the new value
+    fn cum_select<F>(&self, replace: F) -> NullVec<T>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut count = 0usize;
+        let mut current: Option<T> = None;
+        let mut result: Vec<Nullable<T>> = Vec::with_capacity(self.data.len());
+        for (m, v) in self.data.iter_raw() {
+            if !m {
+                count += 1;
+                let is_replaced = match current {
+                    Some(ref c) => replace(c, v),
+                    None => true,
+                };
+                if is_replaced {
+                    current = Some(v.clone());
+                }
+            }
+            match current {
+                Some(ref c) if count >= self.min_periods => result.push(Nullable::Value(c.clone())),
+                _ => result.push(Nullable::Null),
+            }
+        }
+        result.into()
+    }
+}
+
+impl<'a, T> ComparisonAggregation for Expanding<'a, T>
+where
+    T: Clone + PartialOrd + NullStorable,
+{
+    type Kept = NullVec<T>;
+
+    fn min(&self) -> Self::Kept {
+        self.cum_select(|c, v| v < c)
+    }
+
+    fn max(&self) -> Self::Kept {
+        self.cum_select(|c, v| v > c)
+    }
+}
+
+impl<'a, T> Ewm<'a, T>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    /// Compute exponentially weighted variance, the same logic as pandas' `ewmcov`
+    fn var_impl(&self, bias: bool) -> NullVec<f64> {
+        let values = to_f64_values(self.data);
+        let mut result: Vec<f64> = Vec::with_capacity(values.len());
+        if values.is_empty() {
+            return NullVec::new(result);
+        }
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut mean = values[0];
+        let mut cov = 0.;
+        let mut sum_wt = 1.;
+        let mut sum_wt2 = 1.;
+        let mut old_wt = 1.;
+        result.push(if !mean.is_nan() && bias { 0. } else { f64::NAN });
+
+        for &cur in values[1..].iter() {
+            let is_observation = !cur.is_nan();
+            if !mean.is_nan() {
+                if is_observation || !self.ignore_nulls {
+                    sum_wt *= old_wt_factor;
+                    sum_wt2 *= old_wt_factor * old_wt_factor;
+                    old_wt *= old_wt_factor;
+                    if is_observation {
+                        let old_mean = mean;
+                        // avoid numerical errors on constant series
+                        if mean != cur {
+                            mean = (old_wt * old_mean + new_wt * cur) / (old_wt + new_wt);
+                        }
+                        cov = (old_wt * (cov + (old_mean - mean) * (old_mean - mean)) +
+                                   new_wt * (cur - mean) * (cur - mean)) /
+                            (old_wt + new_wt);
+                        sum_wt += new_wt;
+                        sum_wt2 += new_wt * new_wt;
+                        old_wt += new_wt;
+                        if !self.adjust {
+                            sum_wt /= old_wt;
+                            sum_wt2 /= old_wt * old_wt;
+                            old_wt = 1.;
+                        }
+                    }
+                }
+            } else if is_observation {
+                mean = cur;
+            }
+
+            if mean.is_nan() {
+                result.push(f64::NAN);
+            } else if bias {
+                result.push(cov);
+            } else {
+                let numerator = sum_wt * sum_wt;
+                let denominator = numerator - sum_wt2;
+                if denominator > 0. {
+                    result.push(numerator / denominator * cov);
+                } else {
+                    result.push(f64::NAN);
+                }
+            }
+        }
+        NullVec::new(result)
+    }
+}
+
+impl<'a, T> NumericAggregation for Ewm<'a, T>
+where
+    T: Clone + ToPrimitive + NullStorable,
+{
+    type Coerced = NullVec<f64>;
+
+    /// Exponentially weighted mean, the same logic as pandas' `ewma`
+    fn mean(&self) -> Self::Coerced {
+        let values = to_f64_values(self.data);
+        let mut result: Vec<f64> = Vec::with_capacity(values.len());
+        if values.is_empty() {
+            return NullVec::new(result);
+        }
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut weighted = values[0];
+        let mut old_wt = 1.;
+        result.push(weighted);
+
+        for &cur in values[1..].iter() {
+            let is_observation = !cur.is_nan();
+            if !weighted.is_nan() {
+                if is_observation || !self.ignore_nulls {
+                    old_wt *= old_wt_factor;
+                    if is_observation {
+                        // avoid numerical errors on constant series
+                        if weighted != cur {
+                            weighted = (old_wt * weighted + new_wt * cur) / (old_wt + new_wt);
+                        }
+                        if self.adjust {
+                            old_wt += new_wt;
+                        } else {
+                            old_wt = 1.;
+                        }
+                    }
+                }
+            } else if is_observation {
+                weighted = cur;
+            }
+            result.push(weighted);
+        }
+        NullVec::new(result)
+    }
+
+    /// Exponentially weighted biased variance
+    fn var(&self) -> Self::Coerced {
+        self.var_impl(true)
+    }
+
+    /// Exponentially weighted unbiased variance, which is pandas' default
+    fn unbiased_var(&self) -> Self::Coerced {
+        self.var_impl(false)
+    }
+
+    fn std(&self) -> Self::Coerced {
+        sqrt_nullvec(self.var())
+    }
+
+    fn unbiased_std(&self) -> Self::Coerced {
+        sqrt_nullvec(self.unbiased_var())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use nullvec::NullVec;
+    use traits::{NumericAggregation, ComparisonAggregation};
+    use super::EwmDecay;
+
+    fn assert_nullvec_approx(res: NullVec<f64>, exp: Vec<f64>) {
+        let exp = NullVec::new(exp);
+        assert_eq!(res.is_null(), exp.is_null());
+        for (r, e) in res.data.iter().zip(exp.data.iter()) {
+            assert!((r - e).abs() < 1e-6, "{} != {}", r, e);
+        }
+    }
+
+    #[test]
+    fn test_decay_alpha() {
+        assert_eq!(EwmDecay::Alpha(0.3).alpha(), 0.3);
+        assert_eq!(EwmDecay::Span(3.).alpha(), 0.5);
+        assert_eq!(EwmDecay::HalfLife(1.).alpha(), 0.5);
+        assert_eq!(EwmDecay::Com(1.).alpha(), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decay_alpha_invalid() {
+        EwmDecay::Alpha(1.5).alpha();
+    }
+
+    #[test]
+    fn test_expanding_mean() {
+        let nvec = NullVec::with_mask(vec![1, 2, 3, 4], Some(vec![true, false, true, false]));
+        assert_nullvec_approx(nvec.expanding(1).mean(), vec![f64::NAN, 2., 2., 3.]);
+        assert_nullvec_approx(nvec.expanding(2).mean(), vec![f64::NAN, f64::NAN, f64::NAN, 3.]);
+
+        let nvec: NullVec<f64> = NullVec::new(vec![]);
+        assert_eq!(nvec.expanding(1).mean(), NullVec::new(vec![]));
+    }
+
+    #[test]
+    fn test_expanding_var() {
+        let nvec = NullVec::new(vec![1., 2., f64::NAN, 4.]);
+        assert_nullvec_approx(nvec.expanding(1).var(), vec![0., 0.25, 0.25, 14. / 9.]);
+        assert_nullvec_approx(
+            nvec.expanding(1).unbiased_var(),
+            vec![f64::NAN, 0.5, 0.5, 7. / 3.],
+        );
+        assert_nullvec_approx(nvec.expanding(1).std(), vec![0., 0.5, 0.5, (14f64 / 9.).sqrt()]);
+        assert_nullvec_approx(
+            nvec.expanding(3).unbiased_std(),
+            vec![f64::NAN, f64::NAN, f64::NAN, (7f64 / 3.).sqrt()],
+        );
+    }
+
+    #[test]
+    fn test_expanding_min_max() {
+        let nvec = NullVec::with_mask(vec![3, 1, 0, 4], Some(vec![false, false, true, false]));
+        assert_eq!(nvec.expanding(1).min(), NullVec::new(vec![3, 1, 1, 1]));
+        assert_eq!(nvec.expanding(1).max(), NullVec::new(vec![3, 3, 3, 4]));
+        assert_eq!(
+            nvec.expanding(2).max(),
+            NullVec::with_mask(vec![0, 3, 3, 4], Some(vec![true, false, false, false]))
+        );
+    }
+
+    #[test]
+    fn test_ewm_mean() {
+        let nvec = NullVec::new(vec![0., 1., 2., f64::NAN, 4.]);
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Com(0.5), true, false).mean(),
+            vec![0., 0.75, 1.615385, 1.615385, 3.670213],
+        );
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Com(0.5), false, false).mean(),
+            vec![0., 0.666667, 1.555556, 1.555556, 3.650794],
+        );
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Com(0.5), true, true).mean(),
+            vec![0., 0.75, 1.615385, 1.615385, 3.225],
+        );
+    }
+
+    #[test]
+    fn test_ewm_mean_leading_null() {
+        let nvec = NullVec::with_mask(vec![1, 2, 4], Some(vec![true, false, false]));
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Alpha(0.5), false, false).mean(),
+            vec![f64::NAN, 2., 3.],
+        );
+    }
+
+    #[test]
+    fn test_ewm_var() {
+        let nvec = NullVec::new(vec![1., 2., 3.]);
+        // weights are 1, 0.5, 0.25 from the latest
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Alpha(0.5), true, false).var(),
+            vec![0., 2. / 9., 26. / 49.],
+        );
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Alpha(0.5), true, false).unbiased_var(),
+            vec![f64::NAN, 0.5, 26. / 49. * 49. / 28.],
+        );
+        assert_nullvec_approx(
+            nvec.ewm(EwmDecay::Alpha(0.5), true, false).std(),
+            vec![0., (2f64 / 9.).sqrt(), (26f64 / 49.).sqrt()],
+        );
+    }
+}
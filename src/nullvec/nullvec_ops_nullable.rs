@@ -70,6 +70,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     f64,
     f32
 );
@@ -93,6 +95,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     bool
 );
 
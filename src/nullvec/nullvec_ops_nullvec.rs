@@ -3,7 +3,7 @@ use std::ops::{Add, Sub, Mul, Div, Rem, BitAnd, BitOr, BitXor};
 use algos::vec_ops::Elemwise;
 use nullvec::NullVec;
 
-fn get_new_mask(x: &Option<Vec<bool>>, y: &Option<Vec<bool>>) -> Option<Vec<bool>> {
+pub(super) fn get_new_mask(x: &Option<Vec<bool>>, y: &Option<Vec<bool>>) -> Option<Vec<bool>> {
     match (x, y) {
         (&Some(ref xmask), &Some(ref ymask)) => {
             Some(Elemwise::elemwise_rr(xmask, ymask, |x, y| x | y))
@@ -76,6 +76,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     f64,
     f32
 );
@@ -99,6 +101,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     bool
 );
 
@@ -224,4 +228,21 @@ mod tests {
         assert_eq!(res.data, vec![true, false, true]);
         assert_eq!(res.mask, None);
     }
+
+    #[test]
+    fn test_int128() {
+        let nvec1: NullVec<u128> =
+            NullVec::with_mask(vec![u128::MAX - 1, 2], Some(vec![false, true]));
+        let nvec2: NullVec<u128> = NullVec::new(vec![1, 3]);
+
+        let res = &nvec1 + &nvec2;
+        assert_eq!(res.data, vec![u128::MAX, 5]);
+        assert_eq!(res.mask, Some(vec![false, true]));
+
+        let res = nvec1 ^ nvec2;
+        assert_eq!(res.data, vec![u128::MAX, 1]);
+
+        let nvec: NullVec<i128> = NullVec::new(vec![i128::MIN, -1]);
+        assert_eq!((nvec * 1).data, vec![i128::MIN, -1]);
+    }
 }
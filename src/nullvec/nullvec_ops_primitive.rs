@@ -65,6 +65,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     f64,
     f32
 );
@@ -88,6 +90,8 @@ macro_dispatch!(
     u16,
     u8,
     usize,
+    i128,
+    u128,
     bool
 );
 
@@ -0,0 +1,213 @@
+use std::ops::{Add, Sub};
+
+use num_traits::{CheckedAdd, CheckedSub};
+
+use nullable::Nullable;
+use nullvec::NullVec;
+use temporal::{Date32, Duration, Timestamp};
+use traits::{NullStorable, Slicer};
+
+/// Apply `func` to non-null pairs, results of `None` (overflow) become `Null`.
+fn checked_elemwise<L, R, O, F>(left: &NullVec<L>, right: &NullVec<R>, func: F) -> NullVec<O>
+where
+    L: Clone + NullStorable,
+    R: Clone + NullStorable,
+    O: NullStorable,
+    F: Fn(&L, &R) -> Option<O>,
+{
+    assert!(
+        left.len() == right.len(),
+        "lhs and rhs must be the same length"
+    );
+    left.iter_raw()
+        .zip(right.iter_raw())
+        .map(|((lm, l), (rm, r))| {
+            if lm || rm {
+                Nullable::Null
+            } else {
+                func(l, r).map_or(Nullable::Null, Nullable::Value)
+            }
+        })
+        .collect()
+}
+
+/// Apply `func` to non-null values and `right`, results of `None` (overflow)
+/// become `Null`.
+fn checked_broadcast<L, R, O, F>(left: &NullVec<L>, right: &R, func: F) -> NullVec<O>
+where
+    L: Clone + NullStorable,
+    O: NullStorable,
+    F: Fn(&L, &R) -> Option<O>,
+{
+    left.iter_raw()
+        .map(|(m, l)| {
+            if m {
+                Nullable::Null
+            } else {
+                func(l, right).map_or(Nullable::Null, Nullable::Value)
+            }
+        })
+        .collect()
+}
+
+macro_rules! add_temporal_elemwise_op {
+    ($l:ident, $r:ident, $o:ident, $tr:ident, $op:ident, $checked:ident) => {
+        // Nullvec + NullVec
+        impl $tr<NullVec<$r>> for NullVec<$l> {
+            type Output = NullVec<$o>;
+            fn $op(self, other: NullVec<$r>) -> NullVec<$o> {
+                checked_elemwise(&self, &other, |x, y| x.$checked(y))
+            }
+        }
+        // &Nullvec + &NullVec
+        impl<'a, 'b> $tr<&'a NullVec<$r>> for &'b NullVec<$l> {
+            type Output = NullVec<$o>;
+            fn $op(self, other: &NullVec<$r>) -> NullVec<$o> {
+                checked_elemwise(self, other, |x, y| x.$checked(y))
+            }
+        }
+        // Nullvec + Scalar
+        impl $tr<$r> for NullVec<$l> {
+            type Output = NullVec<$o>;
+            fn $op(self, other: $r) -> NullVec<$o> {
+                checked_broadcast(&self, &other, |x, y| x.$checked(y))
+            }
+        }
+        // &Nullvec + Scalar
+        impl<'b> $tr<$r> for &'b NullVec<$l> {
+            type Output = NullVec<$o>;
+            fn $op(self, other: $r) -> NullVec<$o> {
+                checked_broadcast(self, &other, |x, y| x.$checked(y))
+            }
+        }
+    };
+}
+
+// masked rows are skipped, and overflow results in Null
+add_temporal_elemwise_op!(Timestamp, Timestamp, Duration, Sub, sub, checked_duration_since);
+add_temporal_elemwise_op!(Timestamp, Duration, Timestamp, Add, add, checked_add_duration);
+add_temporal_elemwise_op!(Timestamp, Duration, Timestamp, Sub, sub, checked_sub_duration);
+add_temporal_elemwise_op!(Date32, Date32, Duration, Sub, sub, checked_duration_since);
+add_temporal_elemwise_op!(Duration, Duration, Duration, Add, add, checked_add);
+add_temporal_elemwise_op!(Duration, Duration, Duration, Sub, sub, checked_sub);
+
+#[cfg(test)]
+mod tests {
+
+    use nullable::Nullable;
+    use nullvec::NullVec;
+    use temporal::{Duration, TimeUnit, Timestamp};
+    use traits::{BasicAggregation, ComparisonAggregation};
+
+    fn timestamps(values: Vec<i64>, unit: TimeUnit) -> Vec<Timestamp> {
+        values
+            .into_iter()
+            .map(|v| Timestamp::new(v, unit))
+            .collect()
+    }
+
+    #[test]
+    fn test_timestamp_sub() {
+        let l = NullVec::with_mask(
+            timestamps(vec![10, 20, 30], TimeUnit::Second),
+            Some(vec![false, true, false]),
+        );
+        let r = NullVec::new(timestamps(vec![1_000, 2_000, 3_000], TimeUnit::Millisecond));
+        let res = &l - &r;
+        assert_eq!(res.is_null(), vec![false, true, false]);
+        assert_eq!(
+            res.not_null_values(),
+            vec![
+                Duration::new(9, TimeUnit::Second),
+                Duration::new(27, TimeUnit::Second),
+            ]
+        );
+
+        let res = l.clone() - Timestamp::new(10, TimeUnit::Second);
+        assert_eq!(
+            res.not_null_values(),
+            vec![
+                Duration::new(0, TimeUnit::Second),
+                Duration::new(20, TimeUnit::Second),
+            ]
+        );
+
+        let res = l - Duration::new(10, TimeUnit::Second);
+        assert_eq!(
+            res.not_null_values(),
+            timestamps(vec![0, 20], TimeUnit::Second)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_add() {
+        let l = NullVec::new(timestamps(vec![10, 20], TimeUnit::Second));
+        let r = NullVec::with_mask(
+            vec![
+                Duration::new(1, TimeUnit::Second),
+                Duration::new(2, TimeUnit::Second),
+            ],
+            Some(vec![true, false]),
+        );
+        let res = &l + &r;
+        assert_eq!(res.is_null(), vec![true, false]);
+        assert_eq!(
+            res.not_null_values(),
+            timestamps(vec![22], TimeUnit::Second)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let l = NullVec::with_mask(
+            vec![
+                Timestamp::new(i64::MAX, TimeUnit::Second),
+                Timestamp::new(i64::MIN, TimeUnit::Nanosecond),
+                Timestamp::new(1, TimeUnit::Second),
+            ],
+            Some(vec![true, false, false]),
+        );
+        let r = NullVec::new(timestamps(vec![0, 1, 1], TimeUnit::Nanosecond));
+        // masked rows are skipped even if their raw values overflow
+        let res = &l - &r;
+        assert_eq!(res.is_null(), vec![true, true, false]);
+        assert_eq!(res.not_null_values(), vec![Duration::new(999_999_999, TimeUnit::Nanosecond)]);
+
+        let res = &l + Duration::new(i64::MAX, TimeUnit::Nanosecond);
+        assert_eq!(res.is_null(), vec![true, false, true]);
+
+        let d = NullVec::new(vec![
+            Duration::new(i64::MAX, TimeUnit::Second),
+            Duration::new(1, TimeUnit::Second),
+        ]);
+        let res = &d - Duration::new(-1, TimeUnit::Second);
+        assert_eq!(res.is_null(), vec![true, false]);
+        assert_eq!(res.not_null_values(), vec![Duration::new(2, TimeUnit::Second)]);
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let values = NullVec::new(timestamps(vec![20, 10, 30], TimeUnit::Second));
+        assert_eq!(
+            values.min(),
+            Nullable::Value(Timestamp::new(10, TimeUnit::Second))
+        );
+        assert_eq!(
+            values.max(),
+            Nullable::Value(Timestamp::new(30, TimeUnit::Second))
+        );
+
+        let values = NullVec::new(vec![
+            Duration::new(1, TimeUnit::Second),
+            Duration::new(500, TimeUnit::Millisecond),
+        ]);
+        assert_eq!(
+            values.sum(),
+            Nullable::Value(Duration::new(1_500, TimeUnit::Millisecond))
+        );
+        assert_eq!(
+            values.min(),
+            Nullable::Value(Duration::new(500, TimeUnit::Millisecond))
+        );
+    }
+}
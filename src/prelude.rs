@@ -1,9 +1,22 @@
 
-pub use nullvec::NullVec;
+pub use nullvec::{NullVec, Expanding, Ewm, EwmDecay, Interpolation, NullCondition, Branch};
+pub use nullvec::{StringMethods, PadSide, DatetimeMethods};
+pub use columnar::{BinaryColumn, BinaryColumnIter, BinaryEncoding, Categorical, ListColumn,
+                   StructColumn, Utf8Column, Utf8ColumnIter};
 pub use nullable::Nullable;
 pub use nullable::Nullable::Null;
 
-pub use generic::{Array, Scalar};
+pub use generic::{Array, DtypeError, Scalar};
+pub use decimal::Decimal;
+pub use frame::DataFrame;
+pub use io::{ColumnFileReader, ColumnFileWriter, CsvChunks, CsvReader, CsvWriter, JsonLinesReader,
+             JsonLinesWriter, QuoteStyle};
+#[cfg(feature = "arrow-ipc")]
+pub use io::{ArrowFileReader, ArrowFileWriter, ArrowStreamReader, ArrowStreamWriter};
+#[cfg(feature = "mmap")]
+pub use io::{MappedColumn, MappedColumnFile, MappedColumnIter, MappedElement};
+pub use temporal::{Date32, Duration, FormatError, Frequency, ParseDatetimeError, TimeUnit,
+                   Timestamp, TryParseError};
 
 pub use traits::{Slicer, BasicAggregation, NumericAggregation, ComparisonAggregation, Append};
 
@@ -11,6 +24,7 @@ pub use traits::{Slicer, BasicAggregation, NumericAggregation, ComparisonAggrega
 /// Users doesn't need to use it.
 pub mod dev {
     pub use traits::{NullStorable, Stringify};
+    pub use temporal::DateLike;
 
     pub mod algos {
         pub use algos::indexing::Indexing;
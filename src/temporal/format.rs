@@ -0,0 +1,656 @@
+//! Parsing and formatting of temporal values with strftime-like formats.
+//!
+//! Supported directives are:
+//!
+//! - `%Y`: year with 4 digits, optionally preceded by `-`
+//! - `%m`: month, `01` to `12`
+//! - `%d`: day of the month, `01` to `31`
+//! - `%H`: hour, `00` to `23`
+//! - `%M`: minute, `00` to `59`
+//! - `%S`: second, `00` to `59`
+//! - `%f`: fractional seconds, up to 9 digits when parsing and 9 digits when formatting
+//! - `%z`: UTC offset such as `+09`, `+0900`, `+09:00` or `Z` when parsing and `+0900`
+//!   when formatting
+//! - `%%`: literal `%`
+//!
+//! Parsing with a format containing other directives fails like an invalid value,
+//! and formatting returns `FormatError`.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{
+    civil_from_days, days_from_civil, days_in_month, Date32, DateLike, TimeUnit, Timestamp,
+    SECONDS_PER_DAY,
+};
+
+/// Error returned when a value in strict parsing can't be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseDatetimeError {
+    row: usize,
+    value: String,
+}
+
+impl ParseDatetimeError {
+    pub fn new(row: usize, value: String) -> Self {
+        ParseDatetimeError { row, value }
+    }
+
+    /// Returns the location of the value which can't be parsed.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the value which can't be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseDatetimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unable to parse {:?} at row {}", self.value, self.row)
+    }
+}
+
+impl Error for ParseDatetimeError {}
+
+/// Error returned when a format contains unsupported directive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatError {
+    directive: String,
+}
+
+impl FormatError {
+    /// Returns the unsupported directive, such as `%y`.
+    pub fn directive(&self) -> &str {
+        &self.directive
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported directive: {}", self.directive)
+    }
+}
+
+impl Error for FormatError {}
+
+/// Error returned by strict parsing of `NullVec<String>`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TryParseError {
+    /// The format contains unsupported directive
+    Format(FormatError),
+    /// A value can't be parsed
+    Value(ParseDatetimeError),
+}
+
+impl From<FormatError> for TryParseError {
+    fn from(err: FormatError) -> Self {
+        TryParseError::Format(err)
+    }
+}
+
+impl From<ParseDatetimeError> for TryParseError {
+    fn from(err: ParseDatetimeError) -> Self {
+        TryParseError::Value(err)
+    }
+}
+
+impl fmt::Display for TryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryParseError::Format(ref err) => err.fmt(f),
+            TryParseError::Value(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for TryParseError {}
+
+/// Element of a strftime-like format
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Item {
+    Literal(char),
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Fraction,
+    Offset,
+}
+
+/// Split `format` into `Item`s, returning an error on unsupported directive.
+pub(crate) fn items(format: &str) -> Result<Vec<Item>, FormatError> {
+    let mut items = Vec::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            items.push(Item::Literal(c));
+            continue;
+        }
+        let item = match chars.next() {
+            Some('Y') => Item::Year,
+            Some('m') => Item::Month,
+            Some('d') => Item::Day,
+            Some('H') => Item::Hour,
+            Some('M') => Item::Minute,
+            Some('S') => Item::Second,
+            Some('f') => Item::Fraction,
+            Some('z') => Item::Offset,
+            Some('%') => Item::Literal('%'),
+            d => {
+                return Err(FormatError {
+                    directive: d.map_or("%".to_string(), |d| format!("%{}", d)),
+                })
+            }
+        };
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// Split `format` into `Item`s if specified, `None` means ISO 8601.
+pub(crate) fn format_items(format: Option<&str>) -> Result<Option<Vec<Item>>, FormatError> {
+    format.map(items).transpose()
+}
+
+/// Date and time fields parsed from a string
+#[derive(Debug, Default)]
+struct Parsed {
+    year: Option<i64>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+    offset: Option<i32>,
+}
+
+impl Parsed {
+    /// Returns the number of days since the epoch of the local date.
+    fn days(&self) -> Option<i64> {
+        let (year, month, day) = match (self.year, self.month, self.day) {
+            (Some(y), Some(m), Some(d)) => (y, m, d),
+            _ => return None,
+        };
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        if self.hour > 23 || self.minute > 59 || self.second > 59 {
+            return None;
+        }
+        Some(days_from_civil(year, month, day))
+    }
+
+    fn to_date(&self) -> Option<Date32> {
+        let days = self.days()?;
+        if days < i64::from(i32::MIN) || days > i64::from(i32::MAX) {
+            return None;
+        }
+        Some(Date32::from_days(days as i32))
+    }
+
+    fn to_timestamp(&self, unit: TimeUnit) -> Option<Timestamp> {
+        let seconds = self.days()? * SECONDS_PER_DAY
+            + i64::from(self.hour * 3_600 + self.minute * 60 + self.second)
+            - i64::from(self.offset.unwrap_or(0));
+        let per_second = unit.per_second();
+        let value = seconds
+            .checked_mul(per_second)?
+            .checked_add(i64::from(self.nanosecond) / (1_000_000_000 / per_second))?;
+        let timestamp = Timestamp::new(value, unit);
+        match self.offset {
+            Some(offset) => Some(timestamp.with_offset(offset)),
+            None => Some(timestamp),
+        }
+    }
+}
+
+/// Cursor to consume a string from the beginning
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(value: &'a str) -> Self {
+        Cursor {
+            bytes: value.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).cloned()
+    }
+
+    /// Consume `expected` if the next byte is the same
+    fn consume(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume `min` to `max` digits and returns the value and the number of digits
+    fn digits(&mut self, min: usize, max: usize) -> Option<(u64, usize)> {
+        let mut value = 0u64;
+        let mut n = 0;
+        while n < max {
+            match self.peek() {
+                Some(b) if b.is_ascii_digit() => {
+                    value = value * 10 + u64::from(b - b'0');
+                    self.pos += 1;
+                    n += 1;
+                }
+                _ => break,
+            }
+        }
+        if n < min {
+            None
+        } else {
+            Some((value, n))
+        }
+    }
+
+    fn number(&mut self, min: usize, max: usize) -> Option<u32> {
+        self.digits(min, max).map(|(v, _)| v as u32)
+    }
+
+    fn year(&mut self) -> Option<i64> {
+        let negative = self.consume(b'-');
+        let (year, _) = self.digits(4, 4)?;
+        let year = year as i64;
+        Some(if negative { -year } else { year })
+    }
+
+    /// Fractional seconds in nanoseconds, extra digits are ignored
+    fn fraction(&mut self) -> Option<u32> {
+        let (value, n) = self.digits(1, 9)?;
+        // ignore digits finer than nanoseconds
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        Some(value as u32 * 10u32.pow(9 - n as u32))
+    }
+
+    /// UTC offset in seconds, such as `Z`, `+09`, `+0900` or `+09:00`
+    fn offset(&mut self) -> Option<i32> {
+        if self.consume(b'Z') || self.consume(b'z') {
+            return Some(0);
+        }
+        let sign = if self.consume(b'+') {
+            1
+        } else if self.consume(b'-') {
+            -1
+        } else {
+            return None;
+        };
+        let hours = self.number(2, 2)?;
+        let minutes = if self.consume(b':') {
+            self.number(2, 2)?
+        } else {
+            // minutes are optional without separator, but must have 2 digits
+            match self.digits(0, 2)? {
+                (_, 0) => 0,
+                (v, 2) => v as u32,
+                _ => return None,
+            }
+        };
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        Some(sign * (hours * 3_600 + minutes * 60) as i32)
+    }
+}
+
+/// Parse `value` with strftime-like format split by `items`.
+fn parse_with_items(value: &str, items: &[Item]) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    let mut cursor = Cursor::new(value);
+    for item in items {
+        match *item {
+            Item::Literal(c) => {
+                let mut buf = [0u8; 4];
+                for &b in c.encode_utf8(&mut buf).as_bytes() {
+                    if !cursor.consume(b) {
+                        return None;
+                    }
+                }
+            }
+            Item::Year => parsed.year = Some(cursor.year()?),
+            Item::Month => parsed.month = Some(cursor.number(1, 2)?),
+            Item::Day => parsed.day = Some(cursor.number(1, 2)?),
+            Item::Hour => parsed.hour = cursor.number(1, 2)?,
+            Item::Minute => parsed.minute = cursor.number(1, 2)?,
+            Item::Second => parsed.second = cursor.number(1, 2)?,
+            Item::Fraction => parsed.nanosecond = cursor.fraction()?,
+            Item::Offset => parsed.offset = Some(cursor.offset()?),
+        }
+    }
+    if cursor.is_end() {
+        Some(parsed)
+    } else {
+        None
+    }
+}
+
+/// Parse ISO 8601 date or datetime, such as `2017-03-01`, `2017-03-01T12:30`,
+/// `2017-03-01 12:30:15.5` and `2017-03-01T12:30:15+09:00`.
+fn parse_iso(value: &str) -> Option<Parsed> {
+    let mut parsed = Parsed::default();
+    let mut cursor = Cursor::new(value);
+
+    parsed.year = Some(cursor.year()?);
+    if !cursor.consume(b'-') {
+        return None;
+    }
+    parsed.month = Some(cursor.number(2, 2)?);
+    if !cursor.consume(b'-') {
+        return None;
+    }
+    parsed.day = Some(cursor.number(2, 2)?);
+    if cursor.is_end() {
+        return Some(parsed);
+    }
+
+    if !(cursor.consume(b'T') || cursor.consume(b' ')) {
+        return None;
+    }
+    parsed.hour = cursor.number(2, 2)?;
+    if !cursor.consume(b':') {
+        return None;
+    }
+    parsed.minute = cursor.number(2, 2)?;
+    if cursor.consume(b':') {
+        parsed.second = cursor.number(2, 2)?;
+        if cursor.consume(b'.') || cursor.consume(b',') {
+            parsed.nanosecond = cursor.fraction()?;
+        }
+    }
+    if !cursor.is_end() {
+        parsed.offset = Some(cursor.offset()?);
+    }
+    if cursor.is_end() {
+        Some(parsed)
+    } else {
+        None
+    }
+}
+
+fn parse(value: &str, items: Option<&[Item]>) -> Option<Parsed> {
+    match items {
+        Some(items) => parse_with_items(value, items),
+        None => parse_iso(value),
+    }
+}
+
+/// Parse `Date32` with the format split by `format_items`, so that a format is
+/// validated once for many values.
+pub(crate) fn parse_date(value: &str, items: Option<&[Item]>) -> Option<Date32> {
+    parse(value, items).and_then(|p| p.to_date())
+}
+
+/// Parse `Timestamp` with the format split by `format_items`, so that a format
+/// is validated once for many values.
+pub(crate) fn parse_timestamp(
+    value: &str,
+    items: Option<&[Item]>,
+    unit: TimeUnit,
+) -> Option<Timestamp> {
+    parse(value, items).and_then(|p| p.to_timestamp(unit))
+}
+
+impl Date32 {
+    /// Parse `Date32` with strftime-like `format`, or ISO 8601 if `format` is `None`.
+    ///
+    /// Time and UTC offset are ignored. Returns `None` if `value` can't be parsed or
+    /// `format` contains unsupported directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// assert_eq!(Date32::parse("2017-03-01", None), Some(Date32::from_ymd(2017, 3, 1)));
+    /// assert_eq!(Date32::parse("01/03/2017", Some("%d/%m/%Y")),
+    ///            Some(Date32::from_ymd(2017, 3, 1)));
+    /// assert_eq!(Date32::parse("2017-02-30", None), None);
+    /// ```
+    pub fn parse(value: &str, format: Option<&str>) -> Option<Date32> {
+        let items = format_items(format).ok()?;
+        parse_date(value, items.as_ref().map(|v| &v[..]))
+    }
+}
+
+impl Timestamp {
+    /// Parse `Timestamp` in `unit` with strftime-like `format`, or ISO 8601 if
+    /// `format` is `None`.
+    ///
+    /// Fractional seconds finer than `unit` are truncated. Returns `None` if `value`
+    /// can't be parsed or overflows, or `format` contains unsupported directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let t = Timestamp::parse("1970-01-01T09:00:01.5+09:00", None, TimeUnit::Millisecond);
+    /// assert_eq!(t, Some(Timestamp::new(1_500, TimeUnit::Millisecond).with_offset(32_400)));
+    /// ```
+    pub fn parse(value: &str, format: Option<&str>, unit: TimeUnit) -> Option<Timestamp> {
+        let items = format_items(format).ok()?;
+        parse_timestamp(value, items.as_ref().map(|v| &v[..]), unit)
+    }
+}
+
+fn write_year(result: &mut String, year: i64) {
+    if year < 0 {
+        result.push_str(&format!("-{:04}", -year));
+    } else {
+        result.push_str(&format!("{:04}", year));
+    }
+}
+
+/// Format `value` with strftime-like format split by `items`, so that a format
+/// is validated once for many values.
+pub(crate) fn strftime<T: DateLike>(value: &T, items: &[Item]) -> String {
+    let (year, month, day) = civil_from_days(value.local_days());
+    let seconds = value.local_seconds();
+
+    let mut result = String::with_capacity(items.len() + 16);
+    for item in items {
+        match *item {
+            Item::Literal(c) => result.push(c),
+            Item::Year => write_year(&mut result, year),
+            Item::Month => result.push_str(&format!("{:02}", month)),
+            Item::Day => result.push_str(&format!("{:02}", day)),
+            Item::Hour => result.push_str(&format!("{:02}", seconds / 3_600)),
+            Item::Minute => result.push_str(&format!("{:02}", seconds % 3_600 / 60)),
+            Item::Second => result.push_str(&format!("{:02}", seconds % 60)),
+            Item::Fraction => result.push_str(&format!("{:09}", value.subsec_nanos())),
+            Item::Offset => {
+                if let Some(offset) = value.utc_offset() {
+                    let sign = if offset < 0 { '-' } else { '+' };
+                    let offset = offset.abs();
+                    result.push_str(&format!(
+                        "{}{:02}{:02}",
+                        sign,
+                        offset / 3_600,
+                        offset % 3_600 / 60
+                    ));
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{items, strftime};
+    use temporal::FormatError;
+    use temporal::{Date32, TimeUnit, Timestamp};
+
+    #[test]
+    fn test_parse_date() {
+        let exp = Some(Date32::from_ymd(2017, 3, 1));
+        assert_eq!(Date32::parse("2017-03-01", None), exp);
+        assert_eq!(Date32::parse("2017-03-01T10:00:00Z", None), exp);
+        assert_eq!(Date32::parse("20170301", Some("%Y%m%d")), exp);
+        assert_eq!(Date32::parse("3/1/2017", Some("%m/%d/%Y")), exp);
+        assert_eq!(
+            Date32::parse("-0001-01-01", None),
+            Some(Date32::from_ymd(-1, 1, 1))
+        );
+
+        assert_eq!(Date32::parse("2017-3-1", None), None);
+        assert_eq!(Date32::parse("2017-02-29", None), None);
+        assert_eq!(Date32::parse("2017-13-01", None), None);
+        assert_eq!(Date32::parse("2017-03-01x", None), None);
+        assert_eq!(Date32::parse("2017/03/01", Some("%Y-%m-%d")), None);
+        assert_eq!(Date32::parse("", None), None);
+        // missing day
+        assert_eq!(Date32::parse("2017-03", Some("%Y-%m")), None);
+    }
+
+    #[test]
+    fn test_parse_timestamp_iso() {
+        let parse = |s| Timestamp::parse(s, None, TimeUnit::Nanosecond);
+        assert_eq!(
+            parse("1970-01-01"),
+            Some(Timestamp::new(0, TimeUnit::Second))
+        );
+        assert_eq!(
+            parse("1970-01-01T00:01"),
+            Some(Timestamp::new(60, TimeUnit::Second))
+        );
+        assert_eq!(
+            parse("1970-01-01 00:00:01"),
+            Some(Timestamp::new(1, TimeUnit::Second))
+        );
+        assert_eq!(
+            parse("1970-01-01T00:00:00.123456789"),
+            Some(Timestamp::new(123_456_789, TimeUnit::Nanosecond))
+        );
+        assert_eq!(
+            parse("1970-01-01T00:00:00,5"),
+            Some(Timestamp::new(500, TimeUnit::Millisecond))
+        );
+
+        let res = parse("1970-01-01T09:00:00+09:00").unwrap();
+        assert_eq!(res, Timestamp::new(0, TimeUnit::Second));
+        assert_eq!(res.offset(), Some(32_400));
+        let res = parse("1970-01-01T00:00:00-0130").unwrap();
+        assert_eq!(res.offset(), Some(-5_400));
+        let res = parse("1970-01-01T00:00:00Z").unwrap();
+        assert_eq!(res.offset(), Some(0));
+        let res = parse("1970-01-01T00:00:00z").unwrap();
+        assert_eq!(res.offset(), Some(0));
+        let res = parse("1970-01-01T09:00:00+09").unwrap();
+        assert_eq!(res.offset(), Some(32_400));
+
+        assert_eq!(parse("1970-01-01T24:00:00"), None);
+        assert_eq!(parse("1970-01-01T00:00:00+"), None);
+        assert_eq!(parse("1970-01-01T00:00:00+09:"), None);
+        assert_eq!(parse("1970-01-01T00:00:00+090"), None);
+        assert_eq!(parse("1970-01-01T00:00:00+0960"), None);
+        assert_eq!(parse("1970-01-01T00"), None);
+    }
+
+    #[test]
+    fn test_parse_timestamp_format() {
+        let res = Timestamp::parse(
+            "01/03/2017 12:30:15.25 +0100",
+            Some("%d/%m/%Y %H:%M:%S.%f %z"),
+            TimeUnit::Millisecond,
+        )
+        .unwrap();
+        assert_eq!(res.unit(), TimeUnit::Millisecond);
+        assert_eq!(res.to_string(), "2017-03-01T12:30:15.250+01:00");
+
+        // truncated to the unit
+        let res = Timestamp::parse("1.999", Some("%S.%f"), TimeUnit::Second);
+        assert_eq!(res, None);
+        let res = Timestamp::parse("2017-03-01 1.999", Some("%Y-%m-%d %S.%f"), TimeUnit::Second);
+        assert_eq!(res.unwrap().to_string(), "2017-03-01T00:00:01");
+
+        // literal %
+        let res = Timestamp::parse("100%2017-03-01", Some("100%%%Y-%m-%d"), TimeUnit::Second);
+        assert_eq!(res.unwrap().to_string(), "2017-03-01T00:00:00");
+
+        // overflow
+        let res = Timestamp::parse("3000-01-01", None, TimeUnit::Nanosecond);
+        assert_eq!(res, None);
+    }
+
+    #[test]
+    fn test_parse_offset_format() {
+        let parse = |s| Timestamp::parse(s, Some("%H:%M %z %Y-%m-%d"), TimeUnit::Second);
+        for s in &[
+            "09:00 +09 1970-01-01",
+            "09:00 +0900 1970-01-01",
+            "09:00 +09:00 1970-01-01",
+        ] {
+            let res = parse(s).unwrap();
+            assert_eq!(res, Timestamp::new(0, TimeUnit::Second));
+            assert_eq!(res.offset(), Some(32_400));
+        }
+        let res = Timestamp::parse(
+            "1970-01-01 00:00-01:30",
+            Some("%Y-%m-%d %H:%M%z"),
+            TimeUnit::Second,
+        );
+        assert_eq!(res.unwrap().offset(), Some(-5_400));
+    }
+
+    #[test]
+    fn test_parse_unsupported_directive() {
+        assert_eq!(Timestamp::parse("2017", Some("%y"), TimeUnit::Second), None);
+        assert_eq!(Date32::parse("2017%", Some("%Y%")), None);
+    }
+
+    #[test]
+    fn test_strftime() {
+        let d = Date32::from_ymd(2017, 3, 1);
+        assert_eq!(
+            strftime(&d, &items("%d/%m/%Y %H:%M").unwrap()),
+            "01/03/2017 00:00"
+        );
+
+        let t = Timestamp::new(1_500_000_005_250, TimeUnit::Millisecond);
+        assert_eq!(
+            strftime(&t, &items("%Y-%m-%dT%H:%M:%S.%f%z").unwrap()),
+            "2017-07-14T02:40:05.250000000"
+        );
+        let t = t.with_offset(-5_400);
+        assert_eq!(
+            strftime(&t, &items("%H:%M %z %%").unwrap()),
+            "01:10 -0130 %"
+        );
+
+        let t = Timestamp::new(-1, TimeUnit::Microsecond);
+        assert_eq!(
+            strftime(&t, &items("%Y %S.%f").unwrap()),
+            "1969 59.999999000"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_directive() {
+        let err = items("%Y-%j").unwrap_err();
+        assert_eq!(err.directive(), "%j");
+        assert_eq!(err.to_string(), "unsupported directive: %j");
+        let err: FormatError = items("%d%").unwrap_err();
+        assert_eq!(err.directive(), "%");
+    }
+}
@@ -0,0 +1,945 @@
+//! Temporal types which can be stored to `NullVec`.
+//!
+//! Dates and times are handled in the proleptic Gregorian calendar without
+//! leap seconds.
+//!
+//! # Examples
+//!
+//! ```
+//! use nullvec::prelude::*;
+//! let d = Date32::from_ymd(2017, 3, 1);
+//! assert_eq!(d.to_string(), "2017-03-01");
+//!
+//! let t1 = Timestamp::new(0, TimeUnit::Second);
+//! let t2 = Timestamp::new(90_500, TimeUnit::Millisecond);
+//! assert_eq!(t2 - t1, Duration::new(90_500, TimeUnit::Millisecond));
+//! assert_eq!(t2.to_string(), "1970-01-01T00:01:30.500");
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+use num_traits::{CheckedAdd, CheckedSub, Zero};
+
+use traits::NullStorable;
+
+mod format;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+pub(crate) use self::format::{format_items, items, parse_date, parse_timestamp, strftime};
+pub use self::format::{FormatError, ParseDatetimeError, TryParseError};
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Unit of `Timestamp` and `Duration`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// Seconds
+    Second,
+    /// Milliseconds
+    Millisecond,
+    /// Microseconds
+    Microsecond,
+    /// Nanoseconds
+    Nanosecond,
+}
+
+impl TimeUnit {
+    /// Returns the number of units per second.
+    pub fn per_second(&self) -> i64 {
+        match *self {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => 1_000,
+            TimeUnit::Microsecond => 1_000_000,
+            TimeUnit::Nanosecond => 1_000_000_000,
+        }
+    }
+
+    /// Returns the abbreviation of the unit.
+    pub fn abbreviation(&self) -> &'static str {
+        match *self {
+            TimeUnit::Second => "s",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Microsecond => "us",
+            TimeUnit::Nanosecond => "ns",
+        }
+    }
+
+    /// Returns the finer unit of `self` and `other`.
+    fn finer(self, other: TimeUnit) -> TimeUnit {
+        if self.per_second() >= other.per_second() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Number of digits of the fractional seconds.
+    fn digits(&self) -> usize {
+        match *self {
+            TimeUnit::Second => 0,
+            TimeUnit::Millisecond => 3,
+            TimeUnit::Microsecond => 6,
+            TimeUnit::Nanosecond => 9,
+        }
+    }
+}
+
+/// Convert `value` in `from` unit to `to` unit, rounding toward negative infinity.
+///
+/// # Panics
+///
+/// - if the result overflows
+pub fn convert_unit(value: i64, from: TimeUnit, to: TimeUnit) -> i64 {
+    checked_convert_unit(value, from, to).expect("overflow when converting time unit")
+}
+
+/// Same as `convert_unit`, but returns `None` if the result overflows.
+fn checked_convert_unit(value: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let (f, t) = (from.per_second(), to.per_second());
+    if f <= t {
+        value.checked_mul(t / f)
+    } else {
+        Some(value.div_euclid(f / t))
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of the specified civil date.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the civil date `(year, month, day)` of the number of days since 1970-01-01.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Returns whether the year is a leap year.
+pub fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in the month.
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => panic!("month must be between 1 and 12"),
+    }
+}
+
+/// Calendar-like values which have local date and time of day.
+///
+/// This trait is to extract datetime components from `NullVec`. Normal users
+/// do not need to import it.
+pub trait DateLike: Copy {
+    /// Returns the number of days since 1970-01-01 of the local date.
+    fn local_days(&self) -> i64;
+
+    /// Returns the number of seconds since the local midnight.
+    fn local_seconds(&self) -> i64;
+
+    /// Returns the fractional part of the second in nanoseconds.
+    fn subsec_nanos(&self) -> i64 {
+        0
+    }
+
+    /// Returns UTC offset in seconds, if any.
+    fn utc_offset(&self) -> Option<i32> {
+        None
+    }
+}
+
+/// Time span which `Timestamp` is truncated or rounded to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    /// Day boundary in local time
+    Day,
+    /// Hour boundary
+    Hour,
+    /// Minute boundary
+    Minute,
+    /// Second boundary
+    Second,
+}
+
+impl Frequency {
+    /// Returns the number of seconds of the span.
+    pub fn seconds(&self) -> i64 {
+        match *self {
+            Frequency::Day => SECONDS_PER_DAY,
+            Frequency::Hour => 3_600,
+            Frequency::Minute => 60,
+            Frequency::Second => 1,
+        }
+    }
+}
+
+fn write_year(f: &mut fmt::Formatter, year: i64) -> fmt::Result {
+    if year < 0 {
+        write!(f, "-{:04}", -year)
+    } else if year > 9999 {
+        write!(f, "+{}", year)
+    } else {
+        write!(f, "{:04}", year)
+    }
+}
+
+fn write_date(f: &mut fmt::Formatter, days: i64) -> fmt::Result {
+    let (year, month, day) = civil_from_days(days);
+    write_year(f, year)?;
+    write!(f, "-{:02}-{:02}", month, day)
+}
+
+/// Calendar date stored as the number of days since 1970-01-01.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date32 {
+    days: i32,
+}
+
+impl Date32 {
+    /// Create new `Date32` from the number of days since 1970-01-01.
+    pub fn from_days(days: i32) -> Self {
+        Date32 { days }
+    }
+
+    /// Create new `Date32` from year, month and day.
+    ///
+    /// # Panics
+    ///
+    /// - if the date is invalid or out of range
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        assert!(
+            day >= 1 && day <= days_in_month(i64::from(year), month),
+            "invalid date"
+        );
+        let days = days_from_civil(i64::from(year), month, day);
+        assert!(
+            days >= i64::from(i32::MIN) && days <= i64::from(i32::MAX),
+            "date out of range"
+        );
+        Date32 { days: days as i32 }
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns `(year, month, day)`.
+    pub fn ymd(&self) -> (i32, u32, u32) {
+        let (year, month, day) = civil_from_days(i64::from(self.days));
+        (year as i32, month, day)
+    }
+
+    /// Returns the `Duration` elapsed since `other`, which never overflows.
+    pub fn checked_duration_since(&self, other: &Date32) -> Option<Duration> {
+        Some(*self - *other)
+    }
+}
+
+impl fmt::Display for Date32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, i64::from(self.days))
+    }
+}
+
+impl NullStorable for Date32 {}
+
+impl DateLike for Date32 {
+    fn local_days(&self) -> i64 {
+        i64::from(self.days)
+    }
+
+    fn local_seconds(&self) -> i64 {
+        0
+    }
+}
+
+/// Point in time stored as the number of `unit` since 1970-01-01T00:00:00 UTC.
+///
+/// `offset` is the UTC offset in seconds used to represent local time. It doesn't
+/// affect the point in time, thus `Timestamp`s are compared regardless of units
+/// and offsets.
+#[derive(Clone, Copy, Debug)]
+pub struct Timestamp {
+    value: i64,
+    unit: TimeUnit,
+    offset: Option<i32>,
+}
+
+impl Timestamp {
+    /// Create new `Timestamp` without UTC offset.
+    pub fn new(value: i64, unit: TimeUnit) -> Self {
+        Timestamp {
+            value,
+            unit,
+            offset: None,
+        }
+    }
+
+    /// Returns `Timestamp` which has the specified UTC offset in seconds.
+    ///
+    /// # Panics
+    ///
+    /// - if the absolute value of `offset` is a day or more
+    pub fn with_offset(&self, offset: i32) -> Self {
+        assert!(
+            i64::from(offset).abs() < SECONDS_PER_DAY,
+            "offset must be less than a day"
+        );
+        Timestamp {
+            value: self.value,
+            unit: self.unit,
+            offset: Some(offset),
+        }
+    }
+
+    /// Returns `Timestamp` which doesn't have UTC offset.
+    pub fn without_offset(&self) -> Self {
+        Timestamp::new(self.value, self.unit)
+    }
+
+    /// Returns the number of `unit` since the epoch.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// Returns UTC offset in seconds.
+    pub fn offset(&self) -> Option<i32> {
+        self.offset
+    }
+
+    /// Returns `Timestamp` converted to the specified unit.
+    ///
+    /// Converting to coarser unit rounds toward the past.
+    pub fn to_unit(&self, unit: TimeUnit) -> Self {
+        Timestamp {
+            value: convert_unit(self.value, self.unit, unit),
+            unit,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the local time as the number of `unit` since the epoch.
+    ///
+    /// # Panics
+    ///
+    /// - if the result overflows
+    pub fn local_value(&self) -> i64 {
+        self.checked_local_value().expect("timestamp overflow")
+    }
+
+    fn checked_local_value(&self) -> Option<i64> {
+        match self.offset {
+            Some(offset) => self
+                .value
+                .checked_add(i64::from(offset) * self.unit.per_second()),
+            None => Some(self.value),
+        }
+    }
+
+    /// Returns the local time as the number of seconds since the epoch, in `i128`
+    /// not to overflow.
+    fn local_epoch_seconds(&self) -> i128 {
+        i128::from(self.value.div_euclid(self.unit.per_second()))
+            + i128::from(self.offset.unwrap_or(0))
+    }
+
+    /// Returns `Timestamp` truncated to the boundary of `freq` in local time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nullvec::prelude::*;
+    /// let t = Timestamp::new(5_400, TimeUnit::Second).with_offset(1_800);
+    /// assert_eq!(t.truncate(Frequency::Hour).to_string(), "1970-01-01T02:00:00+00:30");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - if the result overflows
+    pub fn truncate(&self, freq: Frequency) -> Self {
+        self.checked_truncate(freq).expect("timestamp overflow")
+    }
+
+    /// Same as `truncate`, but returns `None` if the result overflows.
+    pub fn checked_truncate(&self, freq: Frequency) -> Option<Self> {
+        let span = freq.seconds() * self.unit.per_second();
+        let rem = self.checked_local_value()?.rem_euclid(span);
+        Some(Timestamp {
+            value: self.value.checked_sub(rem)?,
+            unit: self.unit,
+            offset: self.offset,
+        })
+    }
+
+    /// Returns `Timestamp` rounded to the nearest boundary of `freq` in local time.
+    ///
+    /// Halfway values are rounded up.
+    ///
+    /// # Panics
+    ///
+    /// - if the result overflows
+    pub fn round(&self, freq: Frequency) -> Self {
+        self.checked_round(freq).expect("timestamp overflow")
+    }
+
+    /// Same as `round`, but returns `None` if the result overflows.
+    pub fn checked_round(&self, freq: Frequency) -> Option<Self> {
+        let span = freq.seconds() * self.unit.per_second();
+        let rem = self.checked_local_value()?.rem_euclid(span);
+        let value = self.value.checked_sub(rem)?;
+        let value = if rem * 2 >= span {
+            value.checked_add(span)?
+        } else {
+            value
+        };
+        Some(Timestamp {
+            value,
+            unit: self.unit,
+            offset: self.offset,
+        })
+    }
+
+    /// Returns the `Duration` elapsed since `other`, or `None` if the result
+    /// overflows.
+    pub fn checked_duration_since(&self, other: &Timestamp) -> Option<Duration> {
+        let unit = self.unit.finer(other.unit);
+        let l = checked_convert_unit(self.value, self.unit, unit)?;
+        let r = checked_convert_unit(other.value, other.unit, unit)?;
+        l.checked_sub(r).map(|v| Duration::new(v, unit))
+    }
+
+    /// Returns `Timestamp` shifted by `duration`, or `None` if the result overflows.
+    pub fn checked_add_duration(&self, duration: &Duration) -> Option<Timestamp> {
+        let unit = self.unit.finer(duration.unit);
+        let l = checked_convert_unit(self.value, self.unit, unit)?;
+        let r = checked_convert_unit(duration.value, duration.unit, unit)?;
+        Some(Timestamp {
+            value: l.checked_add(r)?,
+            unit,
+            offset: self.offset,
+        })
+    }
+
+    /// Returns `Timestamp` shifted back by `duration`, or `None` if the result
+    /// overflows.
+    pub fn checked_sub_duration(&self, duration: &Duration) -> Option<Timestamp> {
+        let unit = self.unit.finer(duration.unit);
+        let l = checked_convert_unit(self.value, self.unit, unit)?;
+        let r = checked_convert_unit(duration.value, duration.unit, unit)?;
+        Some(Timestamp {
+            value: l.checked_sub(r)?,
+            unit,
+            offset: self.offset,
+        })
+    }
+
+    fn nanos(&self) -> i128 {
+        i128::from(self.value) * i128::from(1_000_000_000 / self.unit.per_second())
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Self {
+        Timestamp::new(0, TimeUnit::Nanosecond)
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Timestamp) -> bool {
+        self.nanos() == other.nanos()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Timestamp) -> Ordering {
+        self.nanos().cmp(&other.nanos())
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let per_second = self.unit.per_second();
+        let local = self.local_value();
+        let seconds = local.div_euclid(per_second);
+        let fraction = local.rem_euclid(per_second);
+        let time = seconds.rem_euclid(SECONDS_PER_DAY);
+
+        write_date(f, seconds.div_euclid(SECONDS_PER_DAY))?;
+        write!(
+            f,
+            "T{:02}:{:02}:{:02}",
+            time / 3600,
+            time % 3600 / 60,
+            time % 60
+        )?;
+        if self.unit != TimeUnit::Second {
+            write!(f, ".{:0width$}", fraction, width = self.unit.digits())?;
+        }
+        if let Some(offset) = self.offset {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.abs();
+            write!(f, "{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl NullStorable for Timestamp {}
+
+impl DateLike for Timestamp {
+    fn local_days(&self) -> i64 {
+        self.local_epoch_seconds()
+            .div_euclid(i128::from(SECONDS_PER_DAY)) as i64
+    }
+
+    fn local_seconds(&self) -> i64 {
+        self.local_epoch_seconds()
+            .rem_euclid(i128::from(SECONDS_PER_DAY)) as i64
+    }
+
+    fn subsec_nanos(&self) -> i64 {
+        let per_second = self.unit.per_second();
+        self.value.rem_euclid(per_second) * (1_000_000_000 / per_second)
+    }
+
+    fn utc_offset(&self) -> Option<i32> {
+        self.offset
+    }
+}
+
+/// Elapsed time stored as the number of `unit`.
+#[derive(Clone, Copy, Debug)]
+pub struct Duration {
+    value: i64,
+    unit: TimeUnit,
+}
+
+impl Duration {
+    /// Create new `Duration`.
+    pub fn new(value: i64, unit: TimeUnit) -> Self {
+        Duration { value, unit }
+    }
+
+    /// Returns the number of `unit`.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    /// Returns `Duration` converted to the specified unit.
+    ///
+    /// Converting to coarser unit rounds toward negative infinity.
+    pub fn to_unit(&self, unit: TimeUnit) -> Self {
+        Duration::new(convert_unit(self.value, self.unit, unit), unit)
+    }
+
+    fn nanos(&self) -> i128 {
+        i128::from(self.value) * i128::from(1_000_000_000 / self.unit.per_second())
+    }
+}
+
+impl Default for Duration {
+    fn default() -> Self {
+        Duration::new(0, TimeUnit::Second)
+    }
+}
+
+impl PartialEq for Duration {
+    fn eq(&self, other: &Duration) -> bool {
+        self.nanos() == other.nanos()
+    }
+}
+
+impl Eq for Duration {}
+
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Duration {
+    fn cmp(&self, other: &Duration) -> Ordering {
+        self.nanos().cmp(&other.nanos())
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Format as ISO 8601 duration, such as `P1DT2H3M4.5S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let per_second = self.unit.per_second() as u64;
+        let value = self.value.unsigned_abs();
+        let seconds = value / per_second;
+        let fraction = value % per_second;
+        let days = seconds / SECONDS_PER_DAY as u64;
+        let (h, m, s) = (seconds % 86_400 / 3600, seconds % 3600 / 60, seconds % 60);
+
+        if self.value < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+        if h == 0 && m == 0 && s == 0 && fraction == 0 {
+            if days == 0 {
+                write!(f, "T0S")?;
+            }
+            return Ok(());
+        }
+        write!(f, "T")?;
+        if h > 0 {
+            write!(f, "{}H", h)?;
+        }
+        if m > 0 {
+            write!(f, "{}M", m)?;
+        }
+        if s > 0 || fraction > 0 {
+            write!(f, "{}", s)?;
+            if fraction > 0 {
+                let digits = format!("{:0width$}", fraction, width = self.unit.digits());
+                write!(f, ".{}", digits.trim_end_matches('0'))?;
+            }
+            write!(f, "S")?;
+        }
+        Ok(())
+    }
+}
+
+impl NullStorable for Duration {}
+
+// Arithmetic
+
+impl Sub for Timestamp {
+    type Output = Duration;
+
+    fn sub(self, other: Timestamp) -> Duration {
+        self.checked_duration_since(&other)
+            .expect("timestamp overflow")
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, other: Duration) -> Timestamp {
+        self.checked_add_duration(&other)
+            .expect("timestamp overflow")
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, other: Duration) -> Timestamp {
+        self.checked_sub_duration(&other)
+            .expect("timestamp overflow")
+    }
+}
+
+impl Sub for Date32 {
+    type Output = Duration;
+
+    fn sub(self, other: Date32) -> Duration {
+        let days = i64::from(self.days) - i64::from(other.days);
+        Duration::new(days * SECONDS_PER_DAY, TimeUnit::Second)
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        CheckedAdd::checked_add(&self, &other).expect("duration overflow")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        CheckedSub::checked_sub(&self, &other).expect("duration overflow")
+    }
+}
+
+impl CheckedAdd for Duration {
+    fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        let unit = self.unit.finer(other.unit);
+        let l = checked_convert_unit(self.value, self.unit, unit)?;
+        let r = checked_convert_unit(other.value, other.unit, unit)?;
+        l.checked_add(r).map(|v| Duration::new(v, unit))
+    }
+}
+
+impl CheckedSub for Duration {
+    fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        let unit = self.unit.finer(other.unit);
+        let l = checked_convert_unit(self.value, self.unit, unit)?;
+        let r = checked_convert_unit(other.value, other.unit, unit)?;
+        l.checked_sub(r).map(|v| Duration::new(v, unit))
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        let value = self.value.checked_neg().expect("duration overflow");
+        Duration::new(value, self.unit)
+    }
+}
+
+impl Zero for Duration {
+    fn zero() -> Duration {
+        Duration::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+        civil_from_days, days_from_civil, Date32, DateLike, Duration, Frequency, TimeUnit,
+        Timestamp,
+    };
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(civil_from_days(-719_468), (0, 3, 1));
+        for days in -800_000..800_000 {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_date32() {
+        let d = Date32::from_ymd(2016, 2, 29);
+        assert_eq!(d.ymd(), (2016, 2, 29));
+        assert_eq!(d.to_string(), "2016-02-29");
+        assert_eq!(Date32::from_days(-1).to_string(), "1969-12-31");
+        assert_eq!(Date32::from_ymd(-1, 1, 1).to_string(), "-0001-01-01");
+        assert!(Date32::from_days(0) < Date32::from_days(1));
+        assert_eq!(
+            Date32::from_days(3) - Date32::from_days(1),
+            Duration::new(2 * 86_400, TimeUnit::Second)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_date32_invalid() {
+        Date32::from_ymd(2017, 2, 29);
+    }
+
+    #[test]
+    fn test_timestamp_display() {
+        let t = Timestamp::new(1_500_000_000, TimeUnit::Second);
+        assert_eq!(t.to_string(), "2017-07-14T02:40:00");
+        assert_eq!(
+            t.with_offset(9 * 3600).to_string(),
+            "2017-07-14T11:40:00+09:00"
+        );
+        assert_eq!(
+            t.with_offset(-5400).to_string(),
+            "2017-07-14T01:10:00-01:30"
+        );
+
+        let t = Timestamp::new(-1, TimeUnit::Millisecond);
+        assert_eq!(t.to_string(), "1969-12-31T23:59:59.999");
+        let t = Timestamp::new(1, TimeUnit::Microsecond);
+        assert_eq!(t.to_string(), "1970-01-01T00:00:00.000001");
+        let t = Timestamp::new(1, TimeUnit::Nanosecond);
+        assert_eq!(t.to_string(), "1970-01-01T00:00:00.000000001");
+    }
+
+    #[test]
+    fn test_timestamp_comparison() {
+        let t1 = Timestamp::new(1, TimeUnit::Second);
+        let t2 = Timestamp::new(1_000, TimeUnit::Millisecond);
+        assert_eq!(t1, t2);
+        assert_eq!(t1, t2.with_offset(3600));
+        assert!(t1 < Timestamp::new(1_001, TimeUnit::Millisecond));
+        assert_eq!(t2.to_unit(TimeUnit::Second).value(), 1);
+        assert_eq!(
+            Timestamp::new(-1, TimeUnit::Millisecond)
+                .to_unit(TimeUnit::Second)
+                .value(),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        let t1 = Timestamp::new(10, TimeUnit::Second).with_offset(3600);
+        let t2 = Timestamp::new(2_500, TimeUnit::Millisecond);
+        let d = t1 - t2;
+        assert_eq!(d.value(), 7_500);
+        assert_eq!(d.unit(), TimeUnit::Millisecond);
+
+        let res = t2 + d;
+        assert_eq!(res, t1);
+        assert_eq!(res.unit(), TimeUnit::Millisecond);
+
+        let res = t1 - Duration::new(1, TimeUnit::Second);
+        assert_eq!(res.value(), 9);
+        assert_eq!(res.offset(), Some(3600));
+
+        let max = Timestamp::new(i64::MAX, TimeUnit::Second);
+        assert_eq!(max.checked_duration_since(&t2), None);
+        assert_eq!(
+            max.checked_add_duration(&Duration::new(1, TimeUnit::Second)),
+            None
+        );
+        assert_eq!(
+            max.checked_sub_duration(&Duration::new(1, TimeUnit::Second)),
+            Some(Timestamp::new(i64::MAX - 1, TimeUnit::Second))
+        );
+        assert_eq!(max.with_offset(1).checked_local_value(), None);
+        assert_eq!(max.with_offset(1).checked_truncate(Frequency::Day), None);
+        assert_eq!(
+            Timestamp::new(i64::MAX, TimeUnit::Nanosecond).checked_round(Frequency::Second),
+            None
+        );
+        // formatting doesn't overflow
+        assert_eq!(max.with_offset(1).local_days(), 106_751_991_167_300);
+    }
+
+    #[test]
+    #[should_panic(expected = "timestamp overflow")]
+    fn test_timestamp_overflow() {
+        let _ = Timestamp::new(i64::MIN, TimeUnit::Second) - Duration::new(1, TimeUnit::Second);
+    }
+
+    #[test]
+    fn test_duration() {
+        let d = Duration::new(1, TimeUnit::Second) + Duration::new(500, TimeUnit::Millisecond);
+        assert_eq!(d, Duration::new(1_500, TimeUnit::Millisecond));
+        assert_eq!(-d, Duration::new(-1_500, TimeUnit::Millisecond));
+        assert!(d > Duration::new(1, TimeUnit::Second));
+
+        assert_eq!(Duration::new(0, TimeUnit::Second).to_string(), "PT0S");
+        assert_eq!(Duration::new(86_400, TimeUnit::Second).to_string(), "P1D");
+        assert_eq!(
+            Duration::new(93_784, TimeUnit::Second).to_string(),
+            "P1DT2H3M4S"
+        );
+        assert_eq!(
+            Duration::new(-1_500, TimeUnit::Millisecond).to_string(),
+            "-PT1.5S"
+        );
+        assert_eq!(
+            Duration::new(60, TimeUnit::Nanosecond).to_string(),
+            "PT0.00000006S"
+        );
+        assert_eq!(Duration::new(3_600, TimeUnit::Second).to_string(), "PT1H");
+    }
+
+    #[test]
+    fn test_date_like() {
+        let t = Timestamp::new(-1, TimeUnit::Millisecond);
+        assert_eq!(t.local_days(), -1);
+        assert_eq!(t.local_seconds(), 86_399);
+
+        let t = Timestamp::new(3_600, TimeUnit::Second).with_offset(-7_200);
+        assert_eq!(t.local_days(), -1);
+        assert_eq!(t.local_seconds(), 82_800);
+    }
+
+    #[test]
+    fn test_truncate_round() {
+        let t = Timestamp::new(5_430_500, TimeUnit::Millisecond);
+        assert_eq!(
+            t.truncate(Frequency::Hour),
+            Timestamp::new(3_600, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.truncate(Frequency::Minute),
+            Timestamp::new(5_400, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.truncate(Frequency::Second),
+            Timestamp::new(5_430, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.truncate(Frequency::Day),
+            Timestamp::new(0, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.round(Frequency::Hour),
+            Timestamp::new(7_200, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.round(Frequency::Minute),
+            Timestamp::new(5_460, TimeUnit::Second)
+        );
+        assert_eq!(
+            t.round(Frequency::Second),
+            Timestamp::new(5_431, TimeUnit::Second)
+        );
+        assert_eq!(t.truncate(Frequency::Hour).unit(), TimeUnit::Millisecond);
+
+        // day boundary in local time
+        let t = Timestamp::new(3_600, TimeUnit::Second).with_offset(-7_200);
+        let res = t.truncate(Frequency::Day);
+        assert_eq!(res.to_string(), "1969-12-31T00:00:00-02:00");
+        let res = t.round(Frequency::Day);
+        assert_eq!(res.to_string(), "1970-01-01T00:00:00-02:00");
+
+        let t = Timestamp::new(-1, TimeUnit::Second);
+        assert_eq!(
+            t.truncate(Frequency::Minute),
+            Timestamp::new(-60, TimeUnit::Second)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Temporal types are serialized as strings: `Date32` and `Timestamp` in ISO 8601,
+//! and `Duration` as the value followed by the unit abbreviation, such as `"90s"`.
+//! The unit of `Timestamp` is restored from the number of fractional digits.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Date32, Duration, TimeUnit, Timestamp};
+
+const UNITS: [TimeUnit; 4] = [
+    TimeUnit::Second,
+    TimeUnit::Millisecond,
+    TimeUnit::Microsecond,
+    TimeUnit::Nanosecond,
+];
+
+/// Visitor which parses a string with the given function.
+struct ParseVisitor<T> {
+    expected: &'static str,
+    parse: fn(&str) -> Option<T>,
+}
+
+impl<'de, T> Visitor<'de> for ParseVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expected)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        (self.parse)(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+fn parse_date(value: &str) -> Option<Date32> {
+    Date32::parse(value, None)
+}
+
+fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    let time = &value[value.find('T')?..];
+    let digits = match time.find('.') {
+        Some(pos) => time[pos + 1..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count(),
+        None => 0,
+    };
+    let unit = UNITS.iter().find(|u| u.digits() == digits)?;
+    Timestamp::parse(value, None, *unit)
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    let pos = value.find(|c: char| c.is_ascii_alphabetic())?;
+    let unit = UNITS.iter().find(|u| u.abbreviation() == &value[pos..])?;
+    value[..pos].parse().ok().map(|v| Duration::new(v, *unit))
+}
+
+impl Serialize for Date32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expected: "ISO 8601 date",
+            parse: parse_date,
+        })
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expected: "ISO 8601 date and time",
+            parse: parse_timestamp,
+        })
+    }
+}
+
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{}{}", self.value, self.unit.abbreviation()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expected: "duration such as \"90s\"",
+            parse: parse_duration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json;
+    use temporal::{Date32, Duration, TimeUnit, Timestamp};
+
+    #[test]
+    fn test_date32() {
+        let d = Date32::from_ymd(2017, 3, 1);
+        assert_eq!(serde_json::to_string(&d).unwrap(), "\"2017-03-01\"");
+        assert_eq!(serde_json::from_str::<Date32>("\"2017-03-01\"").unwrap(), d);
+        assert!(serde_json::from_str::<Date32>("\"2017-02-30\"").is_err());
+    }
+
+    #[test]
+    fn test_timestamp() {
+        for unit in super::UNITS.iter() {
+            let t = Timestamp::new(90, *unit).with_offset(32_400);
+            let s = serde_json::to_string(&t).unwrap();
+            let res: Timestamp = serde_json::from_str(&s).unwrap();
+            assert_eq!(res, t);
+            assert_eq!(res.unit(), *unit);
+            assert_eq!(res.offset(), Some(32_400));
+        }
+        let t = Timestamp::new(1_500, TimeUnit::Millisecond);
+        assert_eq!(
+            serde_json::to_string(&t).unwrap(),
+            "\"1970-01-01T00:00:01.500\""
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        let d = Duration::new(-1_500, TimeUnit::Microsecond);
+        assert_eq!(serde_json::to_string(&d).unwrap(), "\"-1500us\"");
+        let res: Duration = serde_json::from_str("\"-1500us\"").unwrap();
+        assert_eq!((res.value(), res.unit()), (-1_500, TimeUnit::Microsecond));
+        assert!(serde_json::from_str::<Duration>("\"15m\"").is_err());
+    }
+}
@@ -186,3 +186,218 @@ fn test_append() {
     let exp = Array::new(vec![1, 2, 3, 1, 2, 3]);
     assert_eq!(res, exp);
 }
+
+#[test]
+fn test_utf8_array() {
+    let arr = Array::new(Utf8Column::from(vec!["a", "bb", "ccc"]));
+    assert_eq!(arr.dtype(), "utf8");
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr.iloc(&1), Scalar::String("bb".to_string()));
+
+    let res = arr.ilocs(&[2, 0]);
+    assert_eq!(res, Array::new(Utf8Column::from(vec!["ccc", "a"])));
+
+    let res = arr.append(&res);
+    assert_eq!(res,
+               Array::new(Utf8Column::from(vec!["a", "bb", "ccc", "ccc", "a"])));
+}
+
+#[test]
+fn test_categorical_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let values = NullVec::with_mask(vec!["b".to_string(), "a".to_string(), "b".to_string()],
+                                    Some(vec![false, false, true]));
+    let arr = Array::StringArray(values);
+    let cat = arr.cast("category").unwrap();
+    assert_eq!(cat.dtype(), "category");
+    assert_eq!(cat.len(), 3);
+    assert_eq!(cat.iloc(&0), Scalar::String("b".to_string()));
+    assert_eq!(cat.iloc(&2), Scalar::Null);
+    assert_eq!(cat.into_string_vec(),
+               vec!["b".to_string(), "a".to_string(), "Null".to_string()]);
+    assert_eq!(cat.cast("str").unwrap().into_string_vec(), arr.into_string_vec());
+
+    let res = cat.append(&Array::new(vec!["c"]).cast("category").unwrap());
+    assert_eq!(res.cast("utf8"),
+               Ok(Array::new(Utf8Column::with_mask(vec!["b".to_string(),
+                                                        "a".to_string(),
+                                                        "".to_string(),
+                                                        "c".to_string()],
+                                                   Some(vec![false, false, true, false])))));
+}
+
+#[test]
+fn test_cast_unsupported() {
+    let err = Array::new(vec![1, 2]).cast("category").unwrap_err();
+    assert_eq!(err.to_string(), "cast to category is not supported for dtype i32");
+    let err = Array::new(vec!["a"]).cast("i64").unwrap_err();
+    assert_eq!(err.to_string(), "cast to i64 is not supported for dtype str");
+}
+
+#[test]
+#[should_panic(expected = "unable to append str to i64")]
+fn test_append_dtype_mismatch() {
+    Array::new(vec![1i64]).append(&Array::new(vec!["a"]));
+}
+
+#[test]
+fn test_temporal_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let values = vec![Date32::from_ymd(2017, 1, 2), Date32::from_ymd(2016, 12, 31)];
+    let arr = Array::new(values);
+    assert_eq!(arr.dtype(), "date32");
+    assert_eq!(arr.into_string_vec(),
+               vec!["2017-01-02".to_string(), "2016-12-31".to_string()]);
+    assert_eq!(arr.min(), Ok(Scalar::Date32(Date32::from_ymd(2016, 12, 31))));
+    assert_eq!(arr.iloc(&0).as_date32(), Nullable::new(Date32::from_ymd(2017, 1, 2)));
+
+    let values = NullVec::with_mask(vec![Timestamp::new(1, TimeUnit::Second),
+                                         Timestamp::new(0, TimeUnit::Second)],
+                                    Some(vec![false, true]));
+    let arr = Array::TimestampArray(values);
+    assert_eq!(arr.dtype(), "timestamp");
+    let res = arr.append(&Array::new(vec![Timestamp::new(-1, TimeUnit::Millisecond)]));
+    assert_eq!(res.into_string_vec(),
+               vec!["1970-01-01T00:00:01".to_string(),
+                    "Null".to_string(),
+                    "1969-12-31T23:59:59.999".to_string()]);
+    assert_eq!(res.max(), Ok(Scalar::Timestamp(Timestamp::new(1, TimeUnit::Second))));
+    assert_eq!(res.count(), 2);
+    assert_eq!(res.sum(), Err(DtypeError::new("sum", "timestamp")));
+
+    let arr = Array::new(vec![Duration::new(1, TimeUnit::Second),
+                              Duration::new(30, TimeUnit::Millisecond)]);
+    assert_eq!(arr.dtype(), "duration");
+    assert_eq!(arr.sum(), Ok(Scalar::Duration(Duration::new(1_030, TimeUnit::Millisecond))));
+    assert_eq!(arr.sum().unwrap().to_string(), "PT1.03S");
+    assert_eq!(arr.mean(), Err(DtypeError::new("mean", "duration")));
+}
+
+#[test]
+fn test_decimal_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let values = NullVec::new(vec!["10.25".to_string(), "x".to_string(), "-0.5".to_string()]);
+    let arr = Array::DecimalArray(values.parse_decimal());
+    assert_eq!(arr.dtype(), "decimal");
+    assert!(arr.is_numeric());
+    assert_eq!(arr.into_string_vec(),
+               vec!["10.25".to_string(), "Null".to_string(), "-0.5".to_string()]);
+    assert_eq!(arr.sum(), Ok(Scalar::Decimal(Decimal::new(975, 4, 2))));
+    assert_eq!(arr.sum().unwrap().to_string(), "9.75");
+    assert_eq!(arr.min(), Ok(Scalar::Decimal(Decimal::new(-5, 1, 1))));
+    assert_eq!(arr.mean(), Ok(Nullable::new(4.875)));
+    assert_eq!(arr.iloc(&0).as_decimal(), Nullable::new(Decimal::new(1025, 4, 2)));
+
+    let res = arr.append(&Array::new(vec![Decimal::new(1, 1, 0)]));
+    assert_eq!(res.cumsum(true).unwrap().into_string_vec(),
+               vec!["10.25".to_string(), "Null".to_string(), "9.75".to_string(),
+                    "10.75".to_string()]);
+}
+
+#[test]
+fn test_128bit_and_char_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let arr = Array::new(vec![i128::MAX - 1, 1, -5]);
+    assert_eq!(arr.dtype(), "i128");
+    assert!(arr.is_numeric());
+    assert_eq!(arr.min(), Ok(Scalar::i128(-5)));
+    assert_eq!(arr.max(), Ok(Scalar::i128(i128::MAX - 1)));
+    assert_eq!(Array::new(vec![1i128, 2, 3]).sum(), Ok(Scalar::i128(6)));
+
+    let arr = Array::new(vec![u128::MAX, 0]);
+    assert_eq!(arr.dtype(), "u128");
+    assert_eq!(arr.into_string_vec(),
+               vec!["340282366920938463463374607431768211455".to_string(), "0".to_string()]);
+    assert_eq!(arr.count(), 2);
+
+    let arr = Array::CharArray(NullVec::with_mask(vec!['b', 'a', 'c'],
+                                                  Some(vec![false, true, false])));
+    assert_eq!(arr.dtype(), "char");
+    assert!(!arr.is_numeric());
+    assert_eq!(arr.count(), 2);
+    assert_eq!(arr.min(), Ok(Scalar::char('b')));
+    assert_eq!(arr.max(), Ok(Scalar::char('c')));
+    assert_eq!(arr.into_string_vec(),
+               vec!["b".to_string(), "Null".to_string(), "c".to_string()]);
+    assert_eq!(arr.iloc(&2).as_char(), Nullable::new('c'));
+}
+
+#[test]
+fn test_binary_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let values = NullVec::with_mask(vec![vec![0xde, 0xad], vec![], vec![0x01]],
+                                    Some(vec![false, true, false]));
+    let arr = Array::from(values);
+    assert_eq!(arr.dtype(), "binary");
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr.into_string_vec(),
+               vec!["dead".to_string(), "Null".to_string(), "01".to_string()]);
+    assert_eq!(arr.iloc(&0), Scalar::Binary(vec![0xde, 0xad]));
+    assert_eq!(arr.iloc(&0).to_string(), "dead");
+    assert_eq!(arr.iloc(&1), Scalar::Null);
+
+    let res = arr.append(&Array::BinaryArray(BinaryColumn::new(vec![vec![0xff]])));
+    assert_eq!(res.ilocs(&[3, 0]),
+               Array::BinaryArray(BinaryColumn::new(vec![vec![0xff], vec![0xde, 0xad]])));
+
+    let scalars: Vec<Scalar> = res.clone().into();
+    assert_eq!(scalars[2], Scalar::Binary(vec![0x01]));
+    assert_eq!(Array::from(scalars), res);
+}
+
+#[test]
+fn test_list_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let rows = vec![Some(Array::new(vec![1i64, 2])), None, Some(Array::new(vec![3i64]))];
+    let arr = Array::from(ListColumn::from_rows(rows));
+    assert_eq!(arr.dtype(), "list");
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr.into_string_vec(),
+               vec!["[1, 2]".to_string(), "Null".to_string(), "[3]".to_string()]);
+    assert_eq!(arr.iloc(&0), Scalar::List(Array::new(vec![1i64, 2])));
+    assert_eq!(arr.iloc(&0).to_string(), "[1, 2]");
+    assert_eq!(arr.iloc(&1), Scalar::Null);
+
+    let res = arr.shift(1, Scalar::Null).unwrap();
+    assert_eq!(res.into_string_vec(),
+               vec!["Null".to_string(), "[1, 2]".to_string(), "Null".to_string()]);
+}
+
+#[test]
+fn test_struct_array() {
+    use nullvec::prelude::dev::Stringify;
+
+    let c = StructColumn::with_mask(vec![("a".to_string(), Array::new(vec![1i64, 2])),
+                                         ("b".to_string(), Array::new(vec![true, false]))],
+                                    Some(vec![true, false]));
+    let arr = Array::from(c);
+    assert_eq!(arr.dtype(), "struct");
+    assert_eq!(arr.len(), 2);
+    assert_eq!(arr.into_string_vec(),
+               vec!["Null".to_string(), "{a: 2, b: false}".to_string()]);
+    let exp = Scalar::Struct(vec![("a".to_string(), Scalar::i64(2)),
+                                  ("b".to_string(), Scalar::bool(false))]);
+    assert_eq!(arr.iloc(&1), exp);
+
+    let res = arr.append(&arr).ilocs(&[1, 2, 3]);
+    assert_eq!(res.into_string_vec(),
+               vec!["{a: 2, b: false}", "Null", "{a: 2, b: false}"]);
+
+    let scalars: Vec<Scalar> = res.clone().into();
+    assert_eq!(scalars[1], Scalar::Null);
+    let res2 = Array::from(scalars);
+    assert_eq!(res2.into_string_vec(), res.into_string_vec());
+    match res2 {
+        Array::StructArray(ref c) => {
+            assert_eq!(c.names(), &["a".to_string(), "b".to_string()]);
+            assert_eq!(c.is_null(), vec![false, true, false]);
+        }
+        _ => panic!("unexpected dtype"),
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "arrow-ipc")]
+
+extern crate nullvec;
+use nullvec::prelude::*;
+
+use std::io::Cursor;
+
+// written by the Rust implementation of Apache Arrow (arrow-ipc 54.3)
+const FILE: &[u8] = include_bytes!("data/int32_utf8.arrow");
+
+fn expected() -> Vec<(String, Array)> {
+    let a = Array::Int32Array(NullVec::with_mask(
+        vec![1, 0, 3],
+        Some(vec![false, true, false]),
+    ));
+    let b = Utf8Column::with_mask(
+        vec!["x".to_string(), "yz".to_string(), "".to_string()],
+        Some(vec![false, false, true]),
+    );
+    vec![("a".to_string(), a), ("b".to_string(), Array::Utf8Array(b))]
+}
+
+#[test]
+fn test_read_arrow_file() {
+    let mut reader = ArrowFileReader::new(Cursor::new(FILE)).unwrap();
+    assert_eq!(reader.names(), &["a".to_string(), "b".to_string()]);
+    assert_eq!(reader.num_batches(), 1);
+    assert_eq!(reader.read().unwrap(), expected());
+}
+
+// written by the Rust implementation of Apache Arrow (arrow-ipc 54.3), with nine
+// rows in the first batch so that validity bitmaps span more than a byte
+const NULLABLE_STREAM: &[u8] = include_bytes!("data/nullable_large_utf8.arrows");
+const NULLABLE_FILE: &[u8] = include_bytes!("data/nullable_large_utf8.arrow");
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn expected_nullable() -> Vec<Vec<(String, Array)>> {
+    let first = vec![
+        ("a".to_string(),
+         Array::Int64Array(NullVec::with_mask(vec![1, 0, -3, 0, 5, 6, 0, 8, 9],
+                                              Some(vec![false, true, false, true, false,
+                                                        false, true, false, false])))),
+        ("b".to_string(),
+         Array::Utf8Array(Utf8Column::with_mask(
+             strings(&["x", "yz", "", "", "héllo", "", "a,b", "long string value", "z"]),
+             Some(vec![false, false, true, false, false, true, false, false, false])))),
+        ("c".to_string(),
+         Array::Float64Array(NullVec::with_mask(vec![0.5, 0., 1.5, 2., 0., -1., 0., 3.25, 0.],
+                                                Some(vec![false, true, false, false, true,
+                                                          false, false, false, true])))),
+        ("d".to_string(),
+         Array::BoolArray(NullVec::with_mask(vec![true, false, false, true, true, false,
+                                                  false, false, true],
+                                             Some(vec![false, false, true, false, false,
+                                                       true, false, false, false])))),
+    ];
+    let second = vec![
+        ("a".to_string(),
+         Array::Int64Array(NullVec::with_mask(vec![0, 11], Some(vec![true, false])))),
+        ("b".to_string(),
+         Array::Utf8Array(Utf8Column::with_mask(strings(&["w", ""]),
+                                                Some(vec![false, true])))),
+        ("c".to_string(),
+         Array::Float64Array(NullVec::with_mask(vec![4., 0.], Some(vec![false, true])))),
+        ("d".to_string(),
+         Array::BoolArray(NullVec::with_mask(vec![false, true], Some(vec![true, false])))),
+    ];
+    vec![first, second]
+}
+
+#[test]
+fn test_read_arrow_stream_nullable() {
+    let mut reader = ArrowStreamReader::new(Cursor::new(NULLABLE_STREAM)).unwrap();
+    assert_eq!(reader.names(),
+               &["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]);
+    let exp = expected_nullable();
+    assert_eq!(reader.next_batch().unwrap(), Some(exp[0].clone()));
+    assert_eq!(reader.next_batch().unwrap(), Some(exp[1].clone()));
+    assert_eq!(reader.next_batch().unwrap(), None);
+}
+
+#[test]
+fn test_read_arrow_file_nullable() {
+    let mut reader = ArrowFileReader::new(Cursor::new(NULLABLE_FILE)).unwrap();
+    assert_eq!(reader.num_batches(), 2);
+    let exp = expected_nullable();
+    assert_eq!(reader.read_batch(1).unwrap(), exp[1]);
+    assert_eq!(reader.read_batch(0).unwrap(), exp[0]);
+}
@@ -8,6 +8,45 @@ fn test_empty_scalar_to_array() {
     let _: Array = vals.into();
 }
 
+#[test]
+#[should_panic]
+fn test_all_null_scalar_to_array() {
+    let vals: Vec<Scalar> = vec![Scalar::Null, Scalar::Null];
+    let _: Array = vals.into();
+}
+
+#[test]
+fn test_leading_null_scalar_to_array() {
+    // dtype is inferred from the first non-null value
+    let vals: Vec<Scalar> = vec![Scalar::Null, Scalar::i64(1), Scalar::Null];
+    let res = Array::from(vals);
+    assert_eq!(res,
+               Array::Int64Array(NullVec::with_mask(vec![0, 1, 0],
+                                                    Some(vec![true, false, true]))));
+
+    let vals: Vec<Scalar> = vec![Scalar::Null, Scalar::List(Array::new(vec![1i64, 2]))];
+    let res = Array::from(vals);
+    assert_eq!(res.dtype(), "list");
+    assert_eq!(res.iloc(&0), Scalar::Null);
+    assert_eq!(res.iloc(&1), Scalar::List(Array::new(vec![1i64, 2])));
+
+    // struct fields are inferred individually
+    let vals: Vec<Scalar> = vec![Scalar::Struct(vec![("a".to_string(), Scalar::Null)]),
+                                 Scalar::Struct(vec![("a".to_string(), Scalar::i64(1))])];
+    let res = Array::from(vals);
+    assert_eq!(res.iloc(&1),
+               Scalar::Struct(vec![("a".to_string(), Scalar::i64(1))]));
+}
+
+#[test]
+#[should_panic(expected = "struct must have the same fields")]
+fn test_ragged_struct_scalar_to_array() {
+    let vals: Vec<Scalar> = vec![Scalar::Struct(vec![("a".to_string(), Scalar::i64(1)),
+                                                     ("b".to_string(), Scalar::i64(2))]),
+                                 Scalar::Struct(vec![("a".to_string(), Scalar::i64(1))])];
+    let _: Array = vals.into();
+}
+
 #[test]
 fn test_i64_vec_to_array() {
     let exp: Array = Array::Int64Array(NullVec::new(vec![1, 2]));
@@ -102,6 +141,37 @@ fn test_usize_array_to_vec() {
     assert_eq!(res, exps);
 }
 
+#[test]
+fn test_i128_vec_to_array() {
+    let exp: Array = Array::Int128Array(NullVec::new(vec![1, i128::MAX]));
+
+    let vals: Vec<i128> = vec![1, i128::MAX];
+    let res: Array = vals.into();
+    assert_eq!(res, exp);
+
+    let vals: Vec<Scalar> = vec![Scalar::i128(1), Scalar::i128(i128::MAX)];
+    let res = Array::from(vals);
+    assert_eq!(res, exp);
+
+    let res: Vec<Scalar> = exp.into();
+    assert_eq!(res, vec![Scalar::i128(1), Scalar::i128(i128::MAX)]);
+}
+
+#[test]
+fn test_u128_array_to_vec() {
+    let exp: Vec<u128> = vec![1, u128::MAX];
+
+    let vals = Array::UInt128Array(NullVec::new(vec![1, u128::MAX]));
+    let res: Vec<u128> = vals.into();
+    assert_eq!(res, exp);
+
+    let vals = Array::UInt128Array(NullVec::new(vec![1, u128::MAX]));
+    let res: Vec<Scalar> = Vec::from(vals);
+    assert_eq!(res, vec![Scalar::u128(1), Scalar::u128(u128::MAX)]);
+    assert_eq!(res[1].as_u128(), Nullable::new(u128::MAX));
+    assert_eq!(res[0].as_i64(), Nullable::new(1));
+}
+
 #[test]
 fn test_f64_vec_to_array() {
     let exp: Array = Array::Float64Array(NullVec::new(vec![1.1, 2.2]));
@@ -196,6 +266,22 @@ fn test_bool_array_to_vec() {
     assert_eq!(res, exps);
 }
 
+#[test]
+fn test_char_vec_to_array() {
+    let exp: Array = Array::CharArray(NullVec::new(vec!['a', 'あ']));
+
+    let vals: Vec<char> = vec!['a', 'あ'];
+    let res: Array = vals.into();
+    assert_eq!(res, exp);
+
+    let vals: Vec<Scalar> = vec![Scalar::char('a'), Scalar::char('あ')];
+    let res = Array::from(vals);
+    assert_eq!(res, exp);
+
+    let res: Vec<char> = exp.into();
+    assert_eq!(res, vec!['a', 'あ']);
+}
+
 #[test]
 fn test_str_vec_to_array() {
     let exp: Array = Array::StringArray(NullVec::new(vec!["a".to_string(), "b".to_string()]));
@@ -0,0 +1,87 @@
+extern crate nullvec;
+use nullvec::prelude::*;
+use nullvec::prelude::dev::Stringify;
+
+fn sample() -> DataFrame {
+    DataFrame::new(vec![("key".to_string(),
+                         Array::new(Utf8Column::with_mask(vec!["b".to_string(),
+                                                               "a".to_string(),
+                                                               "".to_string(),
+                                                               "b".to_string(),
+                                                               "a".to_string()],
+                                                          Some(vec![false, false, true, false,
+                                                                    false])))),
+                        ("date".to_string(),
+                         Array::new(vec![Date32::from_ymd(2017, 3, 2),
+                                         Date32::from_ymd(2017, 3, 1),
+                                         Date32::from_ymd(2017, 3, 3),
+                                         Date32::from_ymd(2017, 3, 1),
+                                         Date32::from_ymd(2017, 3, 1)])),
+                        ("value".to_string(), Array::new(vec![1.5, f64::NAN, 3.5, 0.5, 2.5]))])
+}
+
+#[test]
+fn test_sort_by() {
+    let df = sample();
+
+    // Null is placed last, and ties keep the original order
+    let res = df.sort_by(&["key"]).unwrap();
+    assert_eq!(res.len(), 5);
+    assert_eq!(res.column("value").unwrap().into_string_vec(),
+               vec!["Null", "2.5", "1.5", "0.5", "3.5"]);
+
+    let res = df.sort_by(&["key", "value"]).unwrap();
+    assert_eq!(res.column("value").unwrap().into_string_vec(),
+               vec!["2.5", "Null", "0.5", "1.5", "3.5"]);
+
+    let res = df.sort_by(&["date", "key"]).unwrap();
+    assert_eq!(res.column("key").unwrap().into_string_vec(),
+               vec!["a", "a", "b", "b", "Null"]);
+
+    // categorical is sorted in the order of its categories
+    let mut df = df;
+    df.remove("key");
+    let values = NullVec::with_mask(vec!["b".to_string(), "a".to_string(), "".to_string(),
+                                         "b".to_string(), "a".to_string()],
+                                    Some(vec![false, false, true, false, false]));
+    let key = Categorical::with_categories(&values, vec!["b".to_string(), "a".to_string()],
+                                           false);
+    df.insert(0, "key", Array::CategoricalArray(key));
+    let res = df.sort_by(&["key", "date"]).unwrap();
+    assert_eq!(res.column("value").unwrap().into_string_vec(),
+               vec!["0.5", "1.5", "Null", "2.5", "3.5"]);
+}
+
+#[test]
+fn test_sort_by_unsupported_dtype() {
+    let mut df = sample();
+    let values = vec![Scalar::Struct(vec![("x".to_string(), Scalar::i64(1))]); df.len()];
+    df.insert(3, "s", Array::from(values));
+
+    let err = df.sort_by(&["key", "s"]).unwrap_err();
+    assert_eq!(err.dtype(), "struct");
+    assert_eq!(err.to_string(), "sort is not supported for dtype struct");
+
+    // other columns can still be used as keys
+    assert!(df.sort_by(&["key"]).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_sort_by_missing_column() {
+    sample().sort_by(&["x"]).unwrap();
+}
+
+#[test]
+fn test_filter_and_slice() {
+    let df = sample();
+    let cond = NullVec::with_mask(vec![true, false, true, true, false],
+                                  Some(vec![false, false, true, false, false]));
+    let res = df.filter(&cond);
+    assert_eq!(res.column("value").unwrap().into_string_vec(), vec!["1.5", "0.5"]);
+
+    let res = df.sort_by(&["value"]).unwrap().head(2);
+    assert_eq!(res.column("value").unwrap().into_string_vec(), vec!["0.5", "1.5"]);
+    let res = df.tail(1).append(&df.head(1));
+    assert_eq!(res.column("value").unwrap().into_string_vec(), vec!["2.5", "1.5"]);
+}